@@ -0,0 +1,172 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Typed canonical answers.
+// Firmware spells the same answer in many ways ("Enable", "Enabled", "On", "PresentEnabled").
+// Parsing answers into a CanonicalAnswer lets every question use the same vocabulary
+// without listing answer_replacements for each question in the spellings database.
+
+use std::fmt;
+
+/// Boolean spellings which are understood for every question, as (true, false) pairs.
+/// The first pair is the canonical spelling.
+const BOOLEAN_VOCABULARY: [(&str, &str); 6] = [
+    ("Enabled", "Disabled"),
+    ("Enable", "Disable"),
+    ("On", "Off"),
+    ("True", "False"),
+    ("Yes", "No"),
+    ("PresentEnabled", "PresentDisabled"),
+];
+
+#[derive(Debug, Clone)]
+pub enum CanonicalAnswer {
+    Boolean(bool),
+    /// a number with an optional unit like "100", "100ms" or "100 ms"
+    Integer {
+        value: u64,
+        unit: Option<String>,
+    },
+    /// any other answer (like "Auto") which is only compared case-insensitively
+    Enum(String),
+}
+
+impl CanonicalAnswer {
+    pub fn parse(answer: &str) -> Self {
+        let answer = answer.trim();
+
+        for (enabled, disabled) in BOOLEAN_VOCABULARY {
+            if answer.eq_ignore_ascii_case(enabled) {
+                return CanonicalAnswer::Boolean(true);
+            }
+            if answer.eq_ignore_ascii_case(disabled) {
+                return CanonicalAnswer::Boolean(false);
+            }
+        }
+
+        let digits_end = answer
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(answer.len());
+        if digits_end > 0 {
+            let unit = answer[digits_end..].trim();
+            if let Ok(value) = answer[..digits_end].parse::<u64>() {
+                if unit.is_empty() {
+                    return CanonicalAnswer::Integer { value, unit: None };
+                }
+                if unit.chars().all(|c| c.is_alphabetic() || c == '%') {
+                    return CanonicalAnswer::Integer {
+                        value,
+                        unit: Some(unit.to_owned()),
+                    };
+                }
+            }
+        }
+
+        CanonicalAnswer::Enum(answer.to_owned())
+    }
+}
+
+impl PartialEq for CanonicalAnswer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CanonicalAnswer::Boolean(a), CanonicalAnswer::Boolean(b)) => a == b,
+            (
+                CanonicalAnswer::Integer { value: a, unit: ua },
+                CanonicalAnswer::Integer { value: b, unit: ub },
+            ) => {
+                a == b
+                    && match (ua, ub) {
+                        (Some(ua), Some(ub)) => ua.eq_ignore_ascii_case(ub),
+                        (None, None) => true,
+                        _ => false,
+                    }
+            }
+            (CanonicalAnswer::Enum(a), CanonicalAnswer::Enum(b)) => a.eq_ignore_ascii_case(b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CanonicalAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanonicalAnswer::Boolean(true) => write!(f, "{}", BOOLEAN_VOCABULARY[0].0),
+            CanonicalAnswer::Boolean(false) => write!(f, "{}", BOOLEAN_VOCABULARY[0].1),
+            CanonicalAnswer::Integer { value, unit: None } => write!(f, "{value}"),
+            CanonicalAnswer::Integer {
+                value,
+                unit: Some(unit),
+            } => write!(f, "{value} {unit}"),
+            CanonicalAnswer::Enum(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+/// normalize_answer returns the canonical spelling of an answer.
+/// Ex: "Enable" -> "Enabled", "PresentDisabled" -> "Disabled", "100ms" -> "100 ms", "Auto" -> "Auto"
+pub fn normalize_answer(answer: &str) -> String {
+    CanonicalAnswer::parse(answer).to_string()
+}
+
+/// answers_equivalent returns true if both answers mean the same thing
+pub fn answers_equivalent(a: &str, b: &str) -> bool {
+    CanonicalAnswer::parse(a) == CanonicalAnswer::parse(b)
+}
+
+/// spell_boolean_like spells value using the same vocabulary as the answer in like.
+/// Ex: (true, "PresentDisabled") -> "PresentEnabled", (false, "On") -> "Off"
+/// It returns None if like isn't a known boolean spelling.
+pub fn spell_boolean_like(value: bool, like: &str) -> Option<&'static str> {
+    let like = like.trim();
+    BOOLEAN_VOCABULARY
+        .iter()
+        .find(|(enabled, disabled)| {
+            like.eq_ignore_ascii_case(enabled) || like.eq_ignore_ascii_case(disabled)
+        })
+        .map(|(enabled, disabled)| if value { *enabled } else { *disabled })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_answer() {
+        assert_eq!(normalize_answer("Enable"), "Enabled");
+        assert_eq!(normalize_answer(" on "), "Enabled");
+        assert_eq!(normalize_answer("PresentDisabled"), "Disabled");
+        assert_eq!(normalize_answer("100ms"), "100 ms");
+        assert_eq!(normalize_answer("100"), "100");
+        assert_eq!(normalize_answer("Auto"), "Auto");
+        assert_eq!(normalize_answer("1.5 GHz"), "1.5 GHz");
+    }
+
+    #[test]
+    fn test_answers_equivalent() {
+        assert!(answers_equivalent("Enabled", "enable"));
+        assert!(answers_equivalent("Yes", "PresentEnabled"));
+        assert!(!answers_equivalent("Enabled", "Disabled"));
+        assert!(answers_equivalent("100 MS", "100ms"));
+        assert!(!answers_equivalent("100", "100ms"));
+        assert!(answers_equivalent("auto", "Auto"));
+    }
+
+    #[test]
+    fn test_spell_boolean_like() {
+        assert_eq!(
+            spell_boolean_like(true, "PresentDisabled"),
+            Some("PresentEnabled")
+        );
+        assert_eq!(spell_boolean_like(false, "on"), Some("Off"));
+        assert_eq!(spell_boolean_like(true, "Auto"), None);
+    }
+}
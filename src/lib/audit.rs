@@ -0,0 +1,258 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Audit events make every change of a setting attributable. Each change (or failed attempt) made
+// by set, apply, restore or undo is sent to the host's journald with the fields below, or to syslog as key="value" pairs with the
+// same names if journald isn't running. The field names are stable so that they can be queried:
+//
+//   UEFISETTINGS_USER          name of the user running uefisettings
+//   UEFISETTINGS_UID           uid of that user
+//   UEFISETTINGS_COMMAND_LINE  command line of uefisettings
+//   UEFISETTINGS_BACKEND       hii or ilo
+//   UEFISETTINGS_SELECTOR      hii: package list GUID, ilo: bios, debug or service
+//   UEFISETTINGS_QUESTION      question which was changed
+//   UEFISETTINGS_OLD_VALUE     answer before the change (empty if it's unknown)
+//   UEFISETTINGS_NEW_VALUE     answer after the change
+//   UEFISETTINGS_RESULT        changed or failed
+//   UEFISETTINGS_ERROR         why the change failed
+
+use std::env;
+use std::os::unix::net::UnixDatagram;
+use std::path::Path;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use log::debug;
+use log::warn;
+use nix::unistd::getuid;
+use nix::unistd::User;
+
+const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+const SYSLOG_SOCKET: &str = "/dev/log";
+const IDENTIFIER: &str = "uefisettings";
+
+// facility auth, severities notice and warning
+const SYSLOG_FACILITY: u8 = 4;
+const SEVERITY_NOTICE: u8 = 5;
+const SEVERITY_WARNING: u8 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditResult {
+    Changed,
+    Failed,
+}
+
+impl AuditResult {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AuditResult::Changed => "changed",
+            AuditResult::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    /// hii or ilo
+    pub backend: &'static str,
+    pub selector: String,
+    pub question: String,
+    pub old_value: String,
+    pub new_value: String,
+    pub result: AuditResult,
+    pub error: String,
+}
+
+impl AuditEvent {
+    /// fields returns the fields of the event along with who made the change and how
+    fn fields(&self) -> Vec<(&'static str, String)> {
+        let uid = getuid();
+        let user = User::from_uid(uid)
+            .ok()
+            .flatten()
+            .map(|user| user.name)
+            .unwrap_or_default();
+        let command_line = env::args().collect::<Vec<_>>().join(" ");
+
+        let mut message = format!(
+            "{} {} '{}' from '{}' to '{}'",
+            self.result.as_str(),
+            self.backend,
+            self.question,
+            self.old_value,
+            self.new_value
+        );
+        if !self.error.is_empty() {
+            message.push_str(&format!(": {}", self.error));
+        }
+
+        vec![
+            ("MESSAGE", message),
+            ("UEFISETTINGS_USER", user),
+            ("UEFISETTINGS_UID", uid.to_string()),
+            ("UEFISETTINGS_COMMAND_LINE", command_line),
+            ("UEFISETTINGS_BACKEND", self.backend.to_owned()),
+            ("UEFISETTINGS_SELECTOR", self.selector.clone()),
+            ("UEFISETTINGS_QUESTION", self.question.clone()),
+            ("UEFISETTINGS_OLD_VALUE", self.old_value.clone()),
+            ("UEFISETTINGS_NEW_VALUE", self.new_value.clone()),
+            ("UEFISETTINGS_RESULT", self.result.as_str().to_owned()),
+            ("UEFISETTINGS_ERROR", self.error.clone()),
+        ]
+    }
+
+    fn severity(&self) -> u8 {
+        match self.result {
+            AuditResult::Changed => SEVERITY_NOTICE,
+            AuditResult::Failed => SEVERITY_WARNING,
+        }
+    }
+}
+
+/// emit sends an audit event. Events are about this process, so they always go to the host's
+/// journald or syslog, even when working on another system root. Settings are changed even if
+/// nobody listens, so failing to send it is only logged.
+pub(crate) fn emit(event: &AuditEvent) {
+    debug!("audit event: {:?}", event);
+    if let Err(why) = send(event, Path::new(JOURNALD_SOCKET), Path::new(SYSLOG_SOCKET)) {
+        warn!("failed to send audit event: {:#}", why);
+    }
+}
+
+/// send sends an audit event to the journald socket, or to the syslog socket if journald isn't running
+fn send(event: &AuditEvent, journald_socket: &Path, syslog_socket: &Path) -> Result<()> {
+    let (socket_path, message) = if journald_socket.exists() {
+        (journald_socket, journald_message(event))
+    } else if syslog_socket.exists() {
+        (syslog_socket, syslog_message(event).into_bytes())
+    } else {
+        return Err(anyhow!("neither journald nor syslog is running"));
+    };
+    UnixDatagram::unbound()
+        .and_then(|socket| socket.send_to(&message, socket_path))
+        .context(format!("sending to {}", socket_path.display()))?;
+    Ok(())
+}
+
+/// journald_message encodes the event in journald's native protocol. Values with a newline
+/// are sent as their length followed by the raw value.
+fn journald_message(event: &AuditEvent) -> Vec<u8> {
+    let mut fields = vec![
+        ("PRIORITY", event.severity().to_string()),
+        ("SYSLOG_FACILITY", SYSLOG_FACILITY.to_string()),
+        ("SYSLOG_IDENTIFIER", IDENTIFIER.to_owned()),
+    ];
+    fields.extend(event.fields());
+
+    let mut message = Vec::new();
+    for (name, value) in fields {
+        message.extend_from_slice(name.as_bytes());
+        if value.contains('\n') {
+            message.push(b'\n');
+            message.extend_from_slice(&(value.len() as u64).to_le_bytes());
+        } else {
+            message.push(b'=');
+        }
+        message.extend_from_slice(value.as_bytes());
+        message.push(b'\n');
+    }
+    message
+}
+
+/// syslog_message formats the event as a syslog line with the fields as key="value" pairs
+fn syslog_message(event: &AuditEvent) -> String {
+    let fields = event.fields();
+    let mut line = format!(
+        "<{}>{}[{}]: {}",
+        SYSLOG_FACILITY * 8 + event.severity(),
+        IDENTIFIER,
+        std::process::id(),
+        fields[0].1.replace('\n', " ")
+    );
+    for (name, value) in &fields[1..] {
+        let value = value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', " ");
+        line.push_str(&format!(" {}=\"{}\"", name, value));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> AuditEvent {
+        AuditEvent {
+            backend: "hii",
+            selector: "Setup".to_owned(),
+            question: "Test \"Setting\"".to_owned(),
+            old_value: "Disabled".to_owned(),
+            new_value: "Enabled".to_owned(),
+            result: AuditResult::Failed,
+            error: "line one\nline two".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_journald_message() {
+        let message = journald_message(&event());
+        let text = String::from_utf8_lossy(&message);
+        assert!(text.starts_with("PRIORITY=4\nSYSLOG_FACILITY=4\nSYSLOG_IDENTIFIER=uefisettings\n"));
+        assert!(text.contains("\nUEFISETTINGS_QUESTION=Test \"Setting\"\n"));
+        assert!(text.contains("\nUEFISETTINGS_RESULT=failed\n"));
+
+        // values with newlines are sent with their length
+        let mut error = b"UEFISETTINGS_ERROR\n".to_vec();
+        error.extend_from_slice(&17u64.to_le_bytes());
+        error.extend_from_slice(b"line one\nline two\n");
+        assert!(message.ends_with(&error));
+    }
+
+    #[test]
+    fn test_send() {
+        let sockets = tempfile::tempdir().unwrap();
+        let journald_socket = sockets.path().join("journal.socket");
+        let syslog_socket = sockets.path().join("log");
+        let receive = |socket: &UnixDatagram| {
+            let mut buf = vec![0; 4096];
+            let len = socket.recv(&mut buf).unwrap();
+            String::from_utf8_lossy(&buf[..len]).into_owned()
+        };
+
+        assert!(send(&event(), &journald_socket, &syslog_socket).is_err());
+
+        let syslog = UnixDatagram::bind(&syslog_socket).unwrap();
+        send(&event(), &journald_socket, &syslog_socket).unwrap();
+        assert!(receive(&syslog).starts_with("<36>uefisettings["));
+
+        // journald is preferred when it's running
+        let journald = UnixDatagram::bind(&journald_socket).unwrap();
+        send(&event(), &journald_socket, &syslog_socket).unwrap();
+        let message = receive(&journald);
+        assert!(message.contains("\nUEFISETTINGS_RESULT=failed\n"));
+        assert!(message.contains(&format!("\nUEFISETTINGS_UID={}\n", getuid())));
+        syslog.set_nonblocking(true).unwrap();
+        assert!(syslog.recv(&mut [0; 16]).is_err());
+    }
+
+    #[test]
+    fn test_syslog_message() {
+        let line = syslog_message(&event());
+        assert!(line.starts_with("<36>uefisettings["));
+        assert!(line.contains(" UEFISETTINGS_QUESTION=\"Test \\\"Setting\\\"\""));
+        assert!(line.ends_with(" UEFISETTINGS_ERROR=\"line one line two\""));
+        assert!(!line.contains('\n'));
+    }
+}
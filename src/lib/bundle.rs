@@ -0,0 +1,273 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// A bundle is a tar archive of everything uefisettings reads from a machine: HiiDB, the efivars
+// backing its varstores, DMI information and iLO responses. It's attached to bug reports and
+// can be replayed so that read-only commands look at the captured machine instead of this one.
+//
+// Layout:
+//   metadata.json        BundleMetadata
+//   machine.json         MachineInfo (DMI information and backends) as thrift simplejson
+//   hiidb.bin            raw HiiDB (hii only)
+//   efivars/<name-guid>  efivars laid out like efivarfs (hii only)
+//   ilo_responses.json   iLO endpoint -> body of its response (ilo only)
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use fbthrift::simplejson_protocol;
+use serde::Deserialize;
+use serde::Serialize;
+use tempfile::TempDir;
+use uefisettings_backend_thrift::MachineInfo;
+
+const METADATA_FILE: &str = "metadata.json";
+const MACHINE_FILE: &str = "machine.json";
+const HIIDB_FILE: &str = "hiidb.bin";
+const EFIVARS_DIR: &str = "efivars";
+const ILO_RESPONSES_FILE: &str = "ilo_responses.json";
+
+/// Changes whenever the layout of a bundle changes in a way older versions can't read
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+static REPLAYED_BUNDLE: RwLock<Option<Arc<Bundle>>> = RwLock::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleMetadata {
+    pub format_version: u32,
+    pub tool_version: String,
+    /// seconds since the unix epoch
+    pub captured_at: u64,
+}
+
+/// BundleContents is everything captured from a machine
+#[derive(Debug, Default)]
+pub struct BundleContents {
+    pub machine: MachineInfo,
+    pub hiidb: Option<Vec<u8>>,
+    /// efivar name (like Setup-<guid>) -> its contents starting with 4 bytes of attributes
+    pub efivars: BTreeMap<String, Vec<u8>>,
+    /// iLO endpoint -> body of the response to a GET
+    pub ilo_responses: BTreeMap<String, String>,
+}
+
+/// write_bundle writes the contents along with metadata about this tool as a tar archive
+pub fn write_bundle<W: Write>(out: W, contents: &BundleContents) -> Result<()> {
+    let captured_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("system time is before the unix epoch")?
+        .as_secs();
+    let metadata = BundleMetadata {
+        format_version: BUNDLE_FORMAT_VERSION,
+        tool_version: env!("CARGO_PKG_VERSION").to_owned(),
+        captured_at,
+    };
+
+    let mut builder = tar::Builder::new(out);
+    let mut append = |path: &str, data: &[u8]| -> Result<()> {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(captured_at);
+        builder
+            .append_data(&mut header, path, data)
+            .context(format!("failed to add {} to bundle", path))
+    };
+
+    append(METADATA_FILE, &serde_json::to_vec_pretty(&metadata)?)?;
+    append(
+        MACHINE_FILE,
+        &simplejson_protocol::serialize(&contents.machine),
+    )?;
+    if let Some(db) = &contents.hiidb {
+        append(HIIDB_FILE, db)?;
+    }
+    for (name, data) in &contents.efivars {
+        append(&format!("{}/{}", EFIVARS_DIR, name), data)?;
+    }
+    if !contents.ilo_responses.is_empty() {
+        append(
+            ILO_RESPONSES_FILE,
+            &serde_json::to_vec_pretty(&contents.ilo_responses)?,
+        )?;
+    }
+
+    builder
+        .into_inner()
+        .context("failed to finish bundle")?
+        .flush()?;
+    Ok(())
+}
+
+/// Bundle is a bundle which has been unpacked into a temporary directory
+#[derive(Debug)]
+pub struct Bundle {
+    source: PathBuf,
+    dir: TempDir,
+    metadata: BundleMetadata,
+    machine: MachineInfo,
+    ilo_responses: BTreeMap<String, String>,
+}
+
+impl Bundle {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path).context(format!("failed to open {}", path.display()))?;
+        let dir = tempfile::tempdir().context("failed to create directory to unpack bundle")?;
+        // unpack refuses entries which would end up outside of dir
+        tar::Archive::new(file)
+            .unpack(dir.path())
+            .context(format!("failed to unpack bundle {}", path.display()))?;
+
+        let metadata: BundleMetadata =
+            serde_json::from_slice(&read_file(dir.path(), METADATA_FILE)?)
+                .context(format!("failed to parse {}", METADATA_FILE))?;
+        if metadata.format_version > BUNDLE_FORMAT_VERSION {
+            return Err(anyhow!(
+                "bundle format version {} is newer than {} which is supported by this version of uefisettings",
+                metadata.format_version,
+                BUNDLE_FORMAT_VERSION
+            ));
+        }
+
+        let machine = simplejson_protocol::deserialize(&read_file(dir.path(), MACHINE_FILE)?)
+            .context(format!("failed to parse {}", MACHINE_FILE))?;
+
+        let ilo_responses = if dir.path().join(ILO_RESPONSES_FILE).exists() {
+            serde_json::from_slice(&read_file(dir.path(), ILO_RESPONSES_FILE)?)
+                .context(format!("failed to parse {}", ILO_RESPONSES_FILE))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Bundle {
+            source: path.to_path_buf(),
+            dir,
+            metadata,
+            machine,
+            ilo_responses,
+        })
+    }
+
+    /// source is the path of the archive
+    pub fn source(&self) -> &Path {
+        &self.source
+    }
+
+    pub fn metadata(&self) -> &BundleMetadata {
+        &self.metadata
+    }
+
+    pub fn machine(&self) -> &MachineInfo {
+        &self.machine
+    }
+
+    pub fn hiidb(&self) -> Result<Vec<u8>> {
+        if !self.dir.path().join(HIIDB_FILE).exists() {
+            return Err(anyhow!("{} has no HiiDB", self.source.display()));
+        }
+        read_file(self.dir.path(), HIIDB_FILE)
+    }
+
+    pub fn efivars_dir(&self) -> PathBuf {
+        self.dir.path().join(EFIVARS_DIR)
+    }
+
+    pub fn ilo_response(&self, endpoint: &str) -> Option<&str> {
+        self.ilo_responses.get(endpoint).map(String::as_str)
+    }
+}
+
+fn read_file(dir: &Path, name: &str) -> Result<Vec<u8>> {
+    fs::read(dir.join(name)).context(format!("failed to read {} from bundle", name))
+}
+
+/// BundleReplay makes everything be read from a bundle instead of the machine until it's dropped.
+/// Nothing can be changed while a bundle is replayed.
+pub struct BundleReplay;
+
+impl BundleReplay {
+    pub fn new(bundle: Bundle) -> Self {
+        *REPLAYED_BUNDLE
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(bundle));
+        BundleReplay
+    }
+}
+
+impl Drop for BundleReplay {
+    fn drop(&mut self) {
+        *REPLAYED_BUNDLE
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    }
+}
+
+/// replayed_bundle returns the bundle which is being replayed if there is one
+pub fn replayed_bundle() -> Option<Arc<Bundle>> {
+    REPLAYED_BUNDLE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use uefisettings_backend_thrift::Backend;
+
+    use super::*;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let contents = BundleContents {
+            machine: MachineInfo {
+                backend: BTreeSet::from([Backend::Hii, Backend::Ilo]),
+                bios_vendor: "Vendor".to_owned(),
+                ..Default::default()
+            },
+            hiidb: Some(vec![1, 2, 3]),
+            efivars: BTreeMap::from([("Setup-guid".to_owned(), vec![7, 0, 0, 0, 1])]),
+            ilo_responses: BTreeMap::from([(
+                "/redfish/v1/".to_owned(),
+                "{\"RedfishVersion\": \"1.6.0\"}".to_owned(),
+            )]),
+        };
+        let archive = tempfile::NamedTempFile::new().unwrap();
+        write_bundle(File::create(archive.path()).unwrap(), &contents).unwrap();
+
+        let bundle = Bundle::open(archive.path()).unwrap();
+        assert_eq!(bundle.metadata().format_version, BUNDLE_FORMAT_VERSION);
+        assert_eq!(bundle.machine(), &contents.machine);
+        assert_eq!(bundle.hiidb().unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            fs::read(bundle.efivars_dir().join("Setup-guid")).unwrap(),
+            vec![7, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            bundle.ilo_response("/redfish/v1/"),
+            Some("{\"RedfishVersion\": \"1.6.0\"}")
+        );
+        assert_eq!(bundle.ilo_response("/redfish/v1/systems/1/bios/"), None);
+    }
+}
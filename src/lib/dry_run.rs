@@ -0,0 +1,79 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// While a DryRun is active nothing is changed. Every write which would have been done (an efivar
+// write for hii or a PATCH for ilo) is recorded instead so that it can be shown to the user.
+
+use std::cell::RefCell;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlannedWrite {
+    Efivar {
+        path: String,
+        /// offset of the answer after the 4 bytes of attributes
+        offset: u16,
+        old_bytes: Vec<u8>,
+        new_bytes: Vec<u8>,
+    },
+    Patch {
+        endpoint: String,
+        body: String,
+    },
+}
+
+thread_local! {
+    /// writes planned by the DryRun which is active on this thread
+    static PLANNED_WRITES: RefCell<Option<Vec<PlannedWrite>>> = const { RefCell::new(None) };
+}
+
+/// DryRun records writes instead of doing them until it's dropped
+pub struct DryRun;
+
+impl DryRun {
+    pub fn start() -> Self {
+        PLANNED_WRITES.with(|planned| *planned.borrow_mut() = Some(Vec::new()));
+        DryRun
+    }
+}
+
+impl Drop for DryRun {
+    fn drop(&mut self) {
+        PLANNED_WRITES.with(|planned| *planned.borrow_mut() = None);
+    }
+}
+
+pub fn is_dry_run() -> bool {
+    PLANNED_WRITES.with(|planned| planned.borrow().is_some())
+}
+
+/// plan records a write instead of doing it. It returns false if there is no dry run,
+/// then the caller has to do the write itself.
+pub fn plan(write: PlannedWrite) -> bool {
+    PLANNED_WRITES.with(|planned| match planned.borrow_mut().as_mut() {
+        Some(planned) => {
+            planned.push(write);
+            true
+        }
+        None => false,
+    })
+}
+
+/// take_planned_writes returns the writes which were planned since it was called last
+pub fn take_planned_writes() -> Vec<PlannedWrite> {
+    PLANNED_WRITES.with(|planned| {
+        planned
+            .borrow_mut()
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    })
+}
@@ -10,51 +10,179 @@
 //
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::collections::HashSet;
 use std::fmt::Write;
 use std::fs;
+use std::fs::File;
 use std::path::Path;
+use std::rc::Rc;
 
 use anyhow::anyhow;
 use anyhow::Context;
 use anyhow::Result;
 use log::debug;
+use log::warn;
 use serde_json::Value;
+use thiserror::Error;
 
 use uefisettings_backend_thrift::Backend;
+use uefisettings_backend_thrift::BackendCapability;
+use uefisettings_backend_thrift::CapabilitiesReport;
+use uefisettings_backend_thrift::Capability;
+use uefisettings_backend_thrift::CapabilityStatus;
 use uefisettings_backend_thrift::GetResponse;
 use uefisettings_backend_thrift::GetResponseList;
+use uefisettings_backend_thrift::HiiChangeKind;
 use uefisettings_backend_thrift::HiiDatabase;
+use uefisettings_backend_thrift::HiiFieldChange;
+use uefisettings_backend_thrift::HiiForm;
+use uefisettings_backend_thrift::HiiFormSet;
+use uefisettings_backend_thrift::HiiMigrationEntry;
+use uefisettings_backend_thrift::HiiMigrationReport;
+use uefisettings_backend_thrift::HiiMigrationStatus;
+use uefisettings_backend_thrift::HiiQuestionDiff;
+use uefisettings_backend_thrift::HiiRestoreResult;
+use uefisettings_backend_thrift::HiiSearchResult;
+use uefisettings_backend_thrift::HiiSettingValue;
 use uefisettings_backend_thrift::HiiShowIfrResponse;
+use uefisettings_backend_thrift::HiiSnapshot;
 use uefisettings_backend_thrift::HiiStringsPackage;
+use uefisettings_backend_thrift::HiiTranslationCheck;
 use uefisettings_backend_thrift::IloAttributes;
 use uefisettings_backend_thrift::MachineInfo;
+use uefisettings_backend_thrift::ProfileCheckReport;
+use uefisettings_backend_thrift::ProfileMismatch;
 use uefisettings_backend_thrift::Question;
 use uefisettings_backend_thrift::SetResponse;
 use uefisettings_backend_thrift::SetResponseList;
-
+use uefisettings_backend_thrift::SetStatus;
+use uefisettings_backend_thrift::UndoResult;
+use uefisettings_backend_thrift::UndoResultList;
+use uefisettings_spellings_db_thrift::CompositeStep;
+use uefisettings_spellings_db_thrift::HiiQuestion;
+use uefisettings_spellings_db_thrift::QuestionMapping;
+
+use crate::answers;
+use crate::answers::CanonicalAnswer;
+use crate::audit;
+use crate::audit::AuditEvent;
+use crate::audit::AuditResult;
+use crate::bundle;
+use crate::bundle::Bundle;
+use crate::bundle::BundleContents;
+use crate::dry_run;
+use crate::dry_run::DryRun;
+use crate::dry_run::PlannedWrite;
+use crate::hii::batch;
+use crate::hii::batch::WriteBatch;
+use crate::hii::cluster;
+use crate::hii::diff;
+use crate::hii::diff::QuestionSnapshot;
+use crate::hii::efivarfs;
 use crate::hii::extract;
 use crate::hii::forms;
 use crate::hii::forms::list_questions;
+use crate::hii::forms::QuestionDescriptor;
+use crate::hii::migrate;
+use crate::hii::minimize;
 use crate::hii::package;
+use crate::hii::search;
 use crate::ilorest::chif;
 use crate::ilorest::requests;
 use crate::ilorest::requests::Ilo5Dev;
 use crate::ilorest::requests::IloDevice;
 use crate::ilorest::requests::RedfishAttributes;
+use crate::journal;
+use crate::journal::JournalEntry;
+use crate::normalize;
+use crate::platform;
+use crate::profile;
+use crate::profile::ProfileBackend;
+use crate::profile::ProfileSetting;
+use crate::system_root;
+use crate::system_root::SystemRoot;
+use crate::translation;
+use crate::translation::get_composite_answer;
+use crate::translation::get_composite_questions;
+use crate::translation::get_composite_steps;
 use crate::translation::get_qa_variations_hii;
 use crate::translation::get_qa_variations_ilo;
 use crate::translation::translate_response;
 use crate::translation::HiiTranslation;
 use crate::translation::IloTranslation;
+use crate::translation::SpellingsDB;
+
+pub use crate::bundle::BundleReplay;
+pub use crate::profile::Profile;
+pub use crate::translation::MachineCache;
+
+/// SetOptions changes how a backend picks the questions/attributes which will be modified
+#[derive(Debug, Default, Clone)]
+pub struct SetOptions {
+    /// modify every matching question even if the matches are stored in different places
+    pub all: bool,
+    /// if nothing matches, fail with suggestions of the closest questions (only used by hii)
+    pub fuzzy: bool,
+    /// show what would be written (efivar bytes or iLO PATCH requests) without changing anything
+    pub dry_run: bool,
+}
+
+/// GetOptions changes how a backend looks for the questions/attributes which will be shown
+#[derive(Debug, Default, Clone)]
+pub struct GetOptions {
+    /// if nothing matches, fail with suggestions of the closest questions (only used by hii)
+    pub fuzzy: bool,
+    /// read questions from this HiiDB dump instead of extracting HiiDB from the machine (only used by hii)
+    pub db: Option<Vec<u8>>,
+}
+
+#[derive(Error, Debug)]
+pub enum QuestionError {
+    #[error(
+        "question '{question}' matches {} different questions, use a selector or --all:\n{}",
+        candidates.len(),
+        candidates.join("\n")
+    )]
+    Ambiguous {
+        question: String,
+        candidates: Vec<String>,
+    },
+    #[error(
+        "question '{question}' wasn't found, closest questions:\n{}",
+        suggestions.join("\n")
+    )]
+    NotFound {
+        question: String,
+        suggestions: Vec<String>,
+    },
+}
 
 /// SettingsBackend is a trait which should be satisfied by all backends (like ilo, hii)
 pub trait SettingsBackend {
     /// set changes the value of the UEFI question/attribute
-    fn set(question: &str, new_value: &str, selector: Option<&str>) -> Result<SetResponseList>;
+    fn set(question: &str, new_value: &str, selector: Option<&str>) -> Result<SetResponseList> {
+        Self::set_with_options(question, new_value, selector, &SetOptions::default())
+    }
+    /// set_with_options is set but with extra knobs like modifying every ambiguous match
+    fn set_with_options(
+        question: &str,
+        new_value: &str,
+        selector: Option<&str>,
+        options: &SetOptions,
+    ) -> Result<SetResponseList>;
     /// get displays the value of the UEFI question/attribute
-    fn get(question: &str, selector: Option<&str>) -> Result<GetResponseList>;
+    fn get(question: &str, selector: Option<&str>) -> Result<GetResponseList> {
+        Self::get_with_options(question, selector, &GetOptions::default())
+    }
+    /// get_with_options is get but with extra knobs like suggesting similar questions
+    fn get_with_options(
+        question: &str,
+        selector: Option<&str>,
+        options: &GetOptions,
+    ) -> Result<GetResponseList>;
 }
 
 pub struct HiiBackend {}
@@ -69,6 +197,30 @@ impl HiiBackend {
         Ok(resp)
     }
 
+    /// minimize returns a smaller HiiDB with only the given questions (and their spellings from the
+    /// spellings database), the formsets and varstores they're in and the strings they use.
+    /// If anonymize is true, titles and help texts are replaced by placeholders.
+    pub fn minimize(db_bytes: &[u8], questions: &[String], anonymize: bool) -> Result<HiiDatabase> {
+        let mut question_variations = HashSet::new();
+        for question in questions {
+            match get_qa_variations_hii(question, "") {
+                HiiTranslation::Translated {
+                    question_variations: variations,
+                    ..
+                }
+                | HiiTranslation::NotTranslated {
+                    question_variations: variations,
+                    ..
+                } => question_variations.extend(variations),
+            }
+        }
+
+        Ok(HiiDatabase {
+            db: minimize::minimize(db_bytes, &question_variations, anonymize)?,
+            ..Default::default()
+        })
+    }
+
     /// show_ifr returns a human readable representation of the forms in Hii
     pub fn show_ifr(db_bytes: &[u8]) -> Result<HiiShowIfrResponse> {
         // We depend on the caller to provide us with the hiidb instead of calling extract here
@@ -155,16 +307,620 @@ impl HiiBackend {
         }
         Ok(res)
     }
+
+    /// list all formsets in HiiDB along with their forms
+    pub fn list_forms(db_bytes: &[u8]) -> Result<Vec<HiiFormSet>> {
+        let mut res = Vec::new();
+        let parsed_db = package::read_db(db_bytes)?;
+        for (guid, package_list) in parsed_db.forms {
+            let string_packages = parsed_db
+                .strings
+                .get(&guid)
+                .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+            for form_package in package_list {
+                for formset_descriptor in forms::list_forms(form_package, string_packages) {
+                    let mut formset = HiiFormSet {
+                        package_list: guid.to_owned(),
+                        guid: formset_descriptor.guid,
+                        class_guid: formset_descriptor.class_guid,
+                        title: formset_descriptor.title,
+                        help: formset_descriptor.help,
+                        ..Default::default()
+                    };
+                    for form_descriptor in formset_descriptor.forms {
+                        formset.forms.push(HiiForm {
+                            form_id: form_descriptor.form_id.into(),
+                            title: form_descriptor.title,
+                            question_count: form_descriptor.question_count.try_into()?,
+                            ..Default::default()
+                        });
+                    }
+                    res.push(formset);
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    /// search finds questions whose prompt, help string or option labels match the pattern.
+    /// The pattern is a case-insensitive substring unless is_regex is set.
+    /// Results are ranked with the most relevant first.
+    pub fn search(db_bytes: &[u8], pattern: &str, is_regex: bool) -> Result<Vec<HiiSearchResult>> {
+        let matcher = search::Matcher::new(pattern, is_regex)?;
+        let parsed_db = package::read_db(db_bytes)?;
+
+        let mut hits = Vec::new();
+        for (guid, package_list) in parsed_db.forms {
+            let string_packages = parsed_db
+                .strings
+                .get(&guid)
+                .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+            for form_package in package_list {
+                for hit in search::search(list_questions(form_package, string_packages), &matcher) {
+                    hits.push((guid.to_owned(), hit));
+                }
+            }
+        }
+
+        // rank across all package lists, sort_by_key is stable so ties keep their order
+        hits.sort_by_key(|(_, hit)| std::cmp::Reverse(hit.score));
+
+        let mut res = Vec::new();
+        for (package_list, hit) in hits {
+            let mut question = Question {
+                name: hit.question.question.trim().to_owned(),
+                answer: hit.question.value,
+                help: hit.question.help,
+                ..Default::default()
+            };
+            for opt in hit.question.possible_options {
+                question.options.push(opt.value);
+            }
+            res.push(HiiSearchResult {
+                package_list,
+                question,
+                breadcrumb: hit.question.breadcrumb,
+                matched_fields: hit
+                    .matched_fields
+                    .iter()
+                    .map(|field| field.as_str().to_owned())
+                    .collect(),
+                score: hit.score.try_into()?,
+                ..Default::default()
+            });
+        }
+        Ok(res)
+    }
+
+    /// diff compares the questions of two HiiDB dumps and returns the questions which were added,
+    /// removed or changed (prompt, help, type, storage, form, options or default).
+    pub fn diff(old_db_bytes: &[u8], new_db_bytes: &[u8]) -> Result<Vec<HiiQuestionDiff>> {
+        let old = question_snapshots(old_db_bytes).context("failed to read the old HiiDB")?;
+        let new = question_snapshots(new_db_bytes).context("failed to read the new HiiDB")?;
+
+        Ok(diff::diff(&old, &new)
+            .into_iter()
+            .map(|question_diff| HiiQuestionDiff {
+                formset_guid: question_diff.formset_guid,
+                question: question_diff.question,
+                storage: question_diff.storage,
+                kind: match question_diff.kind {
+                    diff::ChangeKind::Added => HiiChangeKind::Added,
+                    diff::ChangeKind::Removed => HiiChangeKind::Removed,
+                    diff::ChangeKind::Changed => HiiChangeKind::Changed,
+                },
+                changes: question_diff
+                    .changes
+                    .into_iter()
+                    .map(|change| HiiFieldChange {
+                        field: change.field,
+                        old_value: change.old_value,
+                        new_value: change.new_value,
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    /// snapshot records the raw and display value of every question which is stored in a varstore
+    pub fn snapshot(db_bytes: &[u8]) -> Result<HiiSnapshot> {
+        let parsed_db = package::read_db(db_bytes)?;
+
+        let mut settings = Vec::new();
+        for HiiMatch { descriptor, .. } in stored_questions(&parsed_db)? {
+            match descriptor.raw_value() {
+                Ok(raw_value) => settings.push(HiiSettingValue {
+                    identity: descriptor.identity(),
+                    question: descriptor.question.trim().to_owned(),
+                    raw_value: raw_value.to_string(),
+                    display_value: descriptor.value.trim().to_owned(),
+                    breadcrumb: descriptor.breadcrumb.clone(),
+                    options: descriptor
+                        .possible_options
+                        .iter()
+                        .map(|opt| opt.value.trim().to_owned())
+                        .collect(),
+                    varstore: descriptor.varstore_name().unwrap_or_default(),
+                    ..Default::default()
+                }),
+                Err(e) => debug!("Skipping {}: {:#}", descriptor.identity(), e),
+            }
+        }
+
+        Ok(HiiSnapshot {
+            machine: identify_machine(),
+            settings,
+            ..Default::default()
+        })
+    }
+
+    /// restore writes back the values of a snapshot which are different on this machine.
+    /// Questions are matched by identity. It returns the settings which were changed or failed
+    /// to change, settings which already had the value from the snapshot are left out.
+    pub fn restore(snapshot: &HiiSnapshot) -> Result<Vec<HiiRestoreResult>> {
+        let db_bytes = extract::extract_db()?;
+        let parsed_db = package::read_db(&db_bytes)?;
+        let questions: BTreeMap<String, HiiMatch> = stored_questions(&parsed_db)?
+            .into_iter()
+            .map(|question| (question.descriptor.identity(), question))
+            .collect();
+
+        let batch = WriteBatch::start();
+        let mut res = Vec::new();
+        for setting in &snapshot.settings {
+            let mut result = HiiRestoreResult {
+                identity: setting.identity.clone(),
+                question: setting.question.clone(),
+                new_value: setting.display_value.clone(),
+                ..Default::default()
+            };
+            match questions.get(&setting.identity) {
+                None => result.error = "question not found in HiiDB".to_owned(),
+                Some(HiiMatch {
+                    package_list,
+                    descriptor,
+                }) => match record_hii_write(package_list, descriptor, || {
+                    restore_setting(descriptor, setting)
+                }) {
+                    Ok(None) => continue,
+                    Ok(Some(old_value)) => result.old_value = old_value,
+                    Err(e) => {
+                        result.old_value = descriptor.value.trim().to_owned();
+                        result.error = format!("{:#}", e);
+                    }
+                },
+            }
+            res.push(result);
+        }
+        batch
+            .commit()
+            .context("failed to restore the snapshot, nothing was changed")?;
+        Ok(res)
+    }
+
+    /// migrate maps the settings of a snapshot taken on another BIOS version onto this machine's
+    /// questions by prompt, breadcrumb, options and varstore name instead of by identity.
+    /// Every setting is mapped before anything is written. Nothing is written unless apply is set,
+    /// and uncertain matches are only written if include_uncertain is set too.
+    pub fn migrate(
+        snapshot: &HiiSnapshot,
+        apply: bool,
+        include_uncertain: bool,
+    ) -> Result<HiiMigrationReport> {
+        let db_bytes = extract::extract_db()?;
+        let parsed_db = package::read_db(&db_bytes)?;
+        let questions = stored_questions(&parsed_db)?;
+        let profiles: Vec<migrate::SettingProfile> = questions
+            .iter()
+            .map(|question| setting_profile(&question.descriptor))
+            .collect();
+
+        let mut report = HiiMigrationReport::default();
+        let mut planned = Vec::new();
+        for setting in &snapshot.settings {
+            let saved = migrate::SavedSetting {
+                profile: migrate::SettingProfile {
+                    prompt: setting.question.clone(),
+                    breadcrumb: setting.breadcrumb.clone(),
+                    options: setting.options.clone(),
+                    varstore: setting.varstore.clone(),
+                },
+                raw_value: setting.raw_value.clone(),
+                display_value: setting.display_value.clone(),
+            };
+            let mut entry = HiiMigrationEntry {
+                saved: setting.clone(),
+                ..Default::default()
+            };
+            let matched = match migrate::map_setting(&saved, &profiles) {
+                migrate::Mapping::Confident { index, value } => {
+                    entry.status = HiiMigrationStatus::Confident;
+                    Some((index, value))
+                }
+                migrate::Mapping::Uncertain {
+                    index,
+                    value,
+                    differences,
+                } => {
+                    entry.status = HiiMigrationStatus::Uncertain;
+                    entry.differences = differences.iter().map(|d| d.to_string()).collect();
+                    Some((index, value))
+                }
+                migrate::Mapping::Dropped { reason } => {
+                    entry.status = HiiMigrationStatus::Dropped;
+                    entry.reason = reason;
+                    None
+                }
+            };
+            if let Some((index, value)) = matched {
+                entry.identity = questions[index].descriptor.identity();
+                entry.breadcrumb = questions[index].descriptor.breadcrumb.clone();
+                entry.new_value = value.trim().to_owned();
+                if entry.status == HiiMigrationStatus::Confident || include_uncertain {
+                    planned.push((index, value, setting));
+                }
+            }
+            report.entries.push(entry);
+        }
+
+        if !apply {
+            return Ok(report);
+        }
+        let batch = WriteBatch::start();
+        for (index, value, setting) in planned {
+            let HiiMatch {
+                package_list,
+                descriptor,
+            } = &questions[index];
+            let mut result = HiiRestoreResult {
+                identity: descriptor.identity(),
+                question: setting.question.clone(),
+                new_value: value.trim().to_owned(),
+                ..Default::default()
+            };
+            match record_hii_write(package_list, descriptor, || {
+                write_setting(descriptor, &value)
+            }) {
+                Ok(None) => continue,
+                Ok(Some(old_value)) => result.old_value = old_value,
+                Err(e) => {
+                    result.old_value = descriptor.value.trim().to_owned();
+                    result.error = format!("{:#}", e);
+                }
+            }
+            report.results.push(result);
+        }
+        batch
+            .commit()
+            .context("failed to apply the migrated settings, nothing was changed")?;
+        Ok(report)
+    }
+
+    /// suggest_translations clusters the questions of several HiiDB dumps (usually from different vendors)
+    /// and returns draft spellings database entries for settings which are found in at least min_dumps dumps
+    /// but are spelled differently. Settings already in the spellings database are left out.
+    /// Every dump is paired with a name for messages, dumps which aren't HiiDBs are skipped with a warning.
+    pub fn suggest_translations(
+        dumps: &[(String, Vec<u8>)],
+        min_dumps: usize,
+    ) -> Result<SpellingsDB> {
+        let mut samples = Vec::new();
+        for (dump, (name, db_bytes)) in dumps.iter().enumerate() {
+            let parsed_db = match package::read_db(db_bytes) {
+                Ok(parsed_db) => parsed_db,
+                Err(why) => {
+                    warn!("skipping {}: {:#}", name, why);
+                    continue;
+                }
+            };
+            for (guid, package_list) in parsed_db.forms {
+                let string_packages = parsed_db
+                    .strings
+                    .get(&guid)
+                    .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+                for form_package in package_list {
+                    for descriptor in list_questions(form_package, string_packages) {
+                        samples.push(cluster::QuestionSample {
+                            dump,
+                            prompt: descriptor.question,
+                            help: descriptor.help,
+                            options: descriptor
+                                .possible_options
+                                .into_iter()
+                                .map(|opt| opt.value)
+                                .collect(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // every spelling which is already known, the canonical questions included
+        let known: Vec<&String> = spellings_db()
+            .iter()
+            .flat_map(|(question, mapping)| {
+                std::iter::once(question).chain(
+                    mapping
+                        .hii_question
+                        .iter()
+                        .flat_map(|hii_question| &hii_question.question_variations),
+                )
+            })
+            .collect();
+
+        let mut res = SpellingsDB::new();
+        for suggestion in cluster::cluster(&samples, min_dumps) {
+            if suggestion.question_variations.iter().any(|variation| {
+                known
+                    .iter()
+                    .any(|known| normalize::equivalent(known, variation))
+            }) {
+                continue;
+            }
+            res.insert(
+                suggestion.question,
+                QuestionMapping {
+                    hii_question: Some(HiiQuestion {
+                        question_variations: suggestion.question_variations,
+                        answer_replacements: Some(suggestion.answer_replacements)
+                            .filter(|replacements| !replacements.is_empty()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            );
+        }
+        Ok(res)
+    }
+
+    /// check_translations matches every hii spelling in the spellings database against HiiDB.
+    /// It reports question variations which don't match anything, answer replacements which aren't
+    /// options of the matched questions and spellings which match more than one question.
+    pub fn check_translations(db_bytes: &[u8]) -> Result<Vec<HiiTranslationCheck>> {
+        let parsed_db = package::read_db(db_bytes)?;
+
+        let mut res = Vec::new();
+        for (question, mapping) in spellings_db() {
+            let mut hii_questions = Vec::new();
+            if let Some(hii_question) = &mapping.hii_question {
+                hii_questions.push(("hii_question".to_owned(), hii_question));
+            }
+            for (i, platform) in mapping.platforms.iter().flatten().enumerate() {
+                if let Some(hii_question) = &platform.hii_question {
+                    hii_questions.push((format!("platforms[{}].hii_question", i), hii_question));
+                }
+            }
+
+            for (source, hii_question) in hii_questions {
+                res.push(check_hii_question(
+                    &parsed_db,
+                    question,
+                    source,
+                    hii_question,
+                )?);
+            }
+        }
+        Ok(res)
+    }
+}
+
+fn question_snapshots(db_bytes: &[u8]) -> Result<Vec<QuestionSnapshot>> {
+    let parsed_db = package::read_db(db_bytes)?;
+    Ok(list_hii_questions(&parsed_db)?
+        .iter()
+        .map(|question| QuestionSnapshot::from(&question.descriptor))
+        .collect())
+}
+
+/// list_hii_questions returns every question in every package list of HiiDB
+fn list_hii_questions(parsed_db: &package::ParsedHiiDB) -> Result<Vec<HiiMatch>> {
+    let mut res = Vec::new();
+    for (guid, package_list) in &parsed_db.forms {
+        let string_packages = parsed_db
+            .strings
+            .get(guid)
+            .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+        for form_package in package_list {
+            res.extend(
+                list_questions(Rc::clone(form_package), string_packages)
+                    .into_iter()
+                    .map(|descriptor| HiiMatch {
+                        package_list: guid.to_owned(),
+                        descriptor,
+                    }),
+            );
+        }
+    }
+    Ok(res)
+}
+
+/// stored_questions returns the questions of HiiDB which have a value in a varstore,
+/// a question which shows up in multiple forms is only returned once
+fn stored_questions(parsed_db: &package::ParsedHiiDB) -> Result<Vec<HiiMatch>> {
+    let mut identities = HashSet::new();
+    Ok(list_hii_questions(parsed_db)?
+        .into_iter()
+        .filter(|question| question.descriptor.storage().is_some())
+        .filter(|question| identities.insert(question.descriptor.identity()))
+        .collect())
+}
+
+/// restore_setting writes the raw value from a snapshot to a question if it's different
+/// and returns None if it was already set to that value
+fn restore_setting(
+    descriptor: &QuestionDescriptor,
+    setting: &HiiSettingValue,
+) -> Result<Option<String>> {
+    write_raw_value(descriptor, &setting.raw_value)
+}
+
+/// write_raw_value is write_setting but with the number to store instead of the label of an option
+fn write_raw_value(descriptor: &QuestionDescriptor, raw_value: &str) -> Result<Option<String>> {
+    // change_value takes the label of an option or the number to store
+    let new_value = if descriptor.possible_options.is_empty() {
+        raw_value.to_owned()
+    } else {
+        descriptor
+            .possible_options
+            .iter()
+            .find(|opt| opt.raw_value_string() == raw_value)
+            .map(|opt| opt.value.clone())
+            .ok_or_else(|| anyhow!("no option has the value {}", raw_value))?
+    };
+    write_setting(descriptor, &new_value)
+}
+
+/// write_setting changes a question to new_value (the label of an option or the number to store)
+/// if it's different and returns the answer it had before, or None if it was already set to it
+fn write_setting(descriptor: &QuestionDescriptor, new_value: &str) -> Result<Option<String>> {
+    let new_raw_value = match descriptor
+        .possible_options
+        .iter()
+        .find(|opt| opt.value == new_value)
+    {
+        Some(opt) => opt.raw_value_string(),
+        None => new_value.to_owned(),
+    };
+    if descriptor.raw_value()?.to_string() == new_raw_value {
+        return Ok(None);
+    }
+
+    if !forms::change_value(descriptor, new_value)? {
+        return Err(anyhow!("question has no varstore"));
+    }
+    Ok(Some(descriptor.value.trim().to_owned()))
+}
+
+/// record_hii_write journals the change which write (like write_setting) makes to a question
+/// of package_list and sends its audit event. restore and migrate use it, set records its
+/// changes itself.
+fn record_hii_write(
+    package_list: &str,
+    descriptor: &QuestionDescriptor,
+    write: impl FnOnce() -> Result<Option<String>>,
+) -> Result<Option<String>> {
+    let previous_value = descriptor.raw_value()?.to_string();
+    let old_value = write()?;
+    if let Some(old_value) = &old_value {
+        batch::record_journal_entry(JournalEntry {
+            identity: descriptor.identity(),
+            ..JournalEntry::new(
+                ProfileBackend::Hii,
+                package_list,
+                descriptor.question.trim(),
+                &previous_value,
+                &descriptor.raw_value()?.to_string(),
+            )
+        });
+        batch::record_audit_event(AuditEvent {
+            backend: "hii",
+            selector: package_list.to_owned(),
+            question: descriptor.question.trim().to_owned(),
+            old_value: old_value.clone(),
+            new_value: descriptor.current_value()?,
+            result: AuditResult::Changed,
+            error: String::new(),
+        });
+    }
+    Ok(old_value)
+}
+
+/// setting_profile describes a question the way hii restore --migrate matches it
+fn setting_profile(descriptor: &QuestionDescriptor) -> migrate::SettingProfile {
+    migrate::SettingProfile {
+        prompt: descriptor.question.trim().to_owned(),
+        breadcrumb: descriptor.breadcrumb.clone(),
+        options: descriptor
+            .possible_options
+            .iter()
+            .map(|opt| opt.value.clone())
+            .collect(),
+        varstore: descriptor.varstore_name().unwrap_or_default(),
+    }
 }
 
 impl SettingsBackend for HiiBackend {
-    fn set(question: &str, new_value: &str, _selector: Option<&str>) -> Result<SetResponseList> {
-        // TODO: use selector to only change values which match question + selector
+    fn set_with_options(
+        question: &str,
+        new_value: &str,
+        selector: Option<&str>,
+        options: &SetOptions,
+    ) -> Result<SetResponseList> {
+        let _dry_run = options.dry_run.then(DryRun::start);
+        // every match (and every step of a composite setting) is written together or not at all
+        let batch = WriteBatch::start();
+        let res =
+            get_composite_steps(question, new_value, Backend::Hii).and_then(|steps| match steps {
+                Some(steps) => set_composite(
+                    question,
+                    &steps,
+                    selector,
+                    options,
+                    Self::set_question,
+                    Self::get_question,
+                ),
+                None => Self::set_question(question, new_value, selector, options),
+            });
+        let mut res = audit_failed_set("hii", question, new_value, selector, res)?;
+        // the writes of a batch which joined another one (like the one of apply_profile) are
+        // committed and read back by the one it joined
+        let owner = batch.is_owner();
+        // commit sends the audit events of the staged changes, as failed if they can't be written
+        batch.commit()?;
+        if owner && !options.dry_run {
+            for resp in res.responses.iter_mut().filter(|resp| resp.modified) {
+                Self::read_back_answer(resp);
+            }
+        }
+        Ok(res)
+    }
+
+    fn get_with_options(
+        question: &str,
+        selector: Option<&str>,
+        options: &GetOptions,
+    ) -> Result<GetResponseList> {
+        match get_composite_questions(question, Backend::Hii) {
+            Some(questions) => get_composite(&questions, selector, options, Self::get_question),
+            None => Self::get_question(question, selector, options),
+        }
+    }
+}
+
+impl HiiBackend {
+    /// read_back_answer fills verified_answer of a response with the answer read from its efivar.
+    /// It's used once the writes were committed, which verifies their bytes.
+    fn read_back_answer(resp: &mut SetResponse) {
+        resp.verified_answer = Self::get_question(
+            resp.question.name.trim(),
+            Some(&resp.selector),
+            &GetOptions::default(),
+        )
+        .ok()
+        .and_then(|got| {
+            got.responses
+                .into_iter()
+                .find(|got| got.question.name == resp.question.name)
+        })
+        .map(|got| got.question.answer);
+    }
 
+    /// set_question changes a single (non-composite) question
+    fn set_question(
+        question: &str,
+        new_value: &str,
+        selector: Option<&str>,
+        options: &SetOptions,
+    ) -> Result<SetResponseList> {
         let mut resp = Vec::new();
 
-        let db_bytes = extract::extract_db()?;
-        let parsed_db = package::read_db(&db_bytes)?;
+        let parsed_db = read_hii_db()?;
 
         let hii_translation = get_qa_variations_hii(question, new_value);
         let (question_variations, new_value_variations, is_translated) = match hii_translation {
@@ -178,71 +934,104 @@ impl SettingsBackend for HiiBackend {
             } => (question_variations, answer_variations, false),
         };
 
-        for (guid, package_list) in parsed_db.forms {
-            let string_packages = parsed_db
-                .strings
-                .get(&guid)
-                .context(format!("Failed to get string packages using GUID {}", guid))?;
+        let matches = find_hii_questions(&parsed_db, &question_variations, selector)?;
+        if matches.is_empty() && options.fuzzy {
+            return Err(suggest_hii_questions(&parsed_db, question)?.into());
+        }
 
-            for form_package in package_list {
-                // try to find the question
-                if let Some(question_descriptor) =
-                    forms::find_question(form_package, string_packages, &question_variations)
-                {
-                    let mut modified = false;
-                    // if the question_descriptor provides options then set the closest one from new_value_variations
-                    // (example whatever matches from [Enabled, Enable])
-                    // else try setting the new_value because it might be some arbitrary value like a number
-                    // (will return error if doesn't match constraints)
-                    if !(question_descriptor.possible_options.is_empty()) {
-                        // This is different from modified because if varstore doesn't exist for the question
-                        // then we can't set answers but it isn't an error.
-                        let mut found_option = false;
-                        for opt in &(question_descriptor.possible_options) {
-                            if found_option {
-                                break;
-                            }
-                            for variation in &new_value_variations {
-                                if variation.eq_ignore_ascii_case(&(opt.value)) {
-                                    found_option = true;
-                                    modified =
-                                        forms::change_value(&question_descriptor, &(opt.value))?;
-                                    break;
-                                }
-                            }
-                        }
-                        // if not a single option matched then error out
-                        // we went though all options and if it still wasn't modified then this isn't in the options
-                        if !found_option {
-                            return Err(forms::ChangeValueError::InvalidOption.into());
-                        }
-                    } else {
-                        modified = forms::change_value(&question_descriptor, new_value)?
-                    }
+        // The same setting can show up in multiple forms but if the matches are stored in
+        // different places then we can't tell which one the caller meant.
+        let mut identities = BTreeSet::new();
+        let matches: Vec<HiiMatch> = matches
+            .into_iter()
+            .filter(|m| identities.insert(m.descriptor.identity()))
+            .collect();
+        if matches.len() > 1 && !options.all {
+            return Err(QuestionError::Ambiguous {
+                question: question.to_owned(),
+                candidates: matches.iter().map(HiiMatch::describe).collect(),
+            }
+            .into());
+        }
 
-                    if modified {
-                        let mut set_resp = SetResponse {
-                            selector: guid.to_owned(),
-                            backend: Backend::Hii,
-                            is_translated,
-                            question: Question {
-                                name: question_descriptor.question,
-                                answer: new_value.to_owned(),
-                                help: question_descriptor.help,
-                                ..Default::default()
-                            },
-                            modified: true,
-                            ..Default::default()
-                        };
+        for HiiMatch {
+            package_list,
+            descriptor: question_descriptor,
+        } in matches
+        {
+            // if the question_descriptor provides options then set the closest one from new_value_variations
+            // (example whatever matches from [Enabled, Enable])
+            // else try setting the new_value because it might be some arbitrary value like a number
+            // (will return error if doesn't match constraints)
+            let previous_value = question_descriptor.raw_value();
+            let modified = if !(question_descriptor.possible_options.is_empty()) {
+                // if not a single option matched then error out
+                let opt =
+                    find_hii_option(&question_descriptor.possible_options, &new_value_variations)
+                        .ok_or(forms::ChangeValueError::InvalidOption)?;
+                forms::change_value(&question_descriptor, &(opt.value))?
+            } else {
+                // checkboxes store 1/0 and numeric questions don't know about units,
+                // so "100ms" can't be converted and is rejected instead of being written as 100
+                let raw_value = match CanonicalAnswer::parse(new_value) {
+                    CanonicalAnswer::Boolean(b) if question_descriptor.is_checkbox() => {
+                        u8::from(b).to_string()
+                    }
+                    CanonicalAnswer::Integer {
+                        unit: Some(unit), ..
+                    } => {
+                        return Err(forms::ChangeValueError::UnexpectedUnit(unit).into());
+                    }
+                    CanonicalAnswer::Integer { value, unit: None } => value.to_string(),
+                    _ => new_value.to_owned(),
+                };
+                forms::change_value(&question_descriptor, &raw_value)?
+            };
 
-                        for opt in question_descriptor.possible_options {
-                            set_resp.question.options.push(opt.value)
-                        }
+            if modified {
+                // the answer is only verified once the batch is committed, see read_back_answer
+                if !dry_run::is_dry_run() {
+                    // during a WriteBatch this reads the staged bytes
+                    let new_answer = question_descriptor.current_value()?;
+                    batch::record_journal_entry(JournalEntry {
+                        identity: question_descriptor.identity(),
+                        ..JournalEntry::new(
+                            ProfileBackend::Hii,
+                            &package_list,
+                            question_descriptor.question.trim(),
+                            &previous_value?.to_string(),
+                            &question_descriptor.raw_value()?.to_string(),
+                        )
+                    });
+                    batch::record_audit_event(AuditEvent {
+                        backend: "hii",
+                        selector: package_list.clone(),
+                        question: question_descriptor.question.trim().to_owned(),
+                        old_value: question_descriptor.value.trim().to_owned(),
+                        new_value: new_answer,
+                        result: AuditResult::Changed,
+                        error: String::new(),
+                    });
+                }
+                let mut set_resp = SetResponse {
+                    selector: package_list,
+                    backend: Backend::Hii,
+                    is_translated,
+                    question: Question {
+                        name: question_descriptor.question,
+                        answer: new_value.to_owned(),
+                        help: question_descriptor.help,
+                        ..Default::default()
+                    },
+                    modified: true,
+                    ..Default::default()
+                };
 
-                        resp.push(set_resp);
-                    }
+                for opt in question_descriptor.possible_options {
+                    set_resp.question.options.push(opt.value)
                 }
-                // question not found in this form package
+
+                resp.push(with_planned_writes(set_resp));
             }
         }
 
@@ -252,9 +1041,12 @@ impl SettingsBackend for HiiBackend {
         })
     }
 
-    fn get(question: &str, _selector: Option<&str>) -> Result<GetResponseList> {
-        // TODO: use selector to only get questions which match question + selector
-
+    /// get_question reads a single (non-composite) question
+    fn get_question(
+        question: &str,
+        selector: Option<&str>,
+        options: &GetOptions,
+    ) -> Result<GetResponseList> {
         let mut resp = Vec::new();
 
         let hii_translation = get_qa_variations_hii(question, "");
@@ -269,45 +1061,62 @@ impl SettingsBackend for HiiBackend {
             } => (question_variations, false),
         };
 
-        let db_bytes = extract::extract_db()?;
-        let parsed_db = package::read_db(&db_bytes)?;
-
-        for (guid, package_list) in parsed_db.forms {
-            for form_package in package_list {
-                if let Some(question_descriptor) = forms::find_question(
-                    form_package,
-                    parsed_db
-                        .strings
-                        .get(&guid)
-                        .context(format!("Failed to get string packages using GUID {}", guid))?,
-                    &question_variations,
-                ) {
-                    let mut get_resp = GetResponse {
-                        selector: guid.to_owned(),
-                        backend: Backend::Hii,
-                        is_translated,
-                        // mapping the hii module's QuestionDescriptor to thrift codegen's Question
-                        question: Question {
-                            name: question_descriptor.question,
-                            answer: question_descriptor.value,
-                            help: question_descriptor.help,
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    };
+        // read from a HiiDB dump if there is one instead of extracting HiiDB from the machine
+        let parsed_db = match &options.db {
+            Some(db) => Rc::new(package::read_db(db)?),
+            None => read_hii_db()?,
+        };
 
-                    if is_translated {
-                        get_resp.question.answer =
-                            translate_response(question, &get_resp.question.answer, Backend::Hii);
-                    }
+        let matches = find_hii_questions(&parsed_db, &question_variations, selector)?;
+        if matches.is_empty() && options.fuzzy {
+            return Err(suggest_hii_questions(&parsed_db, question)?.into());
+        }
 
-                    for opt in question_descriptor.possible_options {
-                        get_resp.question.options.push(opt.value)
-                    }
+        // a question which shows up in multiple forms but is stored in the same place is only shown once
+        let mut identities = BTreeSet::new();
+        let matches = matches
+            .into_iter()
+            .filter(|m| identities.insert(m.descriptor.identity()));
+
+        for HiiMatch {
+            package_list,
+            descriptor: question_descriptor,
+        } in matches
+        {
+            let is_checkbox = question_descriptor.is_checkbox();
+            let mut get_resp = GetResponse {
+                selector: package_list,
+                backend: Backend::Hii,
+                is_translated,
+                // mapping the hii module's QuestionDescriptor to thrift codegen's Question
+                question: Question {
+                    name: question_descriptor.question,
+                    answer: question_descriptor.value,
+                    help: question_descriptor.help,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
 
-                    resp.push(get_resp)
+            // checkboxes store 1/0, show them like any other boolean
+            if is_checkbox {
+                if let CanonicalAnswer::Integer {
+                    value: value @ 0..=1,
+                    unit: None,
+                } = CanonicalAnswer::parse(&get_resp.question.answer)
+                {
+                    get_resp.question.answer = CanonicalAnswer::Boolean(value == 1).to_string();
                 }
             }
+
+            get_resp.question.answer =
+                translate_response(question, &get_resp.question.answer, Backend::Hii);
+
+            for opt in question_descriptor.possible_options {
+                get_resp.question.options.push(opt.value)
+            }
+
+            resp.push(get_resp)
         }
 
         Ok(GetResponseList {
@@ -317,23 +1126,189 @@ impl SettingsBackend for HiiBackend {
     }
 }
 
-pub struct IloBackend {}
-
-impl IloBackend {
-    /// list all bios setting attributes provided by ilo
-    pub fn show_attributes() -> Result<Vec<IloAttributes>> {
-        let mut resp = Vec::new();
+/// HiiMatch is a question found in HiiDB along with the package list it was found in
+struct HiiMatch {
+    package_list: String,
+    descriptor: QuestionDescriptor,
+}
 
-        let machine_type = requests::identify_hpe_machine_type()?;
-        let ilo_device = requests::get_device_instance(machine_type);
+impl HiiMatch {
+    /// selected returns true if the selector is the package list GUID, the FormSet GUID
+    /// or the title of the FormSet or Form containing this question
+    fn selected(&self, selector: &str) -> bool {
+        let selector = selector.trim();
+        self.package_list.eq_ignore_ascii_case(selector)
+            || self.descriptor.formset_guid.eq_ignore_ascii_case(selector)
+            || self
+                .descriptor
+                .breadcrumb
+                .iter()
+                .any(|title| title.eq_ignore_ascii_case(selector))
+    }
 
-        // Regular BIOS Settings
-        let ilo_bios_settings = IloAttributes {
-            selector: ilo_device.bios_settings_selector(),
-            attributes: btreemap_from_redfish_attributes(ilo_device.get_current_bios_settings()?),
-            ..Default::default()
-        };
-        resp.push(ilo_bios_settings);
+    /// describe returns a one line description used to tell candidates apart
+    fn describe(&self) -> String {
+        let mut breadcrumb = self.descriptor.breadcrumb.clone();
+        breadcrumb.push(self.descriptor.question.trim().to_owned());
+        format!(
+            "{} ({}): {} [{}]",
+            self.package_list,
+            self.descriptor.formset_guid,
+            breadcrumb.join(" > "),
+            self.descriptor.identity()
+        )
+    }
+}
+
+/// find_hii_option returns the option which matches one of the answer variations.
+/// Exact spellings are preferred, then options which mean the same (like "Enable" for "Enabled").
+fn find_hii_option<'a>(
+    options: &'a [forms::AnswerOption],
+    answer_variations: &HashSet<String>,
+) -> Option<&'a forms::AnswerOption> {
+    options
+        .iter()
+        .find(|opt| {
+            answer_variations
+                .iter()
+                .any(|variation| variation.eq_ignore_ascii_case(&opt.value))
+        })
+        .or_else(|| {
+            options.iter().find(|opt| {
+                answer_variations
+                    .iter()
+                    .any(|variation| answers::answers_equivalent(variation, &opt.value))
+            })
+        })
+}
+
+/// find_hii_questions looks for question_variations in every form package of the parsed HiiDB
+/// and returns all matches which satisfy the (optional) selector
+fn find_hii_questions(
+    parsed_db: &package::ParsedHiiDB,
+    question_variations: &HashSet<String>,
+    selector: Option<&str>,
+) -> Result<Vec<HiiMatch>> {
+    let mut res = Vec::new();
+
+    for (guid, package_list) in &parsed_db.forms {
+        let string_packages = parsed_db
+            .strings
+            .get(guid)
+            .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+        for form_package in package_list {
+            for descriptor in forms::find_questions(
+                Rc::clone(form_package),
+                string_packages,
+                question_variations,
+            ) {
+                let found = HiiMatch {
+                    package_list: guid.to_owned(),
+                    descriptor,
+                };
+                if selector.is_none_or(|s| found.selected(s)) {
+                    res.push(found);
+                }
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// check_hii_question checks a single hii mapping of the spellings database against the parsed HiiDB
+fn check_hii_question(
+    parsed_db: &package::ParsedHiiDB,
+    question: &str,
+    source: String,
+    hii_question: &HiiQuestion,
+) -> Result<HiiTranslationCheck> {
+    let mut check = HiiTranslationCheck {
+        question: question.to_owned(),
+        source,
+        ..Default::default()
+    };
+
+    // each variation is looked up on its own so that the ones which never match can be reported
+    let mut identities = BTreeSet::new();
+    let mut matches = Vec::new();
+    for variation in &hii_question.question_variations {
+        let found = find_hii_questions(parsed_db, &HashSet::from([variation.to_owned()]), None)?;
+        if found.is_empty() {
+            check.unmatched_variations.push(variation.to_owned());
+            continue;
+        }
+        check.matched_variations.push(variation.to_owned());
+        matches.extend(
+            found
+                .into_iter()
+                .filter(|m| identities.insert(m.descriptor.identity())),
+        );
+    }
+
+    if matches.len() > 1 {
+        check.ambiguous_matches = matches.iter().map(HiiMatch::describe).collect();
+    }
+
+    for (answer, replacements) in hii_question.answer_replacements.iter().flatten() {
+        let replacements: HashSet<String> = replacements.iter().cloned().collect();
+        for m in &matches {
+            let options = &m.descriptor.possible_options;
+            if !options.is_empty() && find_hii_option(options, &replacements).is_none() {
+                check
+                    .missing_answers
+                    .push(format!("{} ({})", answer, m.describe()));
+            }
+        }
+    }
+
+    Ok(check)
+}
+
+/// suggest_hii_questions builds a NotFound error listing the prompts in HiiDB closest to the question
+fn suggest_hii_questions(
+    parsed_db: &package::ParsedHiiDB,
+    question: &str,
+) -> Result<QuestionError> {
+    let mut prompts = BTreeSet::new();
+    for (guid, package_list) in &parsed_db.forms {
+        let string_packages = parsed_db
+            .strings
+            .get(guid)
+            .context(format!("Failed to get string packages using GUID {}", guid))?;
+
+        for form_package in package_list {
+            prompts.extend(forms::list_prompts(
+                Rc::clone(form_package),
+                string_packages,
+            ));
+        }
+    }
+
+    Ok(QuestionError::NotFound {
+        question: question.to_owned(),
+        suggestions: normalize::closest(question, &prompts),
+    })
+}
+
+pub struct IloBackend {}
+
+impl IloBackend {
+    /// list all bios setting attributes provided by ilo
+    pub fn show_attributes() -> Result<Vec<IloAttributes>> {
+        let mut resp = Vec::new();
+
+        let machine_type = requests::identify_hpe_machine_type()?;
+        let ilo_device = requests::get_device_instance(machine_type);
+
+        // Regular BIOS Settings
+        let ilo_bios_settings = IloAttributes {
+            selector: ilo_device.bios_settings_selector(),
+            attributes: btreemap_from_redfish_attributes(ilo_device.get_current_bios_settings()?),
+            ..Default::default()
+        };
+        resp.push(ilo_bios_settings);
 
         if machine_type != IloDevice::Ilo4 {
             // Debug Settings
@@ -407,9 +1382,48 @@ impl IloBackend {
 }
 
 impl SettingsBackend for IloBackend {
-    fn set(question: &str, new_value: &str, _selector: Option<&str>) -> Result<SetResponseList> {
-        // TODO: use selector to only change values which match question + selector
+    fn set_with_options(
+        question: &str,
+        new_value: &str,
+        selector: Option<&str>,
+        options: &SetOptions,
+    ) -> Result<SetResponseList> {
+        let _dry_run = options.dry_run.then(DryRun::start);
+        let res =
+            get_composite_steps(question, new_value, Backend::Ilo).and_then(|steps| match steps {
+                Some(steps) => set_composite(
+                    question,
+                    &steps,
+                    selector,
+                    options,
+                    Self::set_question,
+                    Self::get_question,
+                ),
+                None => Self::set_question(question, new_value, selector, options),
+            });
+        audit_failed_set("ilo", question, new_value, selector, res)
+    }
+
+    fn get_with_options(
+        question: &str,
+        selector: Option<&str>,
+        options: &GetOptions,
+    ) -> Result<GetResponseList> {
+        match get_composite_questions(question, Backend::Ilo) {
+            Some(questions) => get_composite(&questions, selector, options, Self::get_question),
+            None => Self::get_question(question, selector, options),
+        }
+    }
+}
 
+impl IloBackend {
+    /// set_question changes a single (non-composite) question
+    fn set_question(
+        question: &str,
+        new_value: &str,
+        selector: Option<&str>,
+        _options: &SetOptions,
+    ) -> Result<SetResponseList> {
         let mut resp = Vec::new();
 
         let ilo_translation = get_qa_variations_ilo(question, new_value);
@@ -426,8 +1440,18 @@ impl SettingsBackend for IloBackend {
 
         // BIOS Settings
         let current_bios_settings = ilo_device.get_current_bios_settings()?;
-        if let Some(Value::String(_)) = current_bios_settings.get(&translated_question) {
-            ilo_device.update_bios_setting(&translated_question, &translated_new_value)?;
+        if !ilo_selected(selector, &ilo_device.bios_settings_selector()) {
+            debug!("skipping bios settings because of selector");
+        } else if let Some(Value::String(current)) = current_bios_settings.get(&translated_question)
+        {
+            let new_attribute_value = ilo_answer_like(&translated_new_value, current);
+            ilo_device.update_bios_setting(&translated_question, &new_attribute_value)?;
+            record_ilo_change(
+                &ilo_device.bios_settings_selector(),
+                &translated_question,
+                current,
+                &new_attribute_value,
+            );
 
             let set_resp = SetResponse {
                 selector: ilo_device.bios_settings_selector(),
@@ -442,19 +1466,28 @@ impl SettingsBackend for IloBackend {
                 ..Default::default()
             };
 
-            resp.push(set_resp)
+            resp.push(with_planned_writes(set_resp))
         }
 
         if machine_type != IloDevice::Ilo4 {
             // Debug Settings
-            if let Some(Value::String(_)) =
+            if !ilo_selected(selector, &Ilo5Dev::debug_settings_selector()) {
+                debug!("skipping debug settings because of selector");
+            } else if let Some(Value::String(current)) =
                 Ilo5Dev::get_current_debug_settings(machine_type)?.get(&translated_question)
             {
+                let new_attribute_value = ilo_answer_like(&translated_new_value, current);
                 Ilo5Dev::update_debug_setting(
                     machine_type,
                     &translated_question,
-                    &translated_new_value,
+                    &new_attribute_value,
                 )?;
+                record_ilo_change(
+                    &Ilo5Dev::debug_settings_selector(),
+                    &translated_question,
+                    current,
+                    &new_attribute_value,
+                );
 
                 let set_resp = SetResponse {
                     selector: Ilo5Dev::debug_settings_selector(),
@@ -469,18 +1502,27 @@ impl SettingsBackend for IloBackend {
                     ..Default::default()
                 };
 
-                resp.push(set_resp)
+                resp.push(with_planned_writes(set_resp))
             }
 
             // Service Settings
-            if let Some(Value::String(_)) =
+            if !ilo_selected(selector, &Ilo5Dev::service_settings_selector()) {
+                debug!("skipping service settings because of selector");
+            } else if let Some(Value::String(current)) =
                 Ilo5Dev::get_current_service_settings(machine_type)?.get(&translated_question)
             {
+                let new_attribute_value = ilo_answer_like(&translated_new_value, current);
                 Ilo5Dev::update_service_setting(
                     machine_type,
                     &translated_question,
-                    &translated_new_value,
+                    &new_attribute_value,
                 )?;
+                record_ilo_change(
+                    &Ilo5Dev::service_settings_selector(),
+                    &translated_question,
+                    current,
+                    &new_attribute_value,
+                );
 
                 let set_resp = SetResponse {
                     selector: Ilo5Dev::service_settings_selector(),
@@ -495,7 +1537,7 @@ impl SettingsBackend for IloBackend {
                     ..Default::default()
                 };
 
-                resp.push(set_resp)
+                resp.push(with_planned_writes(set_resp))
             }
         }
 
@@ -505,9 +1547,12 @@ impl SettingsBackend for IloBackend {
         })
     }
 
-    fn get(question: &str, _selector: Option<&str>) -> Result<GetResponseList> {
-        // TODO: use selector to only get questions which match question + selector
-
+    /// get_question reads a single (non-composite) question
+    fn get_question(
+        question: &str,
+        selector: Option<&str>,
+        _options: &GetOptions,
+    ) -> Result<GetResponseList> {
         let mut resp = Vec::new();
 
         let machine_type = requests::identify_hpe_machine_type()?;
@@ -539,6 +1584,9 @@ impl SettingsBackend for IloBackend {
 
         // look for the question in all settings collections including bios and hidden collections like debug, service
         for (attributes, settings_selector) in setting_collections {
+            if !ilo_selected(selector, &settings_selector) {
+                continue;
+            }
             if let Some(Value::String(s)) = attributes.get(&translated_question) {
                 let mut get_resp = GetResponse {
                     selector: settings_selector,
@@ -552,10 +1600,8 @@ impl SettingsBackend for IloBackend {
                     ..Default::default()
                 };
 
-                if is_translated {
-                    get_resp.question.answer =
-                        translate_response(question, &get_resp.question.answer, Backend::Ilo);
-                }
+                get_resp.question.answer =
+                    translate_response(question, &get_resp.question.answer, Backend::Ilo);
 
                 resp.push(get_resp)
             }
@@ -568,11 +1614,773 @@ impl SettingsBackend for IloBackend {
     }
 }
 
+/// record_ilo_change journals an attribute changed by set and sends its audit event
+/// (nothing is changed during a dry run)
+fn record_ilo_change(selector: &str, attribute: &str, previous_value: &str, new_value: &str) {
+    if !dry_run::is_dry_run() {
+        journal::record(JournalEntry::new(
+            ProfileBackend::Ilo,
+            selector,
+            attribute,
+            previous_value,
+            new_value,
+        ));
+        audit::emit(&AuditEvent {
+            backend: "ilo",
+            selector: selector.to_owned(),
+            question: attribute.to_owned(),
+            old_value: previous_value.to_owned(),
+            new_value: new_value.to_owned(),
+            result: AuditResult::Changed,
+            error: String::new(),
+        });
+    }
+}
+
+/// audit_failed_set sends the audit event of a set which failed and passes its result on.
+/// Nothing is changed during a dry run, so a failed dry run isn't audited.
+fn audit_failed_set<T>(
+    backend: &'static str,
+    question: &str,
+    new_value: &str,
+    selector: Option<&str>,
+    res: Result<T>,
+) -> Result<T> {
+    if let Err(why) = &res {
+        if !dry_run::is_dry_run() {
+            audit::emit(&AuditEvent {
+                backend,
+                selector: selector.unwrap_or_default().to_owned(),
+                question: question.to_owned(),
+                old_value: String::new(),
+                new_value: new_value.to_owned(),
+                result: AuditResult::Failed,
+                error: format!("{:#}", why),
+            });
+        }
+    }
+    res
+}
+
+/// ilo_selected returns true if there is no selector or if it matches the settings collection (like bios or debug)
+fn ilo_selected(selector: Option<&str>, settings_selector: &str) -> bool {
+    selector.is_none_or(|s| s.trim().eq_ignore_ascii_case(settings_selector))
+}
+
+/// ilo_answer_like spells boolean answers the way the attribute's current value is spelled
+/// Ex: setting "Enabled" on an attribute which is currently "PresentDisabled" sends "PresentEnabled"
+fn ilo_answer_like(new_value: &str, current: &str) -> String {
+    match CanonicalAnswer::parse(new_value) {
+        CanonicalAnswer::Boolean(b) => answers::spell_boolean_like(b, current)
+            .map(str::to_owned)
+            .unwrap_or_else(|| new_value.to_owned()),
+        _ => new_value.to_owned(),
+    }
+}
+
+/// with_planned_writes attaches the writes planned by a dry run to the response of the question
+/// they were planned for. Nothing has been modified during a dry run.
+fn with_planned_writes(mut resp: SetResponse) -> SetResponse {
+    if dry_run::is_dry_run() {
+        resp.modified = false;
+        resp.planned_writes = Some(
+            dry_run::take_planned_writes()
+                .into_iter()
+                .map(planned_write)
+                .collect(),
+        );
+    }
+    resp
+}
+
+fn planned_write(write: PlannedWrite) -> uefisettings_backend_thrift::PlannedWrite {
+    let hex = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    match write {
+        PlannedWrite::Efivar {
+            path,
+            offset,
+            old_bytes,
+            new_bytes,
+        } => uefisettings_backend_thrift::PlannedWrite {
+            target: path,
+            offset: offset.into(),
+            old_bytes: hex(&old_bytes),
+            new_bytes: hex(&new_bytes),
+            ..Default::default()
+        },
+        PlannedWrite::Patch { endpoint, body } => uefisettings_backend_thrift::PlannedWrite {
+            target: endpoint,
+            body,
+            ..Default::default()
+        },
+    }
+}
+
+/// set_composite applies the steps of a composite setting in order as one set.
+/// The current answers of every step are read before anything is changed so that if a step fails
+/// the steps which were already applied (including the failed one) can be changed back.
+fn set_composite<S, G>(
+    question: &str,
+    steps: &[CompositeStep],
+    selector: Option<&str>,
+    options: &SetOptions,
+    set: S,
+    get: G,
+) -> Result<SetResponseList>
+where
+    S: Fn(&str, &str, Option<&str>, &SetOptions) -> Result<SetResponseList>,
+    G: Fn(&str, Option<&str>, &GetOptions) -> Result<GetResponseList>,
+{
+    // fail before changing anything if one of the steps doesn't exist on this machine
+    let mut previous_answers = Vec::new();
+    for step in steps {
+        let current = get(&step.question, selector, &GetOptions::default())?;
+        if current.responses.is_empty() {
+            return Err(anyhow!(
+                "composite setting '{}' can't be applied because '{}' wasn't found",
+                question,
+                step.question
+            ));
+        }
+        previous_answers.push(current.responses);
+    }
+
+    let mut resp = Vec::new();
+    for (i, step) in steps.iter().enumerate() {
+        let result = set(&step.question, &step.answer, selector, options).and_then(|step_resp| {
+            if step_resp.responses.is_empty() {
+                Err(anyhow!("'{}' wasn't modified", step.question))
+            } else {
+                Ok(step_resp)
+            }
+        });
+
+        match result {
+            Ok(step_resp) => resp.extend(step_resp.responses),
+            Err(why) => {
+                let mut rollback_errors = Vec::new();
+                for (step, answers) in steps[..=i].iter().zip(&previous_answers).rev() {
+                    for answer in answers {
+                        // --all because every match in this selector had this answer before
+                        let rollback_options = SetOptions {
+                            all: true,
+                            ..Default::default()
+                        };
+                        if let Err(rollback_why) = set(
+                            &step.question,
+                            &answer.question.answer,
+                            Some(&answer.selector),
+                            &rollback_options,
+                        ) {
+                            rollback_errors.push(format!(
+                                "'{}' in {}: {:#}",
+                                step.question, answer.selector, rollback_why
+                            ));
+                        }
+                    }
+                }
+
+                let mut msg = format!(
+                    "composite setting '{}' failed at step {} ('{}' = '{}')",
+                    question,
+                    i + 1,
+                    step.question,
+                    step.answer
+                );
+                if rollback_errors.is_empty() {
+                    msg.push_str(", previous steps were rolled back");
+                } else {
+                    write!(
+                        msg,
+                        ", rollback failed for:\n{}",
+                        rollback_errors.join("\n")
+                    )?;
+                }
+                return Err(why.context(msg));
+            }
+        }
+    }
+
+    Ok(SetResponseList {
+        responses: resp,
+        ..Default::default()
+    })
+}
+
+/// get_composite returns the answers of every question changed by a composite setting
+fn get_composite<G>(
+    questions: &[String],
+    selector: Option<&str>,
+    options: &GetOptions,
+    get: G,
+) -> Result<GetResponseList>
+where
+    G: Fn(&str, Option<&str>, &GetOptions) -> Result<GetResponseList>,
+{
+    let mut resp = Vec::new();
+    for question in questions {
+        resp.extend(get(question, selector, options)?.responses);
+    }
+
+    Ok(GetResponseList {
+        responses: resp,
+        ..Default::default()
+    })
+}
+
+/// load_spellings_db merges spellings files from /etc/uefisettings/spellings.d and then from
+/// spellings_path (a file or a directory) over the built-in spellings database.
+/// Call it before any get/set, otherwise only the built-in database and /etc/uefisettings/spellings.d are used.
+pub fn load_spellings_db(spellings_path: Option<&Path>) -> Result<&'static SpellingsDB> {
+    translation::load_spellings_db(spellings_path)
+}
+
+/// spellings_db returns the spellings database which is used for translating questions and answers
+pub fn spellings_db() -> &'static SpellingsDB {
+    translation::spellings_db()
+}
+
+thread_local! {
+    /// HiiDB of the Session which is active on this thread, see read_hii_db
+    static HII_SESSION: RefCell<Option<HiiSession>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct HiiSession {
+    db: Option<Rc<package::ParsedHiiDB>>,
+}
+
+/// Session makes every get and set until it's dropped use the HiiDB which was extracted and parsed
+/// for the first question and reuse the responses of the iLO, instead of doing both for every question
+struct Session {
+    _ilo_responses: requests::ResponseCache,
+    _machine: translation::MachineCache,
+}
+
+impl Session {
+    fn start() -> Self {
+        HII_SESSION.with(|session| *session.borrow_mut() = Some(HiiSession::default()));
+        Session {
+            _ilo_responses: requests::ResponseCache::start(),
+            _machine: translation::MachineCache::start(),
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        HII_SESSION.with(|session| *session.borrow_mut() = None);
+    }
+}
+
+/// read_hii_db extracts and parses HiiDB, or returns the one parsed earlier in the same Session.
+/// Setting questions only changes efivars so HiiDB stays the same for the whole session.
+fn read_hii_db() -> Result<Rc<package::ParsedHiiDB>> {
+    if let Some(db) = HII_SESSION.with(|session| {
+        session
+            .borrow()
+            .as_ref()
+            .and_then(|session| session.db.clone())
+    }) {
+        return Ok(db);
+    }
+
+    let db = Rc::new(package::read_db(&extract::extract_db()?)?);
+    HII_SESSION.with(|session| {
+        if let Some(session) = session.borrow_mut().as_mut() {
+            session.db = Some(Rc::clone(&db));
+        }
+    });
+    Ok(db)
+}
+
+/// load_profile reads a profile (the answers questions should have) from a YAML or JSON file
+pub fn load_profile(path: &Path) -> Result<Profile> {
+    profile::load_profile(path)
+}
+
+/// apply_profile changes every question of a profile which doesn't already have its answer.
+/// Settings without a backend use default_backend. Every setting gets a response with its status,
+/// a setting which fails doesn't stop the others. Applying the same profile again changes nothing.
+pub fn apply_profile(
+    profile: &Profile,
+    machine: &MachineInfo,
+    default_backend: Backend,
+) -> SetResponseList {
+    let _session = Session::start();
+    // hii settings are written together at the end so that they either all land or none of them
+    let batch = WriteBatch::start();
+
+    let mut responses = Vec::new();
+    for setting in &profile.settings {
+        let backend = profile_backend(setting, default_backend);
+        let res = if !machine.backend.contains(&backend) {
+            Err(anyhow!(
+                "this machine doesn't have the {:?} backend",
+                backend
+            ))
+        } else if backend == Backend::Ilo {
+            apply_setting::<IloBackend>(setting, backend)
+        } else {
+            apply_setting::<HiiBackend>(setting, backend)
+        };
+
+        match res {
+            Ok(applied) => responses.extend(applied),
+            Err(e) => responses.push(SetResponse {
+                selector: setting.selector.clone().unwrap_or_default(),
+                backend,
+                question: Question {
+                    name: setting.question.clone(),
+                    answer: setting.answer.clone(),
+                    ..Default::default()
+                },
+                status: Some(SetStatus::Failed),
+                error: Some(format!("{:#}", e)),
+                ..Default::default()
+            }),
+        }
+    }
+
+    let hii_changed = |resp: &SetResponse| {
+        resp.backend == Backend::Hii && resp.status == Some(SetStatus::Changed)
+    };
+    match batch.commit() {
+        Ok(()) => {
+            for resp in responses.iter_mut().filter(|resp| hii_changed(resp)) {
+                HiiBackend::read_back_answer(resp);
+            }
+        }
+        Err(e) => {
+            for resp in responses.iter_mut().filter(|resp| hii_changed(resp)) {
+                resp.modified = false;
+                resp.status = Some(SetStatus::Failed);
+                resp.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+
+    SetResponseList {
+        responses,
+        ..Default::default()
+    }
+}
+
+/// undo reverts journaled changes through the backend which made them, newest first: the change
+/// with id if it's given, otherwise the last `last` changes which can be undone. A change is only
+/// reverted if its question still has the value it was changed to, and reverting stops at the
+/// first change which can't be reverted. Reverts are journaled too but can't be undone.
+pub fn undo(last: usize, id: Option<u64>) -> Result<UndoResultList> {
+    let _session = Session::start();
+
+    let entries = journal::read_journal()?;
+    let undoable = journal::undoable(&entries);
+    let selected = match id {
+        Some(id) => {
+            if !entries.iter().any(|entry| entry.id == id) {
+                return Err(anyhow!("the journal has no entry {}", id));
+            }
+            let entry = undoable
+                .into_iter()
+                .find(|entry| entry.id == id)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "journal entry {} was undone already or is an undo itself",
+                        id
+                    )
+                })?;
+            vec![entry]
+        }
+        None => undoable.into_iter().take(last).collect(),
+    };
+
+    let mut results = Vec::new();
+    for entry in selected {
+        let (backend, res) = match entry.backend {
+            ProfileBackend::Hii => (Backend::Hii, undo_hii(entry)),
+            ProfileBackend::Ilo => (Backend::Ilo, undo_ilo(entry)),
+        };
+        let failed = res.is_err();
+        results.push(UndoResult {
+            id: entry.id as i64,
+            timestamp: entry.timestamp as i64,
+            backend,
+            selector: entry.selector.clone(),
+            question: entry.question.clone(),
+            restored_value: entry.previous_value.clone(),
+            error: res.err().map(|e| format!("{:#}", e)).unwrap_or_default(),
+            ..Default::default()
+        });
+        if failed {
+            break;
+        }
+    }
+
+    Ok(UndoResultList {
+        results,
+        ..Default::default()
+    })
+}
+
+/// undo_hii writes the previous value of a journaled change back to the varstore
+fn undo_hii(entry: &JournalEntry) -> Result<()> {
+    let parsed_db = read_hii_db()?;
+    let descriptor = stored_questions(&parsed_db)?
+        .into_iter()
+        .map(|question| question.descriptor)
+        .find(|descriptor| descriptor.identity() == entry.identity)
+        .ok_or_else(|| anyhow!("no question is stored at {} anymore", entry.identity))?;
+    let current = descriptor.raw_value()?.to_string();
+    if current != entry.new_value {
+        return Err(anyhow!(
+            "refusing to undo, '{}' has the value {} instead of {} which was written",
+            entry.question,
+            current,
+            entry.new_value
+        ));
+    }
+
+    let batch = WriteBatch::start();
+    write_raw_value(&descriptor, &entry.previous_value)?;
+    batch::record_journal_entry(JournalEntry {
+        identity: entry.identity.clone(),
+        reverts: Some(entry.id),
+        ..JournalEntry::new(
+            ProfileBackend::Hii,
+            &entry.selector,
+            &entry.question,
+            &entry.new_value,
+            &entry.previous_value,
+        )
+    });
+    batch::record_audit_event(AuditEvent {
+        backend: "hii",
+        selector: entry.selector.clone(),
+        question: entry.question.clone(),
+        old_value: entry.new_value.clone(),
+        new_value: entry.previous_value.clone(),
+        result: AuditResult::Changed,
+        error: String::new(),
+    });
+    batch.commit()
+}
+
+/// undo_ilo changes a journaled attribute back to its previous value. Changes are pending until
+/// the next reboot, so the written value is looked for in the pending settings.
+fn undo_ilo(entry: &JournalEntry) -> Result<()> {
+    let machine_type = requests::identify_hpe_machine_type()?;
+    let ilo_device = requests::get_device_instance(machine_type);
+
+    let pending = if entry.selector == ilo_device.bios_settings_selector() {
+        ilo_device.get_pending_bios_settings()?
+    } else if entry.selector == Ilo5Dev::debug_settings_selector() {
+        Ilo5Dev::get_pending_debug_settings(machine_type)?
+    } else if entry.selector == Ilo5Dev::service_settings_selector() {
+        Ilo5Dev::get_pending_service_settings(machine_type)?
+    } else {
+        return Err(anyhow!("unknown ilo selector {}", entry.selector));
+    };
+    match pending.get(&entry.question) {
+        Some(Value::String(current)) if *current == entry.new_value => {}
+        Some(Value::String(current)) => {
+            return Err(anyhow!(
+                "refusing to undo, '{}' has the value {} instead of {} which was written",
+                entry.question,
+                current,
+                entry.new_value
+            ))
+        }
+        _ => return Err(anyhow!("attribute '{}' not found", entry.question)),
+    }
+
+    if entry.selector == ilo_device.bios_settings_selector() {
+        ilo_device.update_bios_setting(&entry.question, &entry.previous_value)?;
+    } else if entry.selector == Ilo5Dev::debug_settings_selector() {
+        Ilo5Dev::update_debug_setting(machine_type, &entry.question, &entry.previous_value)?;
+    } else {
+        Ilo5Dev::update_service_setting(machine_type, &entry.question, &entry.previous_value)?;
+    }
+    journal::record(JournalEntry {
+        reverts: Some(entry.id),
+        ..JournalEntry::new(
+            ProfileBackend::Ilo,
+            &entry.selector,
+            &entry.question,
+            &entry.new_value,
+            &entry.previous_value,
+        )
+    });
+    audit::emit(&AuditEvent {
+        backend: "ilo",
+        selector: entry.selector.clone(),
+        question: entry.question.clone(),
+        old_value: entry.new_value.clone(),
+        new_value: entry.previous_value.clone(),
+        result: AuditResult::Changed,
+        error: String::new(),
+    });
+    Ok(())
+}
+
+/// check_profile compares the current answers of the questions of a profile with their answers in
+/// the profile without changing anything. Settings without a backend use default_backend.
+/// Questions which can't be read are reported as mismatches too.
+pub fn check_profile(
+    profile: &Profile,
+    machine: &MachineInfo,
+    default_backend: Backend,
+) -> ProfileCheckReport {
+    let _session = Session::start();
+
+    let mut mismatches = Vec::new();
+    for setting in &profile.settings {
+        let backend = profile_backend(setting, default_backend);
+        let res = if !machine.backend.contains(&backend) {
+            Err(anyhow!(
+                "this machine doesn't have the {:?} backend",
+                backend
+            ))
+        } else if backend == Backend::Ilo {
+            check_setting::<IloBackend>(setting, backend)
+        } else {
+            check_setting::<HiiBackend>(setting, backend)
+        };
+
+        match res {
+            Ok(found) => mismatches.extend(found),
+            Err(e) => mismatches.push(ProfileMismatch {
+                question: setting.question.clone(),
+                expected: setting.answer.clone(),
+                backend,
+                selector: setting.selector.clone().unwrap_or_default(),
+                error: format!("{:#}", e),
+                ..Default::default()
+            }),
+        }
+    }
+
+    ProfileCheckReport {
+        checked: profile.settings.len().try_into().unwrap_or(i32::MAX),
+        mismatches,
+        ..Default::default()
+    }
+}
+
+fn profile_backend(setting: &ProfileSetting, default_backend: Backend) -> Backend {
+    match setting.backend {
+        Some(ProfileBackend::Hii) => Backend::Hii,
+        Some(ProfileBackend::Ilo) => Backend::Ilo,
+        None => default_backend,
+    }
+}
+
+/// check_setting returns a mismatch for every place a question of a profile doesn't have its answer
+fn check_setting<B: SettingsBackend>(
+    setting: &ProfileSetting,
+    backend: Backend,
+) -> Result<Vec<ProfileMismatch>> {
+    let current = B::get(&setting.question, setting.selector.as_deref())?;
+    if current.responses.is_empty() {
+        return Err(anyhow!("question '{}' wasn't found", setting.question));
+    }
+    if has_answer(&setting.question, &setting.answer, backend, &current) {
+        return Ok(Vec::new());
+    }
+
+    let mismatch = |selector: &str, actual: String| ProfileMismatch {
+        question: setting.question.clone(),
+        expected: setting.answer.clone(),
+        actual,
+        backend,
+        selector: selector.to_owned(),
+        ..Default::default()
+    };
+    if get_composite_questions(&setting.question, backend).is_some() {
+        // the answers of its questions don't add up to the answer of the composite setting
+        let actual = current
+            .responses
+            .iter()
+            .map(|resp| format!("{}: {}", resp.question.name, resp.question.answer))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Ok(vec![mismatch(
+            setting.selector.as_deref().unwrap_or_default(),
+            actual,
+        )]);
+    }
+    Ok(current
+        .responses
+        .into_iter()
+        .filter(|resp| {
+            !answer_matches(
+                &setting.question,
+                &setting.answer,
+                backend,
+                &resp.question.answer,
+            )
+        })
+        .map(|resp| mismatch(&resp.selector, resp.question.answer))
+        .collect())
+}
+
+/// apply_setting sets a question of a profile if it doesn't already have the answer
+fn apply_setting<B: SettingsBackend>(
+    setting: &ProfileSetting,
+    backend: Backend,
+) -> Result<Vec<SetResponse>> {
+    let selector = setting.selector.as_deref();
+
+    let current = B::get(&setting.question, selector)?;
+    if current.responses.is_empty() {
+        return Err(anyhow!("question '{}' wasn't found", setting.question));
+    }
+    if has_answer(&setting.question, &setting.answer, backend, &current) {
+        return Ok(current
+            .responses
+            .into_iter()
+            .map(|resp| SetResponse {
+                selector: resp.selector,
+                backend,
+                question: resp.question,
+                is_translated: resp.is_translated,
+                status: Some(SetStatus::Unchanged),
+                ..Default::default()
+            })
+            .collect());
+    }
+
+    let set = B::set(&setting.question, &setting.answer, selector)?;
+    if set.responses.is_empty() {
+        return Err(anyhow!("question '{}' wasn't changed", setting.question));
+    }
+    Ok(set
+        .responses
+        .into_iter()
+        .map(|resp| SetResponse {
+            status: Some(SetStatus::Changed),
+            ..resp
+        })
+        .collect())
+}
+
+/// has_answer returns true if every question get found already has the answer.
+/// For a composite setting the current answers of its questions have to match the steps of the answer.
+fn has_answer(question: &str, answer: &str, backend: Backend, current: &GetResponseList) -> bool {
+    if get_composite_questions(question, backend).is_some() {
+        let current: Vec<(String, String)> = current
+            .responses
+            .iter()
+            .map(|resp| (resp.question.name.clone(), resp.question.answer.clone()))
+            .collect();
+        return get_composite_answer(question, backend, &current)
+            .is_some_and(|current| current.eq_ignore_ascii_case(answer));
+    }
+
+    !current.responses.is_empty()
+        && current
+            .responses
+            .iter()
+            .all(|resp| answer_matches(question, answer, backend, &resp.question.answer))
+}
+
+/// answer_matches returns true if the current answer of a question (as shown by get) is the answer
+fn answer_matches(question: &str, answer: &str, backend: Backend, current: &str) -> bool {
+    // get translates answers, so a raw answer is translated the same way before comparing
+    answers::answers_equivalent(current, answer)
+        || answers::answers_equivalent(current, &translate_response(question, answer, backend))
+}
+
+/// set_captured_efivars_dir makes hii read varstores (the answers to questions) from a directory of
+/// captured efivars (ex: a copy of /sys/firmware/efi/efivars) instead of the machine's efivarfs.
+/// Nothing can be set while it is used. None goes back to using the machine's efivarfs.
+pub fn set_captured_efivars_dir(dir: Option<&Path>) -> Result<()> {
+    if let Some(dir) = dir {
+        if !dir.is_dir() {
+            return Err(anyhow!("{} isn't a directory", dir.display()));
+        }
+    }
+    efivarfs::set_captured_efivars_dir(dir.map(Path::to_path_buf));
+    Ok(())
+}
+
+/// set_system_root makes every system path (efivars, DMI, lock files, /dev/mem) relative to root
+/// instead of /, ex: to work on a mounted image of another system. If it isn't called, the root is
+/// taken from the UEFISETTINGS_ROOT environment variable. The efivarfs mount and immutability
+/// guards are skipped when the efivars under root aren't a real efivarfs.
+pub fn set_system_root(root: &Path) -> Result<()> {
+    if !root.is_dir() {
+        return Err(anyhow!("{} isn't a directory", root.display()));
+    }
+    system_root::set_system_root(SystemRoot::new(root));
+    Ok(())
+}
+
+/// capture_bundle saves everything which is read from this machine into a tar archive at path:
+/// HiiDB and the efivars of its varstores, DMI information, the current and pending iLO attributes
+/// and the version of this tool. Read-only commands can use it instead of the machine with replay_bundle.
+pub fn capture_bundle(path: &Path) -> Result<()> {
+    let machine = identify_machine();
+    let mut contents = BundleContents {
+        machine: machine.clone(),
+        ..Default::default()
+    };
+
+    if machine.backend.contains(&Backend::Hii) {
+        let db = extract::extract_db().context("failed to capture HiiDB")?;
+        let parsed_db = package::read_db(&db)?;
+        let efivars_dir = efivarfs::efivars_dir();
+        for form_package in parsed_db.forms.into_values().flatten() {
+            for name in forms::varstore_efivars(form_package) {
+                // not every varstore is backed by an efivar
+                match fs::read(efivars_dir.join(&name)) {
+                    Ok(data) => {
+                        contents.efivars.insert(name, data);
+                    }
+                    Err(why) => debug!("skipping varstore {}: {}", name, why),
+                }
+            }
+        }
+        contents.hiidb = Some(db);
+    }
+
+    if machine.backend.contains(&Backend::Ilo) {
+        // showing the pending attributes reads both the current and pending attributes of every collection
+        contents.ilo_responses =
+            requests::record_responses(|| IloBackend::show_pending_attributes().map(|_| ()))
+                .context("failed to capture iLO attributes")?;
+    }
+
+    let file = File::create(path).context(format!("failed to create {}", path.display()))?;
+    bundle::write_bundle(file, &contents)
+}
+
+/// replay_bundle makes everything be read from a bundle made by capture_bundle instead of this machine
+/// until the returned guard is dropped. Nothing can be changed while a bundle is replayed.
+pub fn replay_bundle(path: &Path) -> Result<BundleReplay> {
+    Ok(BundleReplay::new(Bundle::open(path)?))
+}
+
+/// cache_machine makes translations read the machine (or the machine of a replayed bundle) only once
+/// until the returned guard is dropped. Start it after replay_bundle so that the bundle's machine is used.
+pub fn cache_machine() -> MachineCache {
+    MachineCache::start()
+}
+
 /// auto-identify backend and get hardware/bios-information
 pub fn identify_machine() -> MachineInfo {
+    if let Some(bundle) = bundle::replayed_bundle() {
+        return bundle.machine().clone();
+    }
+
     let mut backend = BTreeSet::new();
 
-    if Path::new(extract::OCP_HIIDB_PATH).exists() {
+    if extract::ocp_hiidb_path().exists() {
         backend.insert(Backend::Hii);
     }
     if chif::check_ilo_connectivity().is_ok() {
@@ -588,28 +2396,84 @@ pub fn identify_machine() -> MachineInfo {
 
     MachineInfo {
         backend,
-        // the entries in /sys/class/dmi/id/ are plaintext and populated by the kernel
-        bios_vendor: read_file_contents(Path::new("/sys/class/dmi/id/bios_vendor")),
-        bios_version: read_file_contents(Path::new("/sys/class/dmi/id/bios_version")),
-        bios_release: read_file_contents(Path::new("/sys/class/dmi/id/bios_release")),
-        bios_date: read_file_contents(Path::new("/sys/class/dmi/id/bios_date")),
-        product_name: read_file_contents(Path::new("/sys/class/dmi/id/product_name")),
-        product_family: read_file_contents(Path::new("/sys/class/dmi/id/product_family")),
-        product_version: read_file_contents(Path::new("/sys/class/dmi/id/product_version")),
+        ..platform::read_dmi_info()
+    }
+}
+
+/// capabilities resolves every canonical question in the spellings database against every backend
+/// the machine supports and reports if it is supported along with its current (canonical) value
+pub fn capabilities(machine: &MachineInfo) -> CapabilitiesReport {
+    let _session = Session::start();
+    let capabilities = spellings_db()
+        .keys()
+        .map(|question| {
+            let mut backends = Vec::new();
+            if machine.backend.contains(&Backend::Hii) {
+                backends.push(backend_capability::<HiiBackend>(question, Backend::Hii));
+            }
+            if machine.backend.contains(&Backend::Ilo) {
+                backends.push(backend_capability::<IloBackend>(question, Backend::Ilo));
+            }
+            Capability {
+                question: question.to_owned(),
+                backends,
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    CapabilitiesReport {
+        machine: machine.clone(),
+        capabilities,
         ..Default::default()
     }
 }
 
-/// read_file_contents is a wrapper over std::fs::read_to_string but it
-/// returns an empty string if file can't be read / doesn't exist
-fn read_file_contents(file_path: &Path) -> String {
-    match fs::read_to_string(file_path) {
-        Ok(contents) => contents.trim().to_owned(),
-        Err(why) => {
-            debug!("Can't read {:?} because {}", file_path, why);
-            "".to_owned()
+/// backend_capability reads a canonical question (or every question of a composite setting)
+/// from one backend. A question is ambiguous if it resolves to more than one question.
+fn backend_capability<B: SettingsBackend>(question: &str, backend: Backend) -> BackendCapability {
+    let composite_questions = get_composite_questions(question, backend);
+    let questions = composite_questions
+        .clone()
+        .unwrap_or_else(|| vec![question.to_owned()]);
+
+    let mut capability = BackendCapability {
+        backend,
+        status: CapabilityStatus::Supported,
+        ..Default::default()
+    };
+    let mut current = Vec::new();
+
+    for q in questions {
+        let responses = match B::get(&q, None) {
+            Ok(res) => res.responses,
+            Err(why) => {
+                capability.status = CapabilityStatus::Unsupported;
+                capability.reason = Some(format!("{:#}", why));
+                break;
+            }
+        };
+
+        match responses.as_slice() {
+            [] => {
+                capability.status = CapabilityStatus::Unsupported;
+                capability.reason = Some(format!("question '{}' wasn't found", q));
+                break;
+            }
+            [response] => current.push((q, response.question.answer.to_owned())),
+            _ => capability.status = CapabilityStatus::Ambiguous,
         }
+        capability.responses.extend(responses);
+    }
+
+    if capability.status == CapabilityStatus::Supported {
+        capability.value = match composite_questions {
+            Some(_) => get_composite_answer(question, backend, &current),
+            None => current.pop().map(|(_, answer)| answer),
+        };
     }
+
+    capability
 }
 
 fn btreemap_from_redfish_attributes(attributes: RedfishAttributes) -> BTreeMap<String, String> {
@@ -638,3 +2502,709 @@ fn compare_redfish_attributes(
     }
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+    use std::sync::MutexGuard;
+
+    use super::*;
+    use crate::hii::batch;
+    use crate::hii::test_db;
+
+    fn step(question: &str, answer: &str) -> CompositeStep {
+        CompositeStep {
+            question: question.to_owned(),
+            answer: answer.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// FakeSettings is a backend which refuses to set "TPM State" to "Broken"
+    struct FakeSettings(RefCell<HashMap<String, String>>);
+
+    impl FakeSettings {
+        fn new(settings: &[(&str, &str)]) -> Self {
+            FakeSettings(RefCell::new(
+                settings
+                    .iter()
+                    .map(|(q, a)| (q.to_string(), a.to_string()))
+                    .collect(),
+            ))
+        }
+
+        fn set(
+            &self,
+            question: &str,
+            new_value: &str,
+            _: Option<&str>,
+            _: &SetOptions,
+        ) -> Result<SetResponseList> {
+            if new_value == "Broken" {
+                return Err(anyhow!("invalid option"));
+            }
+            let mut settings = self.0.borrow_mut();
+            let mut responses = Vec::new();
+            if let Some(answer) = settings.get_mut(question) {
+                *answer = new_value.to_owned();
+                responses.push(SetResponse {
+                    selector: "fake".to_owned(),
+                    modified: true,
+                    ..Default::default()
+                });
+            }
+            Ok(SetResponseList {
+                responses,
+                ..Default::default()
+            })
+        }
+
+        fn get(&self, question: &str, _: Option<&str>, _: &GetOptions) -> Result<GetResponseList> {
+            let mut responses = Vec::new();
+            if let Some(answer) = self.0.borrow().get(question) {
+                responses.push(GetResponse {
+                    selector: "fake".to_owned(),
+                    question: Question {
+                        name: question.to_owned(),
+                        answer: answer.to_owned(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            }
+            Ok(GetResponseList {
+                responses,
+                ..Default::default()
+            })
+        }
+
+        fn answer(&self, question: &str) -> String {
+            self.0.borrow()[question].to_owned()
+        }
+
+        fn set_composite(&self, steps: &[CompositeStep]) -> Result<SetResponseList> {
+            set_composite(
+                "TPM",
+                steps,
+                None,
+                &SetOptions::default(),
+                |q, v, sel, opts| self.set(q, v, sel, opts),
+                |q, sel, opts| self.get(q, sel, opts),
+            )
+        }
+    }
+
+    #[test]
+    fn test_set_composite() {
+        let settings = FakeSettings::new(&[
+            ("Security Device Support", "Disabled"),
+            ("TPM State", "Disabled"),
+        ]);
+
+        let resp = settings
+            .set_composite(&[
+                step("Security Device Support", "Enabled"),
+                step("TPM State", "Enabled"),
+            ])
+            .unwrap();
+        assert_eq!(resp.responses.len(), 2);
+        assert_eq!(settings.answer("Security Device Support"), "Enabled");
+        assert_eq!(settings.answer("TPM State"), "Enabled");
+    }
+
+    #[test]
+    fn test_set_composite_rollback() {
+        let settings = FakeSettings::new(&[
+            ("Security Device Support", "Disabled"),
+            ("TPM State", "Disabled"),
+        ]);
+
+        // the second step fails so the first one is rolled back
+        assert!(settings
+            .set_composite(&[
+                step("Security Device Support", "Enabled"),
+                step("TPM State", "Broken"),
+            ])
+            .is_err());
+        assert_eq!(settings.answer("Security Device Support"), "Disabled");
+        assert_eq!(settings.answer("TPM State"), "Disabled");
+
+        // nothing is changed if one of the questions doesn't exist
+        assert!(settings
+            .set_composite(&[
+                step("Security Device Support", "Enabled"),
+                step("Missing", "Enabled"),
+            ])
+            .is_err());
+        assert_eq!(settings.answer("Security Device Support"), "Disabled");
+    }
+
+    /// minimal_hiidb has a single formset with a "Test Setting" one-of question stored at
+    /// offset 0 of the Setup varstore (guid 11111111-1111-1111-1111-111111111111)
+    fn minimal_hiidb() -> Vec<u8> {
+        let guid = [0x11u8; 16];
+        test_db::package_list(
+            "11111111-1111-1111-1111-111111111111",
+            &[
+                "Test Formset",
+                "Test Setting",
+                "Help",
+                "Disabled",
+                "Enabled",
+            ],
+            &[test_db::form_set(
+                guid,
+                1,
+                3,
+                &[
+                    test_db::var_store(guid, 1, 8, "Setup"),
+                    test_db::form(1, 1, &[test_db::one_of(2, 3, 1, 1, 0, &[4, 5])]),
+                ],
+            )],
+        )
+    }
+
+    // system root and bundle replay are global so tests which use them can't run at the same time
+    static SYSTEM_ROOT_LOCK: Mutex<()> = Mutex::new(());
+
+    /// two_formsets_hiidb has a "Test Setting" one-of question in the Main formset (stored in
+    /// Setup-1111...) and another one in the Advanced formset (stored in Setup-2222...)
+    fn two_formsets_hiidb() -> Vec<u8> {
+        let main = [0x11u8; 16];
+        let advanced = [0x22u8; 16];
+        test_db::package_list(
+            "11111111-1111-1111-1111-111111111111",
+            &[
+                "Main",
+                "Test Setting",
+                "Help",
+                "Disabled",
+                "Enabled",
+                "Advanced",
+            ],
+            &[
+                test_db::form_set(
+                    main,
+                    1,
+                    3,
+                    &[
+                        test_db::var_store(main, 1, 8, "Setup"),
+                        test_db::form(1, 1, &[test_db::one_of(2, 3, 1, 1, 0, &[4, 5])]),
+                    ],
+                ),
+                test_db::form_set(
+                    advanced,
+                    6,
+                    3,
+                    &[
+                        test_db::var_store(advanced, 2, 8, "Setup"),
+                        test_db::form(2, 6, &[test_db::one_of(2, 3, 2, 2, 0, &[4, 5])]),
+                    ],
+                ),
+            ],
+        )
+    }
+
+    const SETUP_VARSTORE: &str = "Setup-11111111-1111-1111-1111-111111111111";
+    const ADVANCED_VARSTORE: &str = "Setup-22222222-2222-2222-2222-222222222222";
+
+    /// FakeSystemRoot is the system root until it's dropped. It has a HiiDB in /dev/mem
+    /// (minimal_hiidb unless it's made with with_db) and every question set to Disabled.
+    struct FakeSystemRoot {
+        root: tempfile::TempDir,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl FakeSystemRoot {
+        fn new() -> Self {
+            Self::with_db(&minimal_hiidb(), &[SETUP_VARSTORE])
+        }
+
+        /// with_db uses db as HiiDB, varstores are the names of the efivars its questions are stored in
+        fn with_db(db: &[u8], varstores: &[&str]) -> Self {
+            let _lock = SYSTEM_ROOT_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+            let root = fake_system_root(db, varstores);
+            set_system_root(root.path()).unwrap();
+            FakeSystemRoot { root, _lock }
+        }
+
+        fn path(&self) -> &Path {
+            self.root.path()
+        }
+
+        /// varstore is the efivar "Test Setting" of minimal_hiidb is stored in
+        fn varstore(&self) -> PathBuf {
+            self.efivar(SETUP_VARSTORE)
+        }
+
+        fn efivar(&self, name: &str) -> PathBuf {
+            self.path().join("sys/firmware/efi/efivars").join(name)
+        }
+    }
+
+    impl Drop for FakeSystemRoot {
+        fn drop(&mut self) {
+            system_root::set_system_root(SystemRoot::default());
+        }
+    }
+
+    fn fake_system_root(db: &[u8], varstores: &[&str]) -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let efivars = root.path().join("sys/firmware/efi/efivars");
+        std::fs::create_dir_all(&efivars).unwrap();
+        std::fs::create_dir_all(root.path().join("dev")).unwrap();
+
+        // HiiDB is at address 0 of /dev/mem
+        std::fs::write(root.path().join("dev/mem"), db).unwrap();
+        let hiidb_efivar = [7u32, db.len() as u32, 0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<u8>>();
+        std::fs::write(
+            efivars.join("HiiDB-1b838190-4625-4ead-abc9-cd5e6af18fe0"),
+            hiidb_efivar,
+        )
+        .unwrap();
+        for varstore in varstores {
+            std::fs::write(efivars.join(varstore), [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_set_ambiguous_question() {
+        let root =
+            FakeSystemRoot::with_db(&two_formsets_hiidb(), &[SETUP_VARSTORE, ADVANCED_VARSTORE]);
+        let answers = || {
+            [SETUP_VARSTORE, ADVANCED_VARSTORE].map(|name| fs::read(root.efivar(name)).unwrap()[4])
+        };
+
+        // the same prompt in two formsets needs a selector or --all
+        let ambiguous = HiiBackend::set("Test Setting", "Enabled", None);
+        match ambiguous.unwrap_err().downcast_ref::<QuestionError>() {
+            Some(QuestionError::Ambiguous { candidates, .. }) => assert_eq!(candidates.len(), 2),
+            other => panic!("expected an ambiguous question, got {:?}", other),
+        }
+        assert_eq!(answers(), [0, 0]);
+
+        let selected = HiiBackend::set("Test Setting", "Enabled", Some("Advanced")).unwrap();
+        assert_eq!(selected.responses.len(), 1);
+        assert_eq!(answers(), [0, 1]);
+
+        let options = SetOptions {
+            all: true,
+            ..Default::default()
+        };
+        HiiBackend::set_with_options("Test Setting", "Enabled", None, &options).unwrap();
+        assert_eq!(answers(), [1, 1]);
+    }
+
+    #[test]
+    fn test_set_under_system_root() {
+        let root = FakeSystemRoot::new();
+        let varstore = root.varstore();
+
+        let set = HiiBackend::set("Test Setting", "Enabled", None);
+        let get = HiiBackend::get("Test Setting", None);
+
+        let set = set.unwrap();
+        assert!(set.responses[0].modified);
+        assert_eq!(set.responses[0].verified_answer.as_deref(), Some("Enabled"));
+        assert_eq!(
+            std::fs::read(&varstore).unwrap(),
+            [7, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(get.unwrap().responses[0].question.answer, "Enabled");
+        assert!(root.path().join("run/lock/efibootmgr-remount").exists());
+    }
+
+    #[test]
+    fn test_get_with_db_and_captured_efivars() {
+        let _root = FakeSystemRoot::new();
+        let captured = tempfile::tempdir().unwrap();
+        std::fs::write(
+            captured.path().join(SETUP_VARSTORE),
+            [7, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+        )
+        .unwrap();
+
+        set_captured_efivars_dir(Some(captured.path())).unwrap();
+        let options = GetOptions {
+            db: Some(minimal_hiidb()),
+            ..Default::default()
+        };
+        let get = HiiBackend::get_with_options("Test Setting", None, &options);
+        // without the dump HiiDB would be looked up in the captured efivars, which don't have it
+        let extracted = HiiBackend::get("Test Setting", None);
+        set_captured_efivars_dir(None).unwrap();
+
+        // the answer comes from the captured varstore and not from the one under the root
+        assert_eq!(get.unwrap().responses[0].question.answer, "Enabled");
+        assert!(extracted.is_err());
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let _root = FakeSystemRoot::new();
+
+        let snapshot = HiiBackend::snapshot(&minimal_hiidb());
+        let set = HiiBackend::set("Test Setting", "Enabled", None);
+        let restored = snapshot.as_ref().ok().map(HiiBackend::restore);
+        let restored_again = snapshot.as_ref().ok().map(HiiBackend::restore);
+        let get = HiiBackend::get("Test Setting", None);
+        let journaled = journal::read_journal();
+
+        let snapshot = snapshot.unwrap();
+        assert_eq!(snapshot.settings.len(), 1);
+        assert_eq!(snapshot.settings[0].raw_value, "0");
+        assert_eq!(snapshot.settings[0].display_value, "Disabled");
+        assert!(set.unwrap().responses[0].modified);
+
+        let restored = restored.unwrap().unwrap();
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].old_value, "Enabled");
+        assert_eq!(restored[0].error, "");
+        assert!(restored_again.unwrap().unwrap().is_empty());
+        assert_eq!(get.unwrap().responses[0].question.answer, "Disabled");
+
+        // set and restore are journaled
+        let journaled = journaled.unwrap();
+        assert_eq!(journaled.len(), 2);
+        assert_eq!(journaled[1].question, "Test Setting");
+        assert_eq!(journaled[1].previous_value, "1");
+        assert_eq!(journaled[1].new_value, "0");
+    }
+
+    #[test]
+    fn test_set_dry_run() {
+        let root = FakeSystemRoot::new();
+        let varstore = root.varstore();
+        let before = fs::read(&varstore).unwrap();
+
+        let options = SetOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let set = HiiBackend::set_with_options("Test Setting", "Enabled", None, &options);
+        let get = HiiBackend::get("Test Setting", None);
+
+        let set = set.unwrap();
+        assert_eq!(set.responses.len(), 1);
+        assert!(!set.responses[0].modified);
+        assert_eq!(set.responses[0].verified_answer, None);
+        let planned = set.responses[0].planned_writes.as_ref().unwrap();
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].target, varstore.display().to_string());
+        assert_eq!(planned[0].offset, 0);
+        assert_eq!(planned[0].old_bytes, "00");
+        assert_eq!(planned[0].new_bytes, "01");
+        assert_eq!(fs::read(&varstore).unwrap(), before);
+        assert_eq!(get.unwrap().responses[0].question.answer, "Disabled");
+        assert!(!dry_run::is_dry_run());
+    }
+
+    #[test]
+    fn test_write_batch() {
+        let root = FakeSystemRoot::new();
+        let varstore = root.varstore();
+        let before = fs::read(&varstore).unwrap();
+
+        let batch = WriteBatch::start();
+        let set = HiiBackend::set("Test Setting", "Enabled", None);
+        let staged_get = HiiBackend::get("Test Setting", None);
+        let staged_contents = fs::read(&varstore).unwrap();
+        let committed = batch.commit();
+        let committed_contents = fs::read(&varstore).unwrap();
+
+        // a batch which can't be written completely doesn't write anything
+        let batch = WriteBatch::start();
+        let reset = HiiBackend::set("Test Setting", "Disabled", None);
+        let missing = root.path().join("sys/firmware/efi/efivars/Missing-guid");
+        batch::stage(&missing.display().to_string(), 4, vec![1]);
+        let failed = batch.commit();
+
+        let set = set.unwrap();
+        assert!(set.responses[0].modified);
+        // nothing is verified before the batch it joined is committed
+        assert_eq!(set.responses[0].verified_answer, None);
+        assert_eq!(staged_get.unwrap().responses[0].question.answer, "Enabled");
+        assert_eq!(staged_contents, before);
+        committed.unwrap();
+        assert_eq!(committed_contents[4], 1);
+
+        assert!(reset.unwrap().responses[0].modified);
+        assert!(failed.is_err());
+        assert_eq!(fs::read(&varstore).unwrap(), committed_contents);
+    }
+
+    #[test]
+    fn test_journal_and_undo() {
+        let root = FakeSystemRoot::new();
+        let varstore = root.varstore();
+
+        let set = HiiBackend::set("Test Setting", "Enabled", None);
+        let journaled = journal::read_journal();
+        let undone = undo(1, None);
+        let get = HiiBackend::get("Test Setting", None);
+        let nothing_left = undo(1, None);
+
+        // the value changed since it was written, so the change can't be undone
+        let set_again = HiiBackend::set("Test Setting", "Enabled", None);
+        let mut contents = fs::read(&varstore).unwrap();
+        contents[4] = 0;
+        fs::write(&varstore, &contents).unwrap();
+        let refused = undo(1, None);
+        let final_journal = journal::read_journal();
+
+        assert!(set.unwrap().responses[0].modified);
+        let journaled = journaled.unwrap();
+        assert_eq!(journaled.len(), 1);
+        assert_eq!(journaled[0].id, 1);
+        assert_eq!(journaled[0].backend, ProfileBackend::Hii);
+        assert_eq!(journaled[0].question, "Test Setting");
+        assert_eq!(journaled[0].previous_value, "0");
+        assert_eq!(journaled[0].new_value, "1");
+
+        let undone = undone.unwrap();
+        assert_eq!(undone.results.len(), 1);
+        assert_eq!(undone.results[0].error, "");
+        assert_eq!(undone.results[0].restored_value, "0");
+        assert_eq!(get.unwrap().responses[0].question.answer, "Disabled");
+        assert!(nothing_left.unwrap().results.is_empty());
+
+        assert!(set_again.unwrap().responses[0].modified);
+        let refused = refused.unwrap();
+        assert_eq!(refused.results.len(), 1);
+        assert!(refused.results[0].error.contains("refusing to undo"));
+        let final_journal = final_journal.unwrap();
+        assert_eq!(final_journal.len(), 3);
+        assert_eq!(final_journal[1].reverts, Some(1));
+        assert_eq!(final_journal[2].reverts, None);
+    }
+
+    #[test]
+    fn test_ilo_changes_are_journaled_if_the_batch_fails() {
+        let root = FakeSystemRoot::new();
+
+        let batch = WriteBatch::start();
+        record_ilo_change("Bios", "WorkloadProfile", "Custom", "Virtualization");
+        assert!(batch::stage(
+            &root
+                .efivar("Missing-11111111-1111-1111-1111-111111111111")
+                .display()
+                .to_string(),
+            4,
+            vec![1]
+        ));
+        batch::record_journal_entry(JournalEntry::new(
+            ProfileBackend::Hii,
+            "11111111-1111-1111-1111-111111111111",
+            "Test Setting",
+            "0",
+            "1",
+        ));
+        let committed = batch.commit();
+        let journaled = journal::read_journal();
+
+        // the iLO attribute was changed even though the efivars weren't
+        assert!(committed.is_err());
+        let journaled = journaled.unwrap();
+        assert_eq!(journaled.len(), 1);
+        assert_eq!(journaled[0].backend, ProfileBackend::Ilo);
+        assert_eq!(journaled[0].question, "WorkloadProfile");
+    }
+
+    #[test]
+    fn test_migrate_snapshot() {
+        let root = FakeSystemRoot::new();
+        let varstore = root.varstore();
+
+        // a snapshot from another BIOS version where the setting was stored somewhere else
+        let snapshot = HiiSnapshot {
+            settings: vec![HiiSettingValue {
+                identity: "Setup-22222222-2222-2222-2222-222222222222@0x8".to_owned(),
+                question: "Test Setting".to_owned(),
+                raw_value: "3".to_owned(),
+                display_value: "Enabled".to_owned(),
+                breadcrumb: vec!["Test Formset".to_owned(), "Test Formset".to_owned()],
+                options: vec!["Disabled".to_owned(), "Enabled".to_owned()],
+                varstore: "Setup".to_owned(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let planned = HiiBackend::migrate(&snapshot, false, false);
+        let unchanged = std::fs::read(&varstore).unwrap();
+        let applied = HiiBackend::migrate(&snapshot, true, false);
+
+        let planned = planned.unwrap();
+        assert_eq!(planned.entries[0].status, HiiMigrationStatus::Confident);
+        assert_eq!(planned.entries[0].new_value, "Enabled");
+        assert!(planned.results.is_empty());
+        assert_eq!(unchanged, [7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let applied = applied.unwrap();
+        assert_eq!(applied.results.len(), 1);
+        assert_eq!(applied.results[0].error, "");
+        assert_eq!(
+            std::fs::read(&varstore).unwrap(),
+            [7, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_apply_profile() {
+        let _root = FakeSystemRoot::new();
+        let profile: Profile = serde_yaml::from_str(
+            "settings:\n\
+             - question: Test Setting\n  answer: Enabled\n\
+             - question: Missing Setting\n  answer: Enabled\n",
+        )
+        .unwrap();
+        let machine = MachineInfo {
+            backend: BTreeSet::from([Backend::Hii]),
+            ..Default::default()
+        };
+
+        let first = apply_profile(&profile, &machine, Backend::Hii);
+        let second = apply_profile(&profile, &machine, Backend::Hii);
+
+        let statuses = |res: &SetResponseList| -> Vec<Option<SetStatus>> {
+            res.responses.iter().map(|resp| resp.status).collect()
+        };
+        assert_eq!(
+            statuses(&first),
+            vec![Some(SetStatus::Changed), Some(SetStatus::Failed)]
+        );
+        assert!(first.responses[0].modified);
+        assert_eq!(
+            statuses(&second),
+            vec![Some(SetStatus::Unchanged), Some(SetStatus::Failed)]
+        );
+        assert!(!second.responses[0].modified);
+    }
+
+    #[test]
+    fn test_check_profile() {
+        let _root = FakeSystemRoot::new();
+        let profile: Profile = serde_yaml::from_str(
+            "settings:\n\
+             - question: Test Setting\n  answer: Disabled\n\
+             - question: Test Setting\n  answer: Enabled\n",
+        )
+        .unwrap();
+        let machine = MachineInfo {
+            backend: BTreeSet::from([Backend::Hii]),
+            ..Default::default()
+        };
+
+        let report = check_profile(&profile, &machine, Backend::Hii);
+
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].expected, "Enabled");
+        assert_eq!(report.mismatches[0].actual, "Disabled");
+        assert_eq!(report.mismatches[0].backend, Backend::Hii);
+    }
+
+    #[test]
+    fn test_capture_and_replay_bundle() {
+        let root = FakeSystemRoot::new();
+        let bundle = root.path().join("bundle.tar");
+
+        capture_bundle(&bundle).unwrap();
+
+        let replay = replay_bundle(&bundle).unwrap();
+        let machine = identify_machine();
+        let get = HiiBackend::get("Test Setting", None);
+        let set = HiiBackend::set("Test Setting", "Enabled", None);
+        drop(replay);
+
+        assert_eq!(machine.backend, BTreeSet::from([Backend::Hii]));
+        assert_eq!(get.unwrap().responses[0].question.answer, "Disabled");
+        assert!(set.is_err());
+    }
+
+    #[test]
+    fn test_list_forms() {
+        let formsets = HiiBackend::list_forms(&two_formsets_hiidb()).unwrap();
+        let titles: Vec<&str> = formsets.iter().map(|f| f.title.as_str()).collect();
+        assert_eq!(titles, vec!["Main", "Advanced"]);
+        let forms: Vec<(i32, &str, i32)> = formsets
+            .iter()
+            .flat_map(|formset| &formset.forms)
+            .map(|form| (form.form_id, form.title.as_str(), form.question_count))
+            .collect();
+        assert_eq!(forms, vec![(1, "Main", 1), (2, "Advanced", 1)]);
+    }
+
+    #[test]
+    fn test_backend_capability() {
+        let _root =
+            FakeSystemRoot::with_db(&two_formsets_hiidb(), &[SETUP_VARSTORE, ADVANCED_VARSTORE]);
+        let ambiguous = backend_capability::<HiiBackend>("Test Setting", Backend::Hii);
+        let missing = backend_capability::<HiiBackend>("Missing Setting", Backend::Hii);
+        drop(_root);
+
+        let _root = FakeSystemRoot::new();
+        let supported = backend_capability::<HiiBackend>("Test Setting", Backend::Hii);
+
+        assert_eq!(supported.backend, Backend::Hii);
+        assert_eq!(supported.status, CapabilityStatus::Supported);
+        assert_eq!(supported.value.as_deref(), Some("Disabled"));
+        assert_eq!(ambiguous.status, CapabilityStatus::Ambiguous);
+        assert_eq!(ambiguous.responses.len(), 2);
+        assert_eq!(ambiguous.value, None);
+        assert_eq!(missing.status, CapabilityStatus::Unsupported);
+        assert!(missing.reason.is_some());
+    }
+
+    #[test]
+    fn test_check_hii_question() {
+        let parsed_db = package::read_db(&two_formsets_hiidb()).unwrap();
+        let hii_question = HiiQuestion {
+            question_variations: vec!["Test Setting".to_owned(), "Missing Setting".to_owned()],
+            answer_replacements: Some(BTreeMap::from([
+                ("Auto".to_owned(), vec!["Auto".to_owned()]),
+                ("Disable".to_owned(), vec!["Disabled".to_owned()]),
+            ])),
+            ..Default::default()
+        };
+        let check = check_hii_question(
+            &parsed_db,
+            "Test Setting",
+            "hii_question".to_owned(),
+            &hii_question,
+        )
+        .unwrap();
+
+        assert_eq!(check.matched_variations, vec!["Test Setting"]);
+        assert_eq!(check.unmatched_variations, vec!["Missing Setting"]);
+        assert_eq!(check.ambiguous_matches.len(), 2);
+        // Auto isn't an option of either question while Disabled is an option of both
+        assert_eq!(check.missing_answers.len(), 2);
+        assert!(check
+            .missing_answers
+            .iter()
+            .all(|a| a.starts_with("Auto (")));
+    }
+
+    #[test]
+    fn test_suggest_translations_skips_other_files() {
+        let hiidbs = vec![
+            ("a.bin".to_owned(), minimal_hiidb()),
+            ("b.bin".to_owned(), two_formsets_hiidb()),
+        ];
+        let mut dumps = hiidbs.clone();
+        dumps.insert(1, ("notes.txt".to_owned(), b"not a HiiDB".to_vec()));
+
+        assert_eq!(
+            HiiBackend::suggest_translations(&dumps, 2).unwrap(),
+            HiiBackend::suggest_translations(&hiidbs, 2).unwrap()
+        );
+    }
+}
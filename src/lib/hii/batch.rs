@@ -0,0 +1,168 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// A WriteBatch stages the efivar writes of several questions in memory instead of writing them
+// one by one. On commit the changes are merged per varstore and every efivar is written once,
+// under a single lock and remount. If one of the writes fails the efivars which were already
+// written get their original contents back, so a batch lands fully or not at all.
+// HII changes are only journaled and audited once their efivars have been written. Other backends
+// (like iLO) change their settings right away, so their changes are journaled and audited right away too.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use crate::audit;
+use crate::audit::AuditEvent;
+use crate::audit::AuditResult;
+use crate::hii::forms;
+use crate::journal;
+use crate::journal::JournalEntry;
+
+/// efivar file -> (position in the file, bytes) of every change in the order they were staged
+pub type StagedWrites = BTreeMap<String, Vec<(usize, Vec<u8>)>>;
+
+#[derive(Default)]
+struct Staged {
+    writes: StagedWrites,
+    journal: Vec<JournalEntry>,
+    audit: Vec<AuditEvent>,
+}
+
+thread_local! {
+    /// changes of the WriteBatch which is being staged on this thread
+    static STAGED: RefCell<Option<Staged>> = const { RefCell::new(None) };
+}
+
+/// WriteBatch stages writes until it's committed. Dropping it without committing discards them.
+pub struct WriteBatch {
+    /// false if this batch joined one which was already being staged
+    owner: bool,
+}
+
+impl WriteBatch {
+    /// start begins staging writes. If a batch is already being staged the writes join it
+    /// and are only written when that batch is committed.
+    pub fn start() -> Self {
+        let owner = with_staged(|staged| {
+            if staged.is_some() {
+                return false;
+            }
+            *staged = Some(Staged::default());
+            true
+        });
+        WriteBatch { owner }
+    }
+
+    /// is_owner returns false if this batch joined one which was already being staged,
+    /// then its writes are only written when that batch is committed
+    pub fn is_owner(&self) -> bool {
+        self.owner
+    }
+
+    /// commit writes every staged change, journals them and sends their audit events
+    pub fn commit(self) -> Result<()> {
+        if !self.owner {
+            return Ok(());
+        }
+        let staged = with_staged(Option::take).unwrap_or_default();
+        if let Err(why) = forms::write_efivars(&staged.writes) {
+            for mut event in staged.audit {
+                event.result = AuditResult::Failed;
+                event.error = format!("{:#}", why);
+                audit::emit(&event);
+            }
+            return Err(why);
+        }
+        journal::append_or_log(staged.journal);
+        for event in &staged.audit {
+            audit::emit(event);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for WriteBatch {
+    fn drop(&mut self) {
+        if self.owner {
+            with_staged(|staged| *staged = None);
+        }
+    }
+}
+
+fn with_staged<R>(f: impl FnOnce(&mut Option<Staged>) -> R) -> R {
+    STAGED.with(|staged| f(&mut staged.borrow_mut()))
+}
+
+/// stage records a write if a batch is being staged. It returns false if there is no batch,
+/// then the caller has to do the write itself.
+pub(crate) fn stage(store_filename: &str, position: usize, bytes: Vec<u8>) -> bool {
+    with_staged(|staged| match staged.as_mut() {
+        Some(staged) => {
+            staged
+                .writes
+                .entry(store_filename.to_owned())
+                .or_default()
+                .push((position, bytes));
+            true
+        }
+        None => false,
+    })
+}
+
+/// apply_staged overlays the staged changes of an efivar on bytes read from the start of its file
+pub(crate) fn apply_staged(store_filename: &str, contents: &mut [u8]) {
+    with_staged(|staged| {
+        if let Some(patches) = staged
+            .as_ref()
+            .and_then(|staged| staged.writes.get(store_filename))
+        {
+            for (position, bytes) in patches {
+                if let Some(target) = contents.get_mut(*position..position + bytes.len()) {
+                    target.copy_from_slice(bytes);
+                }
+            }
+        }
+    })
+}
+
+/// record_journal_entry journals an efivar change once the batch is committed.
+/// Without a batch the change was written already, so it's journaled right away.
+pub(crate) fn record_journal_entry(entry: JournalEntry) {
+    let entry = with_staged(|staged| match staged.as_mut() {
+        Some(staged) => {
+            staged.journal.push(entry);
+            None
+        }
+        None => Some(entry),
+    });
+    if let Some(entry) = entry {
+        journal::record(entry);
+    }
+}
+
+/// record_audit_event sends the audit event of an efivar change once the batch is committed
+/// (or as failed if committing fails). Without a batch the change was written already,
+/// so it's sent right away.
+pub(crate) fn record_audit_event(event: AuditEvent) {
+    let event = with_staged(|staged| match staged.as_mut() {
+        Some(staged) => {
+            staged.audit.push(event);
+            None
+        }
+        None => Some(event),
+    });
+    if let Some(event) = event {
+        audit::emit(&event);
+    }
+}
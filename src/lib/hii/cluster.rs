@@ -0,0 +1,296 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Cluster questions from several HiiDB dumps to find the same setting spelled in different ways.
+// Questions are put in the same cluster if their prompts are the same after normalization,
+// or if they have the same help text and the same options and that help text is specific
+// enough to only be used by a single prompt in each dump.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::answers::answers_equivalent;
+use crate::normalize::normalize;
+
+/// QuestionSample is a question as it appears in one of the dumps
+#[derive(Debug, Clone)]
+pub struct QuestionSample {
+    /// index of the dump this question was found in
+    pub dump: usize,
+    pub prompt: String,
+    pub help: String,
+    pub options: Vec<String>,
+}
+
+/// SuggestedMapping is a draft spellings database entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuggestedMapping {
+    /// the prompt used by the most dumps
+    pub question: String,
+    pub question_variations: Vec<String>,
+    /// canonical option -> every spelling of it, only for options which are spelled differently
+    pub answer_replacements: BTreeMap<String, Vec<String>>,
+    /// how many dumps have this question
+    pub dump_count: usize,
+}
+
+// A minimal union-find over sample indexes
+struct Clusters(Vec<usize>);
+
+impl Clusters {
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.0[root] != root {
+            root = self.0[root];
+        }
+        // path compression
+        let mut i = i;
+        while self.0[i] != root {
+            let next = self.0[i];
+            self.0[i] = root;
+            i = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a != b {
+            self.0[b] = a;
+        }
+    }
+}
+
+/// cluster groups the samples into settings and returns a draft mapping for every setting which
+/// is found in at least min_dumps dumps and is spelled differently in some of them
+/// (either the prompt or one of its options). Mappings are sorted by question.
+pub fn cluster(samples: &[QuestionSample], min_dumps: usize) -> Vec<SuggestedMapping> {
+    let mut clusters = Clusters((0..samples.len()).collect());
+
+    let prompts: Vec<String> = samples.iter().map(|s| normalize(&s.prompt)).collect();
+
+    // same prompt after normalization
+    let mut by_prompt: HashMap<&str, usize> = HashMap::new();
+    for (i, prompt) in prompts.iter().enumerate() {
+        if prompt.is_empty() {
+            continue;
+        }
+        match by_prompt.get(prompt.as_str()) {
+            Some(&first) => clusters.union(first, i),
+            None => {
+                by_prompt.insert(prompt, i);
+            }
+        }
+    }
+
+    // same help text and options, but generic help texts like "Enable or disable this option"
+    // are shared by unrelated questions so a help text which goes with more than one prompt
+    // in the same dump isn't used
+    let mut by_help: HashMap<(String, BTreeSet<String>), Vec<usize>> = HashMap::new();
+    for (i, sample) in samples.iter().enumerate() {
+        let help = normalize(&sample.help);
+        if help.is_empty() || prompts[i].is_empty() {
+            continue;
+        }
+        let options = sample.options.iter().map(|o| normalize(o)).collect();
+        by_help.entry((help, options)).or_default().push(i);
+    }
+    for members in by_help.values() {
+        let mut prompts_per_dump: HashMap<usize, BTreeSet<&str>> = HashMap::new();
+        for &i in members {
+            prompts_per_dump
+                .entry(samples[i].dump)
+                .or_default()
+                .insert(&prompts[i]);
+        }
+        if prompts_per_dump.values().all(|p| p.len() == 1) {
+            for &i in &members[1..] {
+                clusters.union(members[0], i);
+            }
+        }
+    }
+
+    let mut groups: BTreeMap<usize, Vec<&QuestionSample>> = BTreeMap::new();
+    for (i, sample) in samples.iter().enumerate() {
+        if !prompts[i].is_empty() {
+            groups.entry(clusters.find(i)).or_default().push(sample);
+        }
+    }
+
+    let mut res: Vec<SuggestedMapping> = groups
+        .into_values()
+        .filter_map(|members| suggest_mapping(&members, min_dumps))
+        .collect();
+    res.sort_by(|a, b| a.question.cmp(&b.question));
+    res
+}
+
+fn suggest_mapping(members: &[&QuestionSample], min_dumps: usize) -> Option<SuggestedMapping> {
+    let dump_count = members
+        .iter()
+        .map(|m| m.dump)
+        .collect::<BTreeSet<_>>()
+        .len();
+    if dump_count < min_dumps {
+        return None;
+    }
+
+    // spelling -> dumps which use it
+    let mut prompts: BTreeMap<&str, BTreeSet<usize>> = BTreeMap::new();
+    let mut options: BTreeMap<&str, BTreeSet<usize>> = BTreeMap::new();
+    for member in members {
+        prompts
+            .entry(member.prompt.trim())
+            .or_default()
+            .insert(member.dump);
+        for option in &member.options {
+            options
+                .entry(option.trim())
+                .or_default()
+                .insert(member.dump);
+        }
+    }
+
+    let answer_replacements = answer_replacements(&options);
+    let distinct_prompts: BTreeSet<String> = prompts.keys().map(|p| normalize(p)).collect();
+    if distinct_prompts.len() < 2 && answer_replacements.is_empty() {
+        return None;
+    }
+
+    Some(SuggestedMapping {
+        question: most_common(&prompts).to_owned(),
+        question_variations: prompts.keys().map(|p| p.to_string()).collect(),
+        answer_replacements,
+        dump_count,
+    })
+}
+
+/// answer_replacements groups the options by their normalized spelling and returns the groups
+/// which have spellings that the shared answer vocabulary doesn't already treat as the same
+fn answer_replacements(options: &BTreeMap<&str, BTreeSet<usize>>) -> BTreeMap<String, Vec<String>> {
+    let mut groups: BTreeMap<String, BTreeMap<&str, BTreeSet<usize>>> = BTreeMap::new();
+    for (option, dumps) in options {
+        groups
+            .entry(normalize(option))
+            .or_default()
+            .insert(option, dumps.clone());
+    }
+
+    let mut res = BTreeMap::new();
+    for spellings in groups.values() {
+        let canonical = most_common(spellings);
+        if spellings
+            .keys()
+            .all(|spelling| answers_equivalent(spelling, canonical))
+        {
+            continue;
+        }
+        res.insert(
+            canonical.to_owned(),
+            spellings.keys().map(|s| s.to_string()).collect(),
+        );
+    }
+    res
+}
+
+/// most_common returns the spelling used by the most dumps, ties go to the shortest spelling
+fn most_common<'a>(spellings: &BTreeMap<&'a str, BTreeSet<usize>>) -> &'a str {
+    spellings
+        .iter()
+        .min_by_key(|(spelling, dumps)| {
+            (std::cmp::Reverse(dumps.len()), spelling.len(), **spelling)
+        })
+        .map(|(spelling, _)| *spelling)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(dump: usize, prompt: &str, help: &str, options: &[&str]) -> QuestionSample {
+        QuestionSample {
+            dump,
+            prompt: prompt.to_owned(),
+            help: help.to_owned(),
+            options: options.iter().map(|o| o.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_cluster() {
+        let samples = vec![
+            sample(
+                0,
+                "Hyper-Threading",
+                "Enables Hyper Threading",
+                &["Disabled", "Enabled"],
+            ),
+            sample(
+                0,
+                "Boot Mode",
+                "Enable or disable",
+                &["UEFI", "Legacy BIOS"],
+            ),
+            sample(
+                0,
+                "SR-IOV Support",
+                "Enable or disable",
+                &["Disabled", "Enabled"],
+            ),
+            sample(
+                0,
+                "Turbo Mode",
+                "Enable or disable",
+                &["Disabled", "Enabled"],
+            ),
+            sample(1, "Hyper Threading [ALL]", "", &["Disable", "Enable"]),
+            sample(1, "Boot Mode", "", &["UEFI", "Legacy-BIOS"]),
+            sample(
+                2,
+                "Enable LP",
+                "Enables Hyper Threading",
+                &["Disabled", "Enabled"],
+            ),
+            sample(2, "Turbo", "Enable or disable", &["Disabled", "Enabled"]),
+        ];
+
+        assert_eq!(
+            cluster(&samples, 2),
+            vec![
+                SuggestedMapping {
+                    question: "Boot Mode".to_owned(),
+                    question_variations: vec!["Boot Mode".to_owned()],
+                    answer_replacements: BTreeMap::from([(
+                        "Legacy BIOS".to_owned(),
+                        vec!["Legacy BIOS".to_owned(), "Legacy-BIOS".to_owned()]
+                    )]),
+                    dump_count: 2,
+                },
+                SuggestedMapping {
+                    question: "Enable LP".to_owned(),
+                    question_variations: vec![
+                        "Enable LP".to_owned(),
+                        "Hyper Threading [ALL]".to_owned(),
+                        "Hyper-Threading".to_owned(),
+                    ],
+                    answer_replacements: BTreeMap::new(),
+                    dump_count: 3,
+                },
+            ]
+        );
+
+        assert!(cluster(&samples, 4).is_empty());
+    }
+}
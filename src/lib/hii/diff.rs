@@ -0,0 +1,300 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Compare the questions of two HiiDB dumps, usually from before and after a BIOS update.
+// Question ids and varstore offsets can change between BIOS versions, so questions are matched
+// by prompt and storage first and only then by whatever is left of their identity.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::hii::forms::QuestionDescriptor;
+
+/// QuestionSnapshot is everything about a question which is compared, without its current answer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuestionSnapshot {
+    pub formset_guid: String,
+    pub question_id: u16,
+    pub prompt: String,
+    pub help: String,
+    /// OneOf, CheckBox or Numeric
+    pub kind: String,
+    /// varstore and offset of the answer like Setup-<guid>@0x10, empty if there is no varstore
+    pub storage: String,
+    /// titles of the formset and form the question is in
+    pub form: String,
+    /// value stored in the varstore -> label of the option
+    pub options: BTreeMap<String, String>,
+    pub default: Option<String>,
+}
+
+impl From<&QuestionDescriptor> for QuestionSnapshot {
+    fn from(descriptor: &QuestionDescriptor) -> Self {
+        QuestionSnapshot {
+            formset_guid: descriptor.formset_guid.clone(),
+            question_id: descriptor.question_id(),
+            prompt: descriptor.question.trim().to_owned(),
+            help: descriptor.help.trim().to_owned(),
+            kind: descriptor.kind(),
+            storage: descriptor.storage().unwrap_or_default(),
+            form: descriptor.breadcrumb.join(" > "),
+            options: descriptor
+                .possible_options
+                .iter()
+                .map(|opt| (opt.raw_value_string(), opt.value.trim().to_owned()))
+                .collect(),
+            default: descriptor.default.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// prompt, help, type, storage, form, default or option <value>
+    pub field: String,
+    /// empty if the field was added
+    pub old_value: String,
+    /// empty if the field was removed
+    pub new_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuestionDiff {
+    pub formset_guid: String,
+    /// prompt in the new dump, or in the old one if the question was removed
+    pub question: String,
+    /// storage in the new dump, or in the old one if the question was removed
+    pub storage: String,
+    pub kind: ChangeKind,
+    /// what changed, only set for ChangeKind::Changed
+    pub changes: Vec<FieldChange>,
+}
+
+// Every pass matches the questions which are still unmatched by a less specific key.
+// Keys which are empty (no prompt or no varstore) are never matched on.
+type MatchKey = fn(&QuestionSnapshot) -> Option<String>;
+
+const MATCH_PASSES: [MatchKey; 4] = [
+    // nothing which identifies the question changed
+    |q| {
+        (!q.prompt.is_empty() && !q.storage.is_empty())
+            .then(|| format!("{}\0{}", q.prompt, q.storage))
+    },
+    // renamed
+    |q| (!q.storage.is_empty()).then(|| q.storage.clone()),
+    // moved to another offset or varstore
+    |q| (!q.prompt.is_empty()).then(|| q.prompt.clone()),
+    // renamed and moved within the same varstore, but it kept its question id
+    |q| {
+        let varstore = q.storage.split('@').next().unwrap_or_default();
+        (!varstore.is_empty()).then(|| format!("{}\0{}\0{}", q.question_id, q.kind, varstore))
+    },
+];
+
+/// diff compares the questions of two dumps. Questions which didn't change are left out.
+pub fn diff(old: &[QuestionSnapshot], new: &[QuestionSnapshot]) -> Vec<QuestionDiff> {
+    // index in new -> index in old
+    let mut matched: HashMap<usize, usize> = HashMap::new();
+    let mut unmatched_old: BTreeSet<usize> = (0..old.len()).collect();
+    let mut unmatched_new: BTreeSet<usize> = (0..new.len()).collect();
+
+    for key in MATCH_PASSES {
+        let group = |questions: &[QuestionSnapshot], unmatched: &BTreeSet<usize>| {
+            let mut groups: HashMap<(String, String), Vec<usize>> = HashMap::new();
+            for &i in unmatched {
+                if let Some(k) = key(&questions[i]) {
+                    groups
+                        .entry((questions[i].formset_guid.clone(), k))
+                        .or_default()
+                        .push(i);
+                }
+            }
+            groups
+        };
+        let old_groups = group(old, &unmatched_old);
+        for (k, new_indexes) in group(new, &unmatched_new) {
+            // The same question shows up once for every form it's in, so groups of the same size
+            // are paired in order. Anything else is ambiguous and left for the next pass.
+            match old_groups.get(&k) {
+                Some(old_indexes) if old_indexes.len() == new_indexes.len() => {
+                    for (&o, &n) in old_indexes.iter().zip(&new_indexes) {
+                        matched.insert(n, o);
+                        unmatched_old.remove(&o);
+                        unmatched_new.remove(&n);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut res = Vec::new();
+    for (n, new_question) in new.iter().enumerate() {
+        match matched.get(&n) {
+            Some(&o) => {
+                let changes = field_changes(&old[o], new_question);
+                if !changes.is_empty() {
+                    res.push(question_diff(new_question, ChangeKind::Changed, changes));
+                }
+            }
+            None => res.push(question_diff(new_question, ChangeKind::Added, Vec::new())),
+        }
+    }
+    for o in unmatched_old {
+        res.push(question_diff(&old[o], ChangeKind::Removed, Vec::new()));
+    }
+    res
+}
+
+fn question_diff(
+    question: &QuestionSnapshot,
+    kind: ChangeKind,
+    changes: Vec<FieldChange>,
+) -> QuestionDiff {
+    QuestionDiff {
+        formset_guid: question.formset_guid.clone(),
+        question: question.prompt.clone(),
+        storage: question.storage.clone(),
+        kind,
+        changes,
+    }
+}
+
+fn field_changes(old: &QuestionSnapshot, new: &QuestionSnapshot) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut compare = |field: &str, old_value: &str, new_value: &str| {
+        if old_value != new_value {
+            changes.push(FieldChange {
+                field: field.to_owned(),
+                old_value: old_value.to_owned(),
+                new_value: new_value.to_owned(),
+            });
+        }
+    };
+
+    compare("prompt", &old.prompt, &new.prompt);
+    compare("help", &old.help, &new.help);
+    compare("type", &old.kind, &new.kind);
+    compare("storage", &old.storage, &new.storage);
+    compare("form", &old.form, &new.form);
+
+    let values: BTreeSet<&String> = old.options.keys().chain(new.options.keys()).collect();
+    for value in values {
+        compare(
+            &format!("option {}", value),
+            old.options.get(value).map_or("", String::as_str),
+            new.options.get(value).map_or("", String::as_str),
+        );
+    }
+
+    compare(
+        "default",
+        old.default.as_deref().unwrap_or_default(),
+        new.default.as_deref().unwrap_or_default(),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(prompt: &str, storage: &str, question_id: u16) -> QuestionSnapshot {
+        QuestionSnapshot {
+            formset_guid: "formset".to_owned(),
+            question_id,
+            prompt: prompt.to_owned(),
+            kind: "OneOf".to_owned(),
+            storage: storage.to_owned(),
+            options: BTreeMap::from([
+                ("0".to_owned(), "Disabled".to_owned()),
+                ("1".to_owned(), "Enabled".to_owned()),
+            ]),
+            default: Some("Disabled".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = vec![
+            question("Unchanged", "Setup-guid@0x1", 1),
+            question("Old Name", "Setup-guid@0x2", 2),
+            question("Moved", "Setup-guid@0x3", 3),
+            question("Options", "Setup-guid@0x4", 4),
+            question("Removed", "Setup-guid@0x5", 5),
+            question("Old Both", "Setup-guid@0x7", 7),
+            question("Gone", "Setup-guid@0x8", 8),
+        ];
+
+        let mut options = question("Options", "Setup-guid@0x4", 4);
+        options.options.insert("1".to_owned(), "On".to_owned());
+        options.options.insert("2".to_owned(), "Auto".to_owned());
+        options.default = Some("Auto".to_owned());
+        let new = vec![
+            question("Unchanged", "Setup-guid@0x1", 1),
+            question("New Name", "Setup-guid@0x2", 2),
+            question("Moved", "Setup-guid@0x13", 3),
+            options,
+            question("Added", "Setup-guid@0x6", 6),
+            question("New Both", "Setup-guid@0x17", 7),
+            // same question id but in another varstore, so it's another question
+            question("Other", "Other-guid@0x8", 8),
+        ];
+
+        let change = |field: &str, old_value: &str, new_value: &str| FieldChange {
+            field: field.to_owned(),
+            old_value: old_value.to_owned(),
+            new_value: new_value.to_owned(),
+        };
+        let res = diff(&old, &new);
+        let summary: Vec<(&str, ChangeKind)> =
+            res.iter().map(|d| (d.question.as_str(), d.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("New Name", ChangeKind::Changed),
+                ("Moved", ChangeKind::Changed),
+                ("Options", ChangeKind::Changed),
+                ("Added", ChangeKind::Added),
+                ("New Both", ChangeKind::Changed),
+                ("Other", ChangeKind::Added),
+                ("Removed", ChangeKind::Removed),
+                ("Gone", ChangeKind::Removed),
+            ]
+        );
+        assert_eq!(
+            res[0].changes,
+            vec![change("prompt", "Old Name", "New Name")]
+        );
+        assert_eq!(
+            res[1].changes,
+            vec![change("storage", "Setup-guid@0x3", "Setup-guid@0x13")]
+        );
+        assert_eq!(
+            res[2].changes,
+            vec![
+                change("option 1", "Enabled", "On"),
+                change("option 2", "", "Auto"),
+                change("default", "Disabled", "Auto"),
+            ]
+        );
+    }
+}
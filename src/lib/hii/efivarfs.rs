@@ -11,6 +11,8 @@
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::path::Path;
+use std::path::PathBuf;
+use std::sync::RwLock;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -22,36 +24,85 @@ use nix::mount::mount;
 use nix::mount::MsFlags;
 use proc_mounts::MountIter;
 
+use crate::bundle;
+use crate::system_root::system_root;
+
 const EFIVARS_MOUNT_POINT: &str = "/sys/firmware/efi/efivars";
 
+// A directory with captured efivars (ex: a copy of /sys/firmware/efi/efivars from another machine)
+// which is used instead of the live efivarfs.
+static CAPTURED_EFIVARS_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// set_captured_efivars_dir makes varstores be read from dir instead of /sys/firmware/efi/efivars.
+/// Files in dir have to be named and laid out like in efivarfs ({name}-{guid} starting with 4 bytes of attributes).
+/// Writing isn't allowed while a captured directory is used. None goes back to the live efivarfs.
+pub fn set_captured_efivars_dir(dir: Option<PathBuf>) {
+    *CAPTURED_EFIVARS_DIR
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = dir;
+}
+
+/// captured_efivars_dir returns the directory set by set_captured_efivars_dir if there is one
+pub fn captured_efivars_dir() -> Option<PathBuf> {
+    CAPTURED_EFIVARS_DIR
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// efivars_dir returns the directory varstores are read from and written to:
+/// the captured directory, the efivars of the bundle being replayed or the efivarfs under the system root
+pub fn efivars_dir() -> PathBuf {
+    captured_efivars_dir()
+        .or_else(|| bundle::replayed_bundle().map(|bundle| bundle.efivars_dir()))
+        .unwrap_or_else(|| system_root().join(EFIVARS_MOUNT_POINT))
+}
+
+/// is_efivarfs returns true if dir is the mount point of an efivarfs
+pub fn is_efivarfs(dir: &Path) -> bool {
+    match MountIter::new() {
+        Ok(mounts) => mounts
+            .filter_map(Result::ok)
+            .any(|m| m.dest == dir && m.fstype == "efivarfs"),
+        Err(why) => {
+            warn!("cannot read /proc/mounts: {}", why);
+            false
+        }
+    }
+}
+
 pub struct EfivarsMountGuard {
+    mount_point: PathBuf,
     original_flags: MsFlags,
 }
 
 impl EfivarsMountGuard {
-    pub fn new() -> Result<Self> {
-        let original_flags = get_current_mount_flags(EFIVARS_MOUNT_POINT)?;
+    pub fn new(mount_point: &Path) -> Result<Self> {
+        let original_flags = get_current_mount_flags(mount_point)?;
         if original_flags.contains(MsFlags::MS_RDONLY) {
             debug!(
                 "{} mounted read only, remounting read/write",
-                EFIVARS_MOUNT_POINT
+                mount_point.display()
             );
             mount(
                 None as Option<&str>,
-                EFIVARS_MOUNT_POINT,
+                mount_point,
                 None as Option<&str>,
                 original_flags & !MsFlags::MS_RDONLY | MsFlags::MS_REMOUNT,
                 None as Option<&str>,
             )
-            .context(format!("Failed to remount {} RW", EFIVARS_MOUNT_POINT))?
+            .context(format!("Failed to remount {} RW", mount_point.display()))?
         } else {
             debug!(
                 "{} is mounted read/write, skipping remount",
-                EFIVARS_MOUNT_POINT
+                mount_point.display()
             );
         }
 
-        Ok(EfivarsMountGuard { original_flags })
+        Ok(EfivarsMountGuard {
+            mount_point: mount_point.to_path_buf(),
+            original_flags,
+        })
     }
 }
 
@@ -60,10 +111,10 @@ impl Drop for EfivarsMountGuard {
         // efivarfs being rw is scary. We should unmount it ASAP.
         // If efivarfs is set as ro in fstab (the ideal), "remount" along will change it back.
         if self.original_flags.contains(MsFlags::MS_RDONLY) {
-            debug!("remounting {} as read only", EFIVARS_MOUNT_POINT);
+            debug!("remounting {} as read only", self.mount_point.display());
             let res = mount(
                 None as Option<&str>,
-                EFIVARS_MOUNT_POINT,
+                &self.mount_point,
                 None as Option<&str>,
                 self.original_flags | MsFlags::MS_REMOUNT,
                 None as Option<&str>,
@@ -71,7 +122,8 @@ impl Drop for EfivarsMountGuard {
             if let Err(why) = res {
                 error!(
                     "failed to remount {} because {:#}",
-                    EFIVARS_MOUNT_POINT, why
+                    self.mount_point.display(),
+                    why
                 );
             }
         }
@@ -14,6 +14,7 @@ use std::fs::File;
 use std::io::Read;
 use std::io::Seek;
 use std::io::SeekFrom;
+use std::path::PathBuf;
 
 use anyhow::Context;
 use anyhow::Result;
@@ -21,8 +22,16 @@ use binrw::io::Cursor;
 use binrw::BinRead;
 use binrw::BinReaderExt;
 
-pub const OCP_HIIDB_PATH: &str =
-    "/sys/firmware/efi/efivars/HiiDB-1b838190-4625-4ead-abc9-cd5e6af18fe0";
+use crate::bundle;
+use crate::hii::efivarfs;
+use crate::system_root::system_root;
+
+const OCP_HIIDB_EFIVAR: &str = "HiiDB-1b838190-4625-4ead-abc9-cd5e6af18fe0";
+
+/// ocp_hiidb_path returns the path of the efivar which points to HiiDB in memory
+pub fn ocp_hiidb_path() -> PathBuf {
+    efivarfs::efivars_dir().join(OCP_HIIDB_EFIVAR)
+}
 
 #[derive(BinRead, Debug, PartialEq)]
 #[br(little)]
@@ -36,30 +45,41 @@ struct HiiDBEFIVar {
 }
 
 pub fn extract_db() -> Result<Vec<u8>> {
+    if let Some(bundle) = bundle::replayed_bundle() {
+        return bundle.hiidb();
+    }
+
     // I haven't seen any documentation on extracting HiiDB anywhere on the internet
     // So this is directly based on what hiitool does.
 
     // try to read data from varstore
+    let hiidb_path = ocp_hiidb_path();
     let mut efivar_file =
-        File::open(OCP_HIIDB_PATH).context(format!("Failed to open {OCP_HIIDB_PATH}"))?;
+        File::open(&hiidb_path).context(format!("Failed to open {}", hiidb_path.display()))?;
 
     let mut efivar_contents = Vec::new();
     efivar_file
         .read_to_end(&mut efivar_contents)
-        .context(format!("Failed to read efivar file, {}", OCP_HIIDB_PATH))?;
+        .context(format!(
+            "Failed to read efivar file, {}",
+            hiidb_path.display()
+        ))?;
 
     let mut efivar_cursor = Cursor::new(&efivar_contents);
     let db_info: HiiDBEFIVar = efivar_cursor.read_ne()?;
 
     // Now that we have offset and size from the HiiDB efivar, use it to read DB from memory.
 
-    let mut mem_file = File::open("/dev/mem").context("Failed to open /dev/mem")?;
+    let mem_path = system_root().join("/dev/mem");
+    let mut mem_file =
+        File::open(&mem_path).context(format!("Failed to open {}", mem_path.display()))?;
     mem_file.seek(SeekFrom::Start(db_info.address as u64))?;
 
     let mut buf = vec![0u8; db_info.length.try_into()?];
-    mem_file
-        .read_exact(&mut buf)
-        .context("Failed to read bytes of specified length from /dev/mem")?;
+    mem_file.read_exact(&mut buf).context(format!(
+        "Failed to read bytes of specified length from {}",
+        mem_path.display()
+    ))?;
 
     Ok(buf)
 }
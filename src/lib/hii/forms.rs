@@ -11,12 +11,15 @@
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::env::var;
 use std::fmt;
 use std::fmt::format;
 use std::fmt::Display;
+use std::fs;
 use std::fs::File;
 use std::io::Read;
 use std::io::Seek;
@@ -32,17 +35,27 @@ use binrw::io::Cursor;
 use binrw::BinRead;
 use binrw::BinReaderExt;
 use binrw::BinResult;
-use binrw::BinWrite;
 use log::debug;
 use log::error;
 use thiserror::Error;
 
+use crate::bundle;
 use crate::chattr::EfivarsImmutabilityGuard;
+use crate::dry_run;
+use crate::dry_run::PlannedWrite;
 use crate::file_lock::FileLock;
+use crate::hii::batch;
+use crate::hii::batch::StagedWrites;
+use crate::hii::efivarfs;
 use crate::hii::efivarfs::EfivarsMountGuard;
 use crate::hii::package::Guid;
+use crate::normalize::normalize;
+use crate::system_root::system_root;
 
 const DUMMY_OPCODE: u8 = 0xFFu8; // doesn't correspond to any known IFROpCode
+const CHECKBOX_DEFAULT_FLAG: u8 = 0x01; // EFI_IFR_CHECKBOX_DEFAULT
+const OPTION_DEFAULT_FLAG: u8 = 0x10; // EFI_IFR_OPTION_DEFAULT
+const STANDARD_DEFAULT_ID: u16 = 0x0000; // EFI_HII_DEFAULT_CLASS_STANDARD
 
 // UEFI Spec v2.9 Page 1844
 #[derive(BinRead, Debug, PartialEq, Copy, Clone)]
@@ -258,6 +271,9 @@ pub enum IFROpCode {
 #[derive(BinRead)]
 #[br(little)]
 pub struct IFROperation {
+    #[br(restore_position)]
+    // the op code byte as it is in HiiDB, so that the operation can be written back
+    raw_op_code: u8,
     pub op_code: IFROpCode,
     #[br(restore_position, map = |x: u8| x  & 0x7F)]
     // only store the first 7 bits and then move the cursor back to position before this field
@@ -278,6 +294,19 @@ pub struct IFROperation {
     pub parsed_data: ParsedOperation,
 }
 
+impl IFROperation {
+    /// to_bytes encodes this operation (without its children or the End of its scope)
+    /// the way it was read from HiiDB
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = vec![
+            self.raw_op_code,
+            self.length | if self.open_scope { 0x80 } else { 0 },
+        ];
+        res.extend(&self.data);
+        res
+    }
+}
+
 // Debug is implemented manually because if we derived Debug instead then we'd
 // get a stack overflow caused by parent printing child which will try to print it's parent....
 impl fmt::Debug for IFROperation {
@@ -481,15 +510,20 @@ trait VariableStore {
     fn guid(&self) -> String;
     fn size(&self) -> u16;
 
+    /// efivar_name is the name of the efivar backing this varstore, like Setup-<guid>
+    fn efivar_name(&self) -> String {
+        format!("{}-{}", &self.name(), &self.guid().to_ascii_lowercase())
+    }
+
     fn store_filename(&self) -> String {
-        format!(
-            "/sys/firmware/efi/efivars/{}-{}",
-            &self.name(),
-            &self.guid().to_ascii_lowercase()
-        )
+        efivarfs::efivars_dir()
+            .join(self.efivar_name())
+            .display()
+            .to_string()
     }
 
     /// extract raw bytes from UEFI using the /sys virtual filesystem
+    /// (or from a directory of captured efivars, see efivarfs::set_captured_efivars_dir)
     fn read_bytes(&self) -> Result<Vec<u8>> {
         // try to read data from varstore
         let mut file = File::open(&self.store_filename()).context(format!(
@@ -503,70 +537,229 @@ trait VariableStore {
             "failed to read bytes from sysfs efivars '{}' of size specified by varstore in hiidb",
             self.store_filename()
         ))?;
+        // changes staged by a WriteBatch haven't been written yet but they are read back
+        batch::apply_staged(&self.store_filename(), &mut buf);
         Ok(buf)
     }
 
     fn write_at_offset(&self, offset: u16, data: TypeValue) -> Result<()> {
-        // Steps:
-        // * Read bytes
-        // * Seek to 4 + offset
-        // * If checks pass, write your answer
-
-        // We have three layers of checks so as to not accidentally corrupt EFI vars.
+        if dry_run::is_dry_run() {
+            return self.plan_write_at_offset(offset, data);
+        }
 
-        // The /run/lock/efibootmgr-remount lock will release automatically on drop.
-        // If something errors out, doesn't matter since we are using the flock syscall to lock it.
-        // Linux will then release it automatically after the program ends.
+        // The answer is after the 4 bytes of attributes at the start of the efivar
+        let store_filename = self.store_filename();
+        let position = 4 + offset as usize;
+        let bytes = type_value_bytes(data);
+        if batch::stage(&store_filename, position, bytes.clone()) {
+            debug!("Staged value for {}", &store_filename);
+            return Ok(());
+        }
 
-        const LOCK_FILE_PATH: &str = "/run/lock/efibootmgr-remount";
-        let mut lock = FileLock::new(LOCK_FILE_PATH);
-        lock.lock()?;
+        write_efivars(&BTreeMap::from([(store_filename, vec![(position, bytes)])]))
+    }
 
+    /// plan_write_at_offset records what write_at_offset would write without locking,
+    /// remounting or writing anything.
+    fn plan_write_at_offset(&self, offset: u16, data: TypeValue) -> Result<()> {
         let store_filename = self.store_filename();
+        let file_contents = fs::read(&store_filename)
+            .context(format!("Failed to read efivarfs file '{}'", store_filename))?;
+
+        let new_bytes = type_value_bytes(data);
+        let start = 4 + offset as usize;
+        let old_bytes = file_contents
+            .get(start..start + new_bytes.len())
+            .ok_or_else(|| anyhow!("offset {} is past the end of '{}'", offset, store_filename))?
+            .to_vec();
+
+        debug!("Dry run, not writing value to {}", &store_filename);
+        dry_run::plan(PlannedWrite::Efivar {
+            path: store_filename,
+            offset,
+            old_bytes,
+            new_bytes,
+        });
+        Ok(())
+    }
+}
+
+/// type_value_bytes returns the little endian bytes of a numeric value (nothing for other types)
+fn type_value_bytes(data: TypeValue) -> Vec<u8> {
+    match data {
+        TypeValue::NumSize8(v) => v.to_le_bytes().to_vec(),
+        TypeValue::NumSize16(v) => v.to_le_bytes().to_vec(),
+        TypeValue::NumSize32(v) => v.to_le_bytes().to_vec(),
+        TypeValue::NumSize64(v) => v.to_le_bytes().to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+/// write_efivars reads every changed efivar, patches it and writes it back once. The lock and the
+/// efivarfs remount are only taken once for all of them. If a write fails, the efivars which were
+/// already written get their original contents back.
+pub(crate) fn write_efivars(changes: &StagedWrites) -> Result<()> {
+    // Steps:
+    // * Read bytes of every efivar
+    // * Patch them at 4 + offset of every change
+    // * If checks pass, write them
+
+    // We have three layers of checks so as to not accidentally corrupt EFI vars.
 
-        let mut file_ro = File::open(&store_filename)
-            .context(format!("Failed to open efivarfs file '{}' to get varstore bytes", store_filename))?;
+    // The /run/lock/efibootmgr-remount lock will release automatically on drop.
+    // If something errors out, doesn't matter since we are using the flock syscall to lock it.
+    // Linux will then release it automatically after the program ends.
+
+    if changes.is_empty() {
+        return Ok(());
+    }
+    if let Some(dir) = efivarfs::captured_efivars_dir() {
+        return Err(anyhow!(
+            "refusing to write to captured efivars in {}",
+            dir.display()
+        ));
+    }
+    if let Some(bundle) = bundle::replayed_bundle() {
+        return Err(anyhow!(
+            "refusing to write to efivars replayed from {}",
+            bundle.source().display()
+        ));
+    }
+
+    const LOCK_FILE_PATH: &str = "/run/lock/efibootmgr-remount";
+    let root = system_root();
+    let lock_file_path = root.join(LOCK_FILE_PATH);
+    if !root.is_host() {
+        // a mounted image or a test directory might not have /run/lock
+        if let Some(lock_dir) = lock_file_path.parent() {
+            fs::create_dir_all(lock_dir)
+                .context(format!("Failed to create {}", lock_dir.display()))?;
+        }
+    }
+    let mut lock = FileLock::new(lock_file_path.display().to_string());
+    lock.lock()?;
 
-        let mut file_contents = Vec::new();
-        file_ro
-            .read_to_end(&mut file_contents)
+    // Nothing is written unless every efivar could be read and patched
+    let mut writes = Vec::new();
+    for (store_filename, patches) in changes {
+        let original = fs::read(store_filename)
             .context(format!("Failed to read efivarfs file '{}'", store_filename))?;
+        let mut contents = original.clone();
+        for (position, bytes) in patches {
+            contents
+                .get_mut(*position..position + bytes.len())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "offset {} is past the end of '{}'",
+                        position - 4,
+                        store_filename
+                    )
+                })?
+                .copy_from_slice(bytes);
+        }
+        writes.push((store_filename, patches, original, contents));
+    }
 
-        let mut cursor = Cursor::new(file_contents);
-        cursor.seek(SeekFrom::Start(4 + offset as u64))?;
+    // The guards only make sense for a real efivarfs, a root which isn't the host's
+    // could be a plain directory (like a mounted image of another system).
+    let efivars_dir = efivarfs::efivars_dir();
+    let needs_guards = root.is_host() || efivarfs::is_efivarfs(&efivars_dir);
 
-        match data {
-            TypeValue::NumSize8(v) => {
-                v.write_options(&mut cursor, binrw::endian::Endian::Little, ())?
-            }
-            TypeValue::NumSize16(v) => {
-                v.write_options(&mut cursor, binrw::endian::Endian::Little, ())?
-            }
-            TypeValue::NumSize32(v) => {
-                v.write_options(&mut cursor, binrw::endian::Endian::Little, ())?
+    let _efifs = if needs_guards {
+        Some(
+            EfivarsMountGuard::new(&efivars_dir)
+                .context("Failed to create efivars fs mount guard")?,
+        )
+    } else {
+        debug!(
+            "{} isn't an efivarfs, skipping remount",
+            efivars_dir.display()
+        );
+        None
+    };
+
+    // All checks passed, now we can try to write.
+    let mut written: Vec<(&String, &Vec<u8>)> = Vec::new();
+    for (store_filename, patches, original, contents) in &writes {
+        let mut result = write_efivar(store_filename, contents, needs_guards);
+        if result.is_ok() {
+            // the file was rewritten even if the firmware didn't store what we asked for
+            written.push((store_filename, original));
+            result = verify_efivar(store_filename, patches, contents);
+        }
+        if let Err(why) = result {
+            let mut restore_errors = Vec::new();
+            for (written_filename, written_original) in written.iter().rev() {
+                if let Err(restore_why) =
+                    write_efivar(written_filename, written_original, needs_guards)
+                {
+                    restore_errors.push(format!("'{}': {:#}", written_filename, restore_why));
+                }
             }
-            TypeValue::NumSize64(v) => {
-                v.write_options(&mut cursor, binrw::endian::Endian::Little, ())?
+
+            let mut msg = format!("Failed to write efivarfs file '{}'", store_filename);
+            if !written.is_empty() && restore_errors.is_empty() {
+                msg.push_str(", written efivars were restored");
+            } else if !restore_errors.is_empty() {
+                msg.push_str(&format!(
+                    ", restoring failed for:\n{}",
+                    restore_errors.join("\n")
+                ));
             }
-            _ => {}
+            return Err(why.context(msg));
         }
+    }
+
+    Ok(())
+}
 
-        let _efifs = EfivarsMountGuard::new().context("Failed to create efivars fs mount guard")?;
+/// verify_efivar reads an efivar back after it was written and checks that the bytes of every
+/// change are what was written. The kernel or the firmware can reject or alter a write.
+fn verify_efivar(
+    store_filename: &str,
+    patches: &[(usize, Vec<u8>)],
+    contents: &[u8],
+) -> Result<()> {
+    let stored = fs::read(store_filename).context(format!(
+        "Failed to read back efivarfs file '{}'",
+        store_filename
+    ))?;
+    for (position, bytes) in patches {
+        let range = *position..position + bytes.len();
+        let expected = &contents[range.clone()];
+        let actual = stored.get(range).unwrap_or_default();
+        if actual != expected {
+            return Err(WriteVerificationError {
+                store_filename: store_filename.to_owned(),
+                offset: position - 4,
+                expected: expected.to_vec(),
+                actual: actual.to_vec(),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
 
-        // Needed on kernel 4.6+ to make EFI vars the kernel doesn't know how to
-        // validate temporarily writable.
-        let _immutability_attribute_guard = EfivarsImmutabilityGuard::new(&store_filename)
-            .context("failed to create immutability attribute guard")?;
+fn write_efivar(store_filename: &str, contents: &[u8], needs_guards: bool) -> Result<()> {
+    // Needed on kernel 4.6+ to make EFI vars the kernel doesn't know how to
+    // validate temporarily writable.
+    let _immutability_attribute_guard = if needs_guards {
+        Some(
+            EfivarsImmutabilityGuard::new(store_filename)
+                .context("failed to create immutability attribute guard")?,
+        )
+    } else {
+        None
+    };
 
-        // All checks passed, now we can try to write.
-        debug!("Writing value to {}", &store_filename);
-        File::create(&store_filename)
-            .context("Failed to open efivarfs file for writing")?
-            .write_all(cursor.get_ref())
-            .context("Failed to write to efivarfs file")?;
+    debug!("Writing value to {}", store_filename);
+    File::create(store_filename)
+        .context("Failed to open efivarfs file for writing")?
+        .write_all(contents)
+        .context("Failed to write to efivarfs file")?;
 
-        Ok(())
-    }
+    Ok(())
 }
 
 #[derive(BinRead, Debug, PartialEq, Clone)]
@@ -625,14 +818,11 @@ pub struct DefaultStore {
 pub struct IFRDefault {
     pub default_id: u16,
     value_type: u8,
-    // The third field is TypeValue and should be parsed with the following code.
-    // However the structure of IFRDefault and existence of that field varies depending on
-    // what scope the IFRDefault is in, even though the opcode remains the same.
-
-    // Since we are not using TypeValue for IFRDefault right now, we're gonna pretend that field does not exist.
-
-    // #[br(parse_with = type_value_parser, args(value_type))]
-    // pub value: TypeValue,
+    // The third field is TypeValue but it only exists if the IFRDefault doesn't open a scope.
+    // Otherwise (EFI_IFR_DEFAULT_2) the value is computed by an expression in its scope,
+    // so it's parsed by handle_opcode and left as None for those.
+    #[br(ignore)]
+    pub value: Option<TypeValue>,
 }
 
 #[derive(BinRead, Debug, PartialEq)]
@@ -768,6 +958,7 @@ pub fn handle_form_package(
     // this is the root element so all the values like op_code, length, etc are dummy
     debug!("new forms package");
     let root = Rc::new(RefCell::new(IFROperation {
+        raw_op_code: DUMMY_OPCODE,
         op_code: IFROpCode::Unknown(DUMMY_OPCODE),
         length: 0,
         open_scope: false,
@@ -927,9 +1118,18 @@ fn handle_opcode(node: Rc<RefCell<IFROperation>>) -> Result<()> {
             node.parsed_data = ParsedOperation::DefaultStore(parsed);
         }
         IFROpCode::Default => {
-            let parsed: IFRDefault = data_cursor
+            let mut parsed: IFRDefault = data_cursor
                 .read_ne()
                 .context("Failed to parse Default's data")?;
+            if !node.open_scope {
+                // a value which can't be parsed only means the question has no known default
+                parsed.value = type_value_parser(
+                    &mut data_cursor,
+                    binrw::Endian::Little,
+                    (parsed.value_type,),
+                )
+                .ok();
+            }
             debug!("Default is {:?}", parsed);
             node.parsed_data = ParsedOperation::IFRDefault(parsed);
         }
@@ -992,9 +1192,15 @@ pub struct QuestionDescriptor {
     pub question: String,
     pub help: String,
     pub value: String,
+    /// GUID of the FormSet this question belongs to
+    pub formset_guid: String,
+    /// titles of the FormSet and Form this question is in, outermost first
+    pub breadcrumb: Vec<String>,
     max_value: RangeType,
     opcode: IFROpCode,
     pub possible_options: Vec<AnswerOption>,
+    /// label of the answer which is used by default, if the question has one
+    pub default: Option<String>,
     header: QuestionHeader,
     varstore: Option<Box<dyn VariableStore>>,
 }
@@ -1004,17 +1210,123 @@ impl fmt::Debug for QuestionDescriptor {
             .field("question", &self.question)
             .field("value", &self.value)
             .field("help", &self.help)
+            .field("breadcrumb", &self.breadcrumb)
             .field("possible_options", &self.possible_options)
             .finish()
     }
 }
 
+impl QuestionDescriptor {
+    /// identity describes where this question's answer is stored.
+    /// Two descriptors with the same identity are the same setting even if they show up in different forms.
+    pub fn identity(&self) -> String {
+        match self.storage() {
+            Some(storage) => storage,
+            None => format!("{}#{}", self.formset_guid, self.header.question_id),
+        }
+    }
+
+    /// storage describes the varstore and offset of this question's answer like Setup-<guid>@0x10
+    pub fn storage(&self) -> Option<String> {
+        self.varstore.as_ref().map(|varstore| {
+            format!(
+                "{}-{}@{:#x}",
+                varstore.name(),
+                varstore.guid(),
+                self.header.var_store_info
+            )
+        })
+    }
+
+    /// varstore_name is the name of the varstore this question's answer is stored in like Setup
+    pub fn varstore_name(&self) -> Option<String> {
+        self.varstore.as_ref().map(|varstore| varstore.name())
+    }
+
+    pub fn question_id(&self) -> u16 {
+        self.header.question_id
+    }
+
+    /// kind is the type of question like OneOf, CheckBox or Numeric
+    pub fn kind(&self) -> String {
+        format!("{:?}", self.opcode)
+    }
+
+    /// raw_value reads the number which is stored in the varstore for this question
+    pub fn raw_value(&self) -> Result<u64> {
+        let varstore = self
+            .varstore
+            .as_ref()
+            .ok_or_else(|| anyhow!("question {} has no varstore", self.question))?;
+        let bytes = varstore.read_bytes()?;
+        let offset = self.header.var_store_info;
+        Ok(match self.max_value {
+            RangeType::NumSize8(_) => extract_efi_data::<u8>(offset, &bytes)?.into(),
+            RangeType::NumSize16(_) => extract_efi_data::<u16>(offset, &bytes)?.into(),
+            RangeType::NumSize32(_) => extract_efi_data::<u32>(offset, &bytes)?.into(),
+            RangeType::NumSize64(_) => extract_efi_data::<u64>(offset, &bytes)?,
+        })
+    }
+
+    /// current_value reads the answer stored in the varstore, as the label of its option
+    /// if the question has options
+    pub fn current_value(&self) -> Result<String> {
+        let raw_value = self.raw_value()?.to_string();
+        Ok(self
+            .possible_options
+            .iter()
+            .find(|opt| opt.raw_value_string() == raw_value)
+            .map(|opt| opt.value.trim().to_owned())
+            .unwrap_or(raw_value))
+    }
+
+    /// is_checkbox returns true if the answer is stored as 1 or 0
+    pub fn is_checkbox(&self) -> bool {
+        self.opcode == IFROpCode::CheckBox
+    }
+}
+
 #[derive(Debug)]
 pub struct AnswerOption {
     pub value: String,
     raw_value: TypeValue,
 }
 
+impl AnswerOption {
+    /// raw_value_string is the value stored in the varstore when this option is chosen
+    pub fn raw_value_string(&self) -> String {
+        type_value_string(&self.raw_value)
+    }
+}
+
+/// type_value_string formats numbers and booleans as they are, other values for debugging
+fn type_value_string(value: &TypeValue) -> String {
+    match value {
+        TypeValue::NumSize8(v) => v.to_string(),
+        TypeValue::NumSize16(v) => v.to_string(),
+        TypeValue::NumSize32(v) => v.to_string(),
+        TypeValue::NumSize64(v) => v.to_string(),
+        TypeValue::Boolean(v) => v.to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// find_default_value returns the value of a question's IFRDefault for the standard default store,
+/// or of the first one which has a value if there is none for it
+fn find_default_value(question_node: &IFROperation) -> Option<TypeValue> {
+    let mut res = None;
+    for child in &question_node.children {
+        if let ParsedOperation::IFRDefault(default) = &child.borrow().parsed_data {
+            match default.value {
+                Some(value) if default.default_id == STANDARD_DEFAULT_ID => return Some(value),
+                Some(value) if res.is_none() => res = Some(value),
+                _ => {}
+            }
+        }
+    }
+    res
+}
+
 // list_questions returns a list of QuestionDescriptors in a form package
 // node should be the form_package node
 pub fn list_questions(
@@ -1089,100 +1401,224 @@ pub fn list_questions(
     res
 }
 
-/// find_question accepts the root node, string_packages and possible_question_phrases.
+#[derive(Debug)]
+pub struct FormSetDescriptor {
+    pub guid: String,
+    pub class_guid: String,
+    pub title: String,
+    pub help: String,
+    pub forms: Vec<FormDescriptor>,
+}
+
+#[derive(Debug)]
+pub struct FormDescriptor {
+    pub form_id: u16,
+    pub title: String,
+    pub question_count: usize,
+}
+
+// list_forms returns the FormSets in a form package along with the Forms inside them
+// node should be the form_package node
+pub fn list_forms(
+    node: Rc<RefCell<IFROperation>>,
+    string_packages: &Vec<HashMap<i32, String>>,
+) -> Vec<FormSetDescriptor> {
+    let mut res = Vec::new();
+
+    let current_node = node.borrow();
+
+    match &current_node.parsed_data {
+        ParsedOperation::FormSet(parsed) => {
+            let mut formset = FormSetDescriptor {
+                guid: parsed.guid.to_string(),
+                class_guid: parsed.class_guid.to_string(),
+                title: find_corresponding_string(parsed.title_string_id, string_packages)
+                    .trim()
+                    .to_string(),
+                help: find_corresponding_string(parsed.help_string_id, string_packages)
+                    .trim()
+                    .to_string(),
+                forms: Vec::new(),
+            };
+
+            // Forms are direct children of their FormSet
+            for child in &current_node.children {
+                if let ParsedOperation::Form(form) = &child.borrow().parsed_data {
+                    formset.forms.push(FormDescriptor {
+                        form_id: form.form_id,
+                        title: find_corresponding_string(form.title_string_id, string_packages)
+                            .trim()
+                            .to_string(),
+                        question_count: count_questions(Rc::clone(child)),
+                    });
+                }
+            }
+
+            res.push(formset);
+        }
+        _ => {
+            for child in &current_node.children {
+                res.extend(list_forms(Rc::clone(child), string_packages));
+            }
+        }
+    }
+
+    res
+}
+
+/// count_questions counts the questions (OneOf, Numeric and CheckBox) under the given node
+fn count_questions(node: Rc<RefCell<IFROperation>>) -> usize {
+    let current_node = node.borrow();
+
+    let own = match &current_node.parsed_data {
+        ParsedOperation::OneOf(_) | ParsedOperation::Numeric(_) | ParsedOperation::CheckBox(_) => 1,
+        _ => 0,
+    };
+
+    own + current_node
+        .children
+        .iter()
+        .map(|child| count_questions(Rc::clone(child)))
+        .sum::<usize>()
+}
+
+/// find_questions accepts the root node, string_packages and possible_question_phrases.
 /// possible_question_phrases is a vector of strings which represent variations of the
-/// same question. So if a single phrase matches then we assume that we have the answer.
-pub fn find_question<T>(
+/// same question. Every question whose prompt matches any of the phrases is returned
+/// because unrelated formsets can use the same prompt (like "Enable") for different settings.
+/// Prompts and phrases are compared after normalization (see normalize::normalize) so
+/// "Intel(R) VT-d [ALL]" matches "Intel® VT-d".
+pub fn find_questions<T>(
     node: Rc<RefCell<IFROperation>>,
     string_packages: &Vec<HashMap<i32, String>>,
     possible_question_phrases: &HashSet<T>,
-) -> Option<QuestionDescriptor>
+) -> Vec<QuestionDescriptor>
 where
     T: AsRef<str>,
 {
+    let normalized_phrases: HashSet<String> = possible_question_phrases
+        .iter()
+        .map(|phrase| normalize(phrase.as_ref()))
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+
+    find_normalized_questions(node, string_packages, &normalized_phrases)
+}
+
+fn find_normalized_questions(
+    node: Rc<RefCell<IFROperation>>,
+    string_packages: &Vec<HashMap<i32, String>>,
+    normalized_phrases: &HashSet<String>,
+) -> Vec<QuestionDescriptor> {
+    let mut res = Vec::new();
     let current_node = node.borrow();
 
     // Only Numeric, OneOf and CheckBox are questions.
-    // If our question is found this match expression will return without caring if we found answer.
-    // Otherwise, we will look at children of current_node
 
-    for phrase in possible_question_phrases {
-        match &current_node.parsed_data {
-            ParsedOperation::Numeric(parsed) => {
-                let question = find_corresponding_string(
-                    parsed.question_header().prompt_string_id,
-                    string_packages,
-                );
-
-                if phrase.as_ref().eq_ignore_ascii_case(question.trim()) {
-                    let varstore = find_corresponding_varstore(
-                        Rc::clone(&node),
-                        parsed.question_header().var_store_id,
-                    );
+    match &current_node.parsed_data {
+        ParsedOperation::Numeric(parsed) => {
+            let question = find_corresponding_string(
+                parsed.question_header().prompt_string_id,
+                string_packages,
+            );
 
-                    let res =
-                        handle_numeric(varstore, parsed, question, string_packages, &current_node);
+            if normalized_phrases.contains(&normalize(question)) {
+                let varstore = find_corresponding_varstore(
+                    Rc::clone(&node),
+                    parsed.question_header().var_store_id,
+                );
 
-                    return Some(res);
-                }
-            }
-            ParsedOperation::OneOf(parsed) => {
-                let question = find_corresponding_string(
-                    parsed.question_header().prompt_string_id,
+                res.push(handle_numeric(
+                    varstore,
+                    parsed,
+                    question,
                     string_packages,
+                    &current_node,
+                ));
+            }
+        }
+        ParsedOperation::OneOf(parsed) => {
+            let question = find_corresponding_string(
+                parsed.question_header().prompt_string_id,
+                string_packages,
+            );
+
+            if normalized_phrases.contains(&normalize(question)) {
+                let varstore = find_corresponding_varstore(
+                    Rc::clone(&node),
+                    parsed.question_header().var_store_id,
                 );
 
-                if phrase.as_ref().eq_ignore_ascii_case(question.trim()) {
-                    let varstore = find_corresponding_varstore(
-                        Rc::clone(&node),
-                        parsed.question_header().var_store_id,
-                    );
+                res.push(handle_oneof(
+                    varstore,
+                    parsed,
+                    &node,
+                    string_packages,
+                    question,
+                    &current_node,
+                ));
+            }
+        }
+        ParsedOperation::CheckBox(parsed) => {
+            let question = find_corresponding_string(
+                parsed.question_header().prompt_string_id,
+                string_packages,
+            );
 
-                    let res = handle_oneof(
-                        varstore,
-                        parsed,
-                        &node,
-                        string_packages,
-                        question,
-                        &current_node,
-                    );
+            if normalized_phrases.contains(&normalize(question)) {
+                let varstore = find_corresponding_varstore(
+                    Rc::clone(&node),
+                    parsed.question_header().var_store_id,
+                );
 
-                    return Some(res);
-                }
-            }
-            ParsedOperation::CheckBox(parsed) => {
-                let question = find_corresponding_string(
-                    parsed.question_header().prompt_string_id,
+                res.push(handle_checkbox(
+                    varstore,
+                    parsed,
+                    question,
                     string_packages,
-                );
+                    &current_node,
+                ));
+            }
+        }
 
-                if phrase.as_ref().eq_ignore_ascii_case(question.trim()) {
-                    let varstore = find_corresponding_varstore(
-                        Rc::clone(&node),
-                        parsed.question_header().var_store_id,
-                    );
+        _ => {}
+    }
 
-                    let res =
-                        handle_checkbox(varstore, parsed, question, string_packages, &current_node);
+    // look at children for more matches
+    for child in &current_node.children {
+        res.extend(find_normalized_questions(
+            Rc::clone(child),
+            string_packages,
+            normalized_phrases,
+        ));
+    }
 
-                    return Some(res);
-                }
-            }
+    res
+}
 
-            _ => {}
-        }
+/// list_prompts returns the prompts of all questions under the node without reading their values
+pub fn list_prompts(
+    node: Rc<RefCell<IFROperation>>,
+    string_packages: &Vec<HashMap<i32, String>>,
+) -> Vec<String> {
+    let mut res = Vec::new();
+    let current_node = node.borrow();
+
+    let prompt_string_id = match &current_node.parsed_data {
+        ParsedOperation::Numeric(parsed) => Some(parsed.question_header().prompt_string_id),
+        ParsedOperation::OneOf(parsed) => Some(parsed.question_header().prompt_string_id),
+        ParsedOperation::CheckBox(parsed) => Some(parsed.question_header().prompt_string_id),
+        _ => None,
+    };
+    if let Some(prompt_string_id) = prompt_string_id {
+        res.push(find_corresponding_string(prompt_string_id, string_packages).to_owned());
     }
 
-    // Question not found in current_node so look at children
-    for child in &node.borrow().children {
-        if let Some(res) =
-            find_question(Rc::clone(child), string_packages, possible_question_phrases)
-        {
-            return Some(res);
-        }
+    for child in &current_node.children {
+        res.extend(list_prompts(Rc::clone(child), string_packages));
     }
 
-    None
+    res
 }
 
 fn handle_checkbox(
@@ -1212,12 +1648,21 @@ fn handle_checkbox(
             }
         },
     }
+    let (formset_guid, breadcrumb) = find_location(current_node, string_packages);
     let res = QuestionDescriptor {
         question: question.to_string(),
         value: answer,
         help: find_corresponding_string(parsed.question_header().help_string_id, string_packages)
             .to_string(),
+        formset_guid,
+        breadcrumb,
         possible_options: Vec::new(),
+        default: Some(match find_default_value(current_node) {
+            Some(TypeValue::Boolean(v)) => u8::from(v).to_string(),
+            Some(value) => type_value_string(&value),
+            None if parsed.flags & CHECKBOX_DEFAULT_FLAG != 0 => "1".to_string(),
+            None => "0".to_string(),
+        }),
         header: parsed.question_header(),
         varstore: varstore.ok(),
         max_value: RangeType::NumSize8(1),
@@ -1295,6 +1740,7 @@ fn handle_oneof(
     let mut possible_options = Vec::new();
     // Some of OneOf's children are OneOfOptions
 
+    let mut option_default = None;
     let mut found_option = false;
     for child in &node.borrow().children {
         match &child.borrow().parsed_data {
@@ -1319,6 +1765,10 @@ fn handle_oneof(
                     // cannot break here because we want to add all options to possible_options
                 }
 
+                if o.flags & OPTION_DEFAULT_FLAG != 0 && option_default.is_none() {
+                    option_default = Some(opt.value.trim().to_string());
+                }
+
                 possible_options.push(opt);
             }
             _ => {}
@@ -1328,12 +1778,27 @@ fn handle_oneof(
 		answer.push_str("Unknown");
 	}
 
+    // an IFRDefault takes precedence over the default flag of the options
+    let default = find_default_value(current_node)
+        .map(|value| type_value_string(&value))
+        .and_then(|value| {
+            possible_options
+                .iter()
+                .find(|opt| opt.raw_value_string() == value)
+                .map(|opt| opt.value.trim().to_string())
+        })
+        .or(option_default);
+
+    let (formset_guid, breadcrumb) = find_location(current_node, string_packages);
     let res = QuestionDescriptor {
         question: question.trim().to_string(),
         value: answer,
         help: find_corresponding_string(parsed.question_header().help_string_id, string_packages)
             .to_string(),
+        formset_guid,
+        breadcrumb,
         possible_options,
+        default,
         header: parsed.question_header(),
         varstore: varstore.ok(),
         max_value: match &parsed.data {
@@ -1380,12 +1845,16 @@ fn handle_numeric(
             },
         },
     }
+    let (formset_guid, breadcrumb) = find_location(current_node, string_packages);
     let res = QuestionDescriptor {
         question: question.to_string(),
         value: answer,
         help: find_corresponding_string(parsed.question_header().help_string_id, string_packages)
             .to_string(),
+        formset_guid,
+        breadcrumb,
         possible_options: Vec::new(),
+        default: find_default_value(current_node).map(|value| type_value_string(&value)),
         header: parsed.question_header(),
         varstore: varstore.ok(),
         max_value: match &parsed.data {
@@ -1628,12 +2097,26 @@ pub fn display(
     Ok(result)
 }
 
+/// WriteVerificationError means an efivar doesn't hold what was written to it when it's read back
+#[derive(Error, Debug)]
+#[error(
+    "efivar '{store_filename}' holds {actual:02x?} at offset {offset} instead of the written {expected:02x?}"
+)]
+pub struct WriteVerificationError {
+    pub store_filename: String,
+    pub offset: usize,
+    pub expected: Vec<u8>,
+    pub actual: Vec<u8>,
+}
+
 #[derive(Error, Debug)]
 pub enum ChangeValueError {
     #[error("provided value did not match any possible option")]
     InvalidOption,
     #[error("provided value exceeded max possible value")]
     ExceededMaxValue,
+    #[error("numeric questions store plain numbers, provided value has a unit: {0}")]
+    UnexpectedUnit(String),
 
     #[error(transparent)]
     Other(#[from] anyhow::Error),
@@ -1741,6 +2224,60 @@ fn find_corresponding_string<'a>(
     ""
 }
 
+/// find_location bubbles up from the current node and returns the GUID of the FormSet
+/// along with the titles of the FormSet and Form which contain the node.
+fn find_location(
+    node: &IFROperation,
+    string_packages: &Vec<HashMap<i32, String>>,
+) -> (String, Vec<String>) {
+    let mut formset_guid = String::new();
+    let mut breadcrumb = Vec::new();
+
+    let mut parent = node.parent.as_ref().and_then(|p| p.upgrade());
+    while let Some(current) = parent {
+        let current_node = current.borrow();
+        match &current_node.parsed_data {
+            ParsedOperation::Form(parsed) => breadcrumb.push(
+                find_corresponding_string(parsed.title_string_id, string_packages)
+                    .trim()
+                    .to_string(),
+            ),
+            ParsedOperation::FormSet(parsed) => {
+                formset_guid = parsed.guid.to_string();
+                breadcrumb.push(
+                    find_corresponding_string(parsed.title_string_id, string_packages)
+                        .trim()
+                        .to_string(),
+                );
+            }
+            _ => {}
+        }
+        parent = current_node.parent.as_ref().and_then(|p| p.upgrade());
+    }
+
+    breadcrumb.reverse();
+    (formset_guid, breadcrumb)
+}
+
+/// varstore_efivars returns the names of the efivars backing every varstore in this form package
+pub fn varstore_efivars(node: Rc<RefCell<IFROperation>>) -> BTreeSet<String> {
+    let mut res = BTreeSet::new();
+    let current_node = node.borrow();
+    match &current_node.parsed_data {
+        ParsedOperation::VarStore(v) => {
+            res.insert(v.efivar_name());
+        }
+        ParsedOperation::VarStoreEfi(v) => {
+            res.insert(v.efivar_name());
+        }
+        _ => {}
+    }
+    for child in &current_node.children {
+        res.extend(varstore_efivars(Rc::clone(child)));
+    }
+    res
+}
+
 /// find_corresponding_varstore bubble's up from current node till we find a FormSet.
 /// then it looks for varstores which will be FormSet's children
 fn find_corresponding_varstore(
@@ -1798,3 +2335,84 @@ where
 
     Ok(answer)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hii::package;
+    use crate::hii::test_db;
+
+    #[test]
+    fn test_defaults() {
+        let guid = [0x11u8; 16];
+        let db = test_db::package_list(
+            "11111111-1111-1111-1111-111111111111",
+            &[
+                "Formset",
+                "Help",
+                "Disabled",
+                "Enabled",
+                "No Default",
+                "From Default",
+                "Count",
+            ],
+            &[test_db::form_set(
+                guid,
+                1,
+                2,
+                &[
+                    test_db::var_store(guid, 1, 8, "Setup"),
+                    test_db::form(
+                        1,
+                        1,
+                        &[
+                            test_db::one_of(5, 2, 1, 1, 0, &[3, 4]),
+                            test_db::with_children(
+                                test_db::one_of(6, 2, 2, 1, 1, &[3, 4]),
+                                // the manufacturing default store is only used without a standard one
+                                &[test_db::default(1, 0), test_db::default(0, 1)],
+                            ),
+                            test_db::with_children(
+                                test_db::numeric(7, 2, 3, 1, 2, 10),
+                                &[test_db::default(0, 5)],
+                            ),
+                        ],
+                    ),
+                ],
+            )],
+        );
+
+        let parsed_db = package::read_db(&db).unwrap();
+        let guid = "11111111-1111-1111-1111-111111111111";
+        let defaults: Vec<(String, Option<String>)> = parsed_db.forms[guid]
+            .iter()
+            .flat_map(|root| list_questions(Rc::clone(root), &parsed_db.strings[guid]))
+            .map(|q| (q.question, q.default))
+            .collect();
+        assert_eq!(
+            defaults,
+            vec![
+                ("No Default".to_owned(), None),
+                ("From Default".to_owned(), Some("Enabled".to_owned())),
+                ("Count".to_owned(), Some("5".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_efivar() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(file.path(), [7, 0, 0, 0, 1, 0]).unwrap();
+        let store_filename = file.path().display().to_string();
+        let patches = vec![(4, vec![1])];
+
+        verify_efivar(&store_filename, &patches, &[7, 0, 0, 0, 1, 0]).unwrap();
+
+        // the firmware kept 1 where 2 was written
+        let err = verify_efivar(&store_filename, &patches, &[7, 0, 0, 0, 2, 0]).unwrap_err();
+        let mismatch = err.downcast_ref::<WriteVerificationError>().unwrap();
+        assert_eq!(mismatch.offset, 0);
+        assert_eq!(mismatch.expected, vec![2]);
+        assert_eq!(mismatch.actual, vec![1]);
+    }
+}
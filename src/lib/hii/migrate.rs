@@ -0,0 +1,233 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Map the settings saved by hii snapshot onto the questions of another BIOS version.
+// Varstore offsets and question ids move between firmware releases, so saved settings are
+// matched by what the user sees instead: the prompt, the forms it's in, its options and the
+// name of its varstore.
+
+use crate::normalize::equivalent;
+
+/// SettingProfile is what identifies a question across BIOS versions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SettingProfile {
+    pub prompt: String,
+    /// titles of the formset and form the question is in
+    pub breadcrumb: Vec<String>,
+    /// labels of the options, empty for questions without options
+    pub options: Vec<String>,
+    /// name of the varstore like Setup
+    pub varstore: String,
+}
+
+/// SavedSetting is a question and its value from a snapshot
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SavedSetting {
+    pub profile: SettingProfile,
+    /// number stored in the varstore
+    pub raw_value: String,
+    /// label of the chosen option for questions with options
+    pub display_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mapping {
+    /// prompt, breadcrumb, options and varstore name are the same
+    Confident { index: usize, value: String },
+    /// the prompt is the same but the fields in differences aren't
+    Uncertain {
+        index: usize,
+        value: String,
+        differences: Vec<&'static str>,
+    },
+    /// nothing the setting can be mapped to
+    Dropped { reason: String },
+}
+
+/// map_setting finds the question in current which the saved setting should be written to.
+/// The value is the label of an option for questions with options, otherwise the raw value.
+pub fn map_setting(saved: &SavedSetting, current: &[SettingProfile]) -> Mapping {
+    let mut best: Vec<(usize, Vec<&'static str>)> = Vec::new();
+    for (index, candidate) in current.iter().enumerate() {
+        if !equivalent(&saved.profile.prompt, &candidate.prompt) {
+            continue;
+        }
+        let differences = differences(&saved.profile, candidate);
+        match best.first() {
+            Some((_, best_differences)) if best_differences.len() < differences.len() => {}
+            Some((_, best_differences)) if best_differences.len() == differences.len() => {
+                best.push((index, differences))
+            }
+            _ => best = vec![(index, differences)],
+        }
+    }
+
+    let (index, differences) = match best.len() {
+        0 => {
+            return Mapping::Dropped {
+                reason: "no question has this prompt".to_owned(),
+            };
+        }
+        1 => best.remove(0),
+        n => {
+            return Mapping::Dropped {
+                reason: format!("{} questions match equally well", n),
+            };
+        }
+    };
+
+    let candidate = &current[index];
+    let value = if candidate.options.is_empty() {
+        if !saved.profile.options.is_empty() {
+            return Mapping::Dropped {
+                reason: "the question doesn't have options anymore".to_owned(),
+            };
+        }
+        saved.raw_value.clone()
+    } else {
+        match candidate
+            .options
+            .iter()
+            .find(|option| equivalent(option, &saved.display_value))
+        {
+            Some(option) => option.clone(),
+            None => {
+                return Mapping::Dropped {
+                    reason: format!("option {} doesn't exist anymore", saved.display_value),
+                };
+            }
+        }
+    };
+
+    if differences.is_empty() {
+        Mapping::Confident { index, value }
+    } else {
+        Mapping::Uncertain {
+            index,
+            value,
+            differences,
+        }
+    }
+}
+
+fn differences(saved: &SettingProfile, candidate: &SettingProfile) -> Vec<&'static str> {
+    let same_list = |a: &[String], b: &[String]| {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| equivalent(a, b))
+    };
+
+    let mut res = Vec::new();
+    if !same_list(&saved.breadcrumb, &candidate.breadcrumb) {
+        res.push("breadcrumb");
+    }
+    if !same_list(&saved.options, &candidate.options) {
+        res.push("options");
+    }
+    if saved.varstore != candidate.varstore {
+        res.push("varstore");
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(prompt: &str, form: &str, options: &[&str]) -> SettingProfile {
+        SettingProfile {
+            prompt: prompt.to_owned(),
+            breadcrumb: vec!["Setup".to_owned(), form.to_owned()],
+            options: options.iter().map(|o| o.to_string()).collect(),
+            varstore: "Setup".to_owned(),
+        }
+    }
+
+    fn saved(profile: SettingProfile, display_value: &str) -> SavedSetting {
+        SavedSetting {
+            profile,
+            raw_value: "1".to_owned(),
+            display_value: display_value.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_map_setting() {
+        let current = vec![
+            profile("Intel(R) TXT", "Security", &["Disabled", "Enabled"]),
+            profile("SR-IOV Support", "PCI", &["Disabled", "Enabled", "Auto"]),
+            profile("Boot Timeout", "Boot", &[]),
+            profile("Option ROM", "Slot 1", &["Disabled", "Enabled"]),
+            profile("Option ROM", "Slot 2", &["Disabled", "Enabled"]),
+        ];
+
+        assert_eq!(
+            map_setting(
+                &saved(
+                    profile("Intel TXT", "Security", &["Disabled", "Enabled"]),
+                    "Enabled"
+                ),
+                &current
+            ),
+            Mapping::Confident {
+                index: 0,
+                value: "Enabled".to_owned()
+            }
+        );
+        assert_eq!(
+            map_setting(
+                &saved(
+                    profile("SR-IOV Support", "Chipset", &["Disabled", "Enabled"]),
+                    "Enabled"
+                ),
+                &current
+            ),
+            Mapping::Uncertain {
+                index: 1,
+                value: "Enabled".to_owned(),
+                differences: vec!["breadcrumb", "options"],
+            }
+        );
+        assert_eq!(
+            map_setting(&saved(profile("Boot Timeout", "Boot", &[]), ""), &current),
+            Mapping::Confident {
+                index: 2,
+                value: "1".to_owned()
+            }
+        );
+        assert_eq!(
+            map_setting(
+                &saved(
+                    profile("Option ROM", "Slot 3", &["Disabled", "Enabled"]),
+                    "Enabled"
+                ),
+                &current
+            ),
+            Mapping::Dropped {
+                reason: "2 questions match equally well".to_owned()
+            }
+        );
+        assert_eq!(
+            map_setting(
+                &saved(profile("Intel(R) TXT", "Security", &["Off", "On"]), "On"),
+                &current
+            ),
+            Mapping::Dropped {
+                reason: "option On doesn't exist anymore".to_owned()
+            }
+        );
+        assert_eq!(
+            map_setting(&saved(profile("Removed", "Boot", &[]), ""), &current),
+            Mapping::Dropped {
+                reason: "no question has this prompt".to_owned()
+            }
+        );
+    }
+}
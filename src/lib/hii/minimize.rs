@@ -0,0 +1,451 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Shrink a HiiDB dump down to a few questions so it can be shared as a fixture (real dumps are proprietary).
+// The result keeps the formsets with their varstores and default stores, the forms which have one of
+// the questions, the questions with everything in their scope (options, defaults) and only the strings
+// which are still used. Conditions around questions (like SuppressIf) are dropped because they can
+// refer to questions which are removed.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use anyhow::Result;
+
+use crate::hii::forms::IFROperation;
+use crate::hii::forms::ParsedOperation;
+use crate::hii::forms::Question;
+use crate::hii::package;
+use crate::hii::package::Guid;
+use crate::hii::strings;
+use crate::normalize::normalize;
+
+// IFR End operation (op code 0x29, length 2) which closes a scope
+const END_OPERATION: [u8; 2] = [0x29, 0x02];
+
+type IFRNodeLink = Rc<RefCell<IFROperation>>;
+
+/// StringUse is what a kept string is used for, only labels are needed to find and set questions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum StringUse {
+    /// titles, help texts and names which can be replaced by placeholders
+    Description,
+    /// question prompts and option labels
+    Label,
+}
+
+/// minimize returns a HiiDB with only the questions whose prompts match one of the
+/// question phrases (after normalization). If anonymize is true, every string
+/// except question prompts and option labels is replaced by a placeholder.
+pub fn minimize<T>(db: &[u8], question_phrases: &HashSet<T>, anonymize: bool) -> Result<Vec<u8>>
+where
+    T: AsRef<str>,
+{
+    let normalized_phrases: HashSet<String> = question_phrases
+        .iter()
+        .map(|phrase| normalize(phrase.as_ref()))
+        .filter(|phrase| !phrase.is_empty())
+        .collect();
+
+    let parsed_db = package::read_db(db)?;
+    let string_headers = package::read_string_headers(db)?;
+
+    // sorted so that minimizing the same dump always gives the same result
+    let mut guids: Vec<&String> = parsed_db.forms.keys().collect();
+    guids.sort();
+
+    let mut res = Vec::new();
+    for guid in guids {
+        let no_strings = Vec::new();
+        let string_packages = parsed_db.strings.get(guid).unwrap_or(&no_strings);
+
+        let mut used_strings = BTreeMap::new();
+        let form_packages: Vec<Vec<u8>> = parsed_db.forms[guid]
+            .iter()
+            .flat_map(|root| {
+                minimize_form_package(
+                    root,
+                    string_packages,
+                    &normalized_phrases,
+                    &mut used_strings,
+                )
+            })
+            .collect();
+        if form_packages.is_empty() {
+            continue;
+        }
+
+        // every string package keeps its language, and so the name of the language
+        let no_headers = Vec::new();
+        let headers = string_headers.get(guid).unwrap_or(&no_headers);
+        for header in headers {
+            if header.language_name != 0 {
+                use_string(
+                    &mut used_strings,
+                    header.language_name,
+                    StringUse::Description,
+                );
+            }
+        }
+
+        let string_packages: Vec<Vec<u8>> = string_packages
+            .iter()
+            .zip(headers)
+            .map(|(string_package, header)| {
+                let kept = used_strings
+                    .iter()
+                    .filter_map(|(&id, &string_use)| {
+                        let string = string_package.get(&(id as i32))?;
+                        let string = match string_use {
+                            StringUse::Description if anonymize => format!("String {}", id),
+                            _ => string.to_owned(),
+                        };
+                        Some((id as i32, string))
+                    })
+                    .collect();
+                strings::write_string_package(header, &kept)
+            })
+            .collect();
+
+        res.extend(package::write_package_list(
+            &guid.parse::<Guid>()?,
+            &string_packages,
+            &form_packages,
+        ));
+    }
+
+    if res.is_empty() {
+        return Err(anyhow!("none of the questions are in HiiDB"));
+    }
+    Ok(res)
+}
+
+/// minimize_form_package returns the IFR of every FormSet in the form package which has one of
+/// the matching questions, with only those questions. Each FormSet is written as its own form package.
+fn minimize_form_package(
+    root: &IFRNodeLink,
+    string_packages: &[HashMap<i32, String>],
+    normalized_phrases: &HashSet<String>,
+    used_strings: &mut BTreeMap<u16, StringUse>,
+) -> Vec<Vec<u8>> {
+    // the root is a placeholder and the FormSets are its children
+    root.borrow()
+        .children
+        .iter()
+        .filter_map(|formset| {
+            minimize_formset(formset, string_packages, normalized_phrases, used_strings)
+        })
+        .collect()
+}
+
+/// minimize_formset returns the IFR of the FormSet with only the matching questions
+/// or None if none of them are in it
+fn minimize_formset(
+    formset: &IFRNodeLink,
+    string_packages: &[HashMap<i32, String>],
+    normalized_phrases: &HashSet<String>,
+    used_strings: &mut BTreeMap<u16, StringUse>,
+) -> Option<Vec<u8>> {
+    let formset = formset.borrow();
+    let ParsedOperation::FormSet(parsed) = &formset.parsed_data else {
+        return None;
+    };
+
+    let mut strings = BTreeMap::new();
+    let mut forms = Vec::new();
+    let mut stores = Vec::new();
+    for child in &formset.children {
+        let child_ref = child.borrow();
+        match &child_ref.parsed_data {
+            ParsedOperation::VarStore(_) | ParsedOperation::VarStoreEfi(_) => {
+                write_scope(child, &mut stores, &mut strings);
+            }
+            ParsedOperation::DefaultStore(default_store) => {
+                use_string(
+                    &mut strings,
+                    default_store.name_string_id,
+                    StringUse::Description,
+                );
+                write_scope(child, &mut stores, &mut strings);
+            }
+            ParsedOperation::Form(form) => {
+                let mut questions = Vec::new();
+                find_questions(
+                    child,
+                    string_packages,
+                    normalized_phrases,
+                    &mut questions,
+                    &mut strings,
+                );
+                if !questions.is_empty() {
+                    use_string(&mut strings, form.title_string_id, StringUse::Description);
+                    forms.extend(child_ref.to_bytes());
+                    forms.extend(questions);
+                    forms.extend(END_OPERATION);
+                }
+            }
+            _ => {}
+        }
+    }
+    if forms.is_empty() {
+        return None;
+    }
+
+    use_string(&mut strings, parsed.title_string_id, StringUse::Description);
+    use_string(&mut strings, parsed.help_string_id, StringUse::Description);
+    for (id, string_use) in strings {
+        use_string(used_strings, id, string_use);
+    }
+
+    let mut res = formset.to_bytes();
+    res.extend(stores);
+    res.extend(forms);
+    res.extend(END_OPERATION);
+    Some(res)
+}
+
+/// find_questions writes every matching question below node (with its scope) to out
+fn find_questions(
+    node: &IFRNodeLink,
+    string_packages: &[HashMap<i32, String>],
+    normalized_phrases: &HashSet<String>,
+    out: &mut Vec<u8>,
+    strings: &mut BTreeMap<u16, StringUse>,
+) {
+    for child in &node.borrow().children {
+        let prompt_string_id = match &child.borrow().parsed_data {
+            ParsedOperation::OneOf(q) => Some(q.question_header().prompt_string_id),
+            ParsedOperation::CheckBox(q) => Some(q.question_header().prompt_string_id),
+            ParsedOperation::Numeric(q) => Some(q.question_header().prompt_string_id),
+            _ => None,
+        };
+        match prompt_string_id {
+            Some(id)
+                if normalized_phrases.contains(&normalize(find_string(id, string_packages))) =>
+            {
+                write_scope(child, out, strings);
+            }
+            Some(_) => {}
+            None => find_questions(child, string_packages, normalized_phrases, out, strings),
+        }
+    }
+}
+
+/// write_scope writes node, everything in its scope and the End of its scope to out
+fn write_scope(node: &IFRNodeLink, out: &mut Vec<u8>, strings: &mut BTreeMap<u16, StringUse>) {
+    let node = node.borrow();
+    match &node.parsed_data {
+        ParsedOperation::OneOf(q) => use_question_strings(strings, q),
+        ParsedOperation::CheckBox(q) => use_question_strings(strings, q),
+        ParsedOperation::Numeric(q) => use_question_strings(strings, q),
+        ParsedOperation::OneOfOption(option) => {
+            use_string(strings, option.option_string_id, StringUse::Label)
+        }
+        ParsedOperation::Text(text) => {
+            use_string(strings, text.prompt_string_id, StringUse::Description);
+            use_string(strings, text.help_string_id, StringUse::Description);
+            use_string(strings, text.text_id, StringUse::Description);
+        }
+        ParsedOperation::Subtitle(subtitle) => {
+            use_string(strings, subtitle.prompt_string_id, StringUse::Description);
+            use_string(strings, subtitle.help_string_id, StringUse::Description);
+        }
+        _ => {}
+    }
+
+    out.extend(node.to_bytes());
+    for child in &node.children {
+        write_scope(child, out, strings);
+    }
+    if node.open_scope {
+        out.extend(END_OPERATION);
+    }
+}
+
+fn use_question_strings(strings: &mut BTreeMap<u16, StringUse>, question: &impl Question) {
+    let header = question.question_header();
+    use_string(strings, header.prompt_string_id, StringUse::Label);
+    use_string(strings, header.help_string_id, StringUse::Description);
+}
+
+/// use_string marks a string as used, a string which is used as a label is never replaced
+fn use_string(strings: &mut BTreeMap<u16, StringUse>, id: u16, string_use: StringUse) {
+    if id == 0 {
+        return;
+    }
+    let current = strings.entry(id).or_insert(string_use);
+    *current = (*current).max(string_use);
+}
+
+fn find_string(id: u16, string_packages: &[HashMap<i32, String>]) -> &str {
+    string_packages
+        .iter()
+        .find_map(|package| package.get(&(id as i32)))
+        .map(String::as_str)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hii::forms;
+    use crate::hii::test_db;
+
+    /// test_db has "Hyper-Threading" and "SR-IOV" in the first form and "Turbo Mode" in the second one
+    fn test_db() -> Vec<u8> {
+        let guid = [0x11u8; 16];
+        test_db::package_list(
+            "11111111-1111-1111-1111-111111111111",
+            &[
+                "Vendor Formset",
+                "Vendor Help",
+                "Vendor Form",
+                "Disabled",
+                "Enabled",
+                "Hyper-Threading",
+                "Hyper-Threading help",
+                "SR-IOV",
+                "Turbo Mode",
+            ],
+            &[test_db::form_set(
+                guid,
+                1,
+                2,
+                &[
+                    test_db::var_store(guid, 1, 8, "Setup"),
+                    test_db::form(
+                        1,
+                        3,
+                        &[
+                            test_db::one_of(6, 7, 1, 1, 0, &[4, 5]),
+                            test_db::one_of(8, 2, 2, 1, 1, &[4, 5]),
+                        ],
+                    ),
+                    test_db::form(2, 3, &[test_db::one_of(9, 2, 3, 1, 2, &[4, 5])]),
+                ],
+            )],
+        )
+    }
+
+    fn questions(db: &[u8]) -> Vec<(String, String, Vec<String>)> {
+        let parsed_db = package::read_db(db).unwrap();
+        let guid = "11111111-1111-1111-1111-111111111111";
+        let mut res = Vec::new();
+        for root in &parsed_db.forms[guid] {
+            for q in forms::list_questions(Rc::clone(root), &parsed_db.strings[guid]) {
+                res.push((
+                    q.question,
+                    q.help,
+                    q.possible_options.into_iter().map(|o| o.value).collect(),
+                ));
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_minimize() {
+        let db = test_db();
+        assert_eq!(questions(&db).len(), 3);
+
+        let minimized = minimize(&db, &HashSet::from(["Hyper Threading"]), false).unwrap();
+        assert!(minimized.len() < db.len());
+        assert_eq!(
+            questions(&minimized),
+            vec![(
+                "Hyper-Threading".to_owned(),
+                "Hyper-Threading help".to_owned(),
+                vec!["Disabled".to_owned(), "Enabled".to_owned()],
+            )]
+        );
+        let parsed_db = package::read_db(&minimized).unwrap();
+        let strings = &parsed_db.strings["11111111-1111-1111-1111-111111111111"][0];
+        assert_eq!(strings.get(&1).map(String::as_str), Some("Vendor Formset"));
+        assert_eq!(strings.get(&8), None);
+        assert_eq!(strings.get(&9), None);
+
+        let anonymized = minimize(&db, &HashSet::from(["Turbo Mode"]), true).unwrap();
+        assert_eq!(
+            questions(&anonymized),
+            vec![(
+                "Turbo Mode".to_owned(),
+                "String 2".to_owned(),
+                vec!["Disabled".to_owned(), "Enabled".to_owned()],
+            )]
+        );
+
+        assert!(minimize(&db, &HashSet::from(["Missing"]), false).is_err());
+    }
+
+    #[test]
+    fn test_minimize_keeps_languages() {
+        let guid = [0x11u8; 16];
+        let form_set = test_db::form_set(
+            guid,
+            1,
+            1,
+            &[
+                test_db::var_store(guid, 1, 8, "Setup"),
+                test_db::form(1, 1, &[test_db::one_of(2, 1, 1, 1, 0, &[3, 4])]),
+            ],
+        );
+        let string_package = |language, strings: [&str; 6]| {
+            let strings = (1..).zip(strings).map(|(id, s)| (id, s.to_owned()));
+            strings::write_string_package(&test_db::string_header(language, 6), &strings.collect())
+        };
+        let db = package::write_package_list(
+            &"11111111-1111-1111-1111-111111111111".parse().unwrap(),
+            &[
+                string_package(
+                    "en-US",
+                    [
+                        "Setup",
+                        "Turbo Mode",
+                        "Disabled",
+                        "Enabled",
+                        "Unused",
+                        "English",
+                    ],
+                ),
+                string_package(
+                    "fr-FR",
+                    [
+                        "Config",
+                        "Mode Turbo",
+                        "Désactivé",
+                        "Activé",
+                        "Inutilisé",
+                        "Français",
+                    ],
+                ),
+            ],
+            &[form_set],
+        );
+
+        let minimized = minimize(&db, &HashSet::from(["Turbo Mode"]), false).unwrap();
+        let headers = &package::read_string_headers(&minimized).unwrap()
+            ["11111111-1111-1111-1111-111111111111"];
+        assert_eq!(
+            headers,
+            &package::read_string_headers(&db).unwrap()["11111111-1111-1111-1111-111111111111"]
+        );
+        let parsed_db = package::read_db(&minimized).unwrap();
+        let strings = &parsed_db.strings["11111111-1111-1111-1111-111111111111"];
+        assert_eq!(strings[1].get(&2).map(String::as_str), Some("Mode Turbo"));
+        assert_eq!(strings[1].get(&5), None);
+        assert_eq!(strings[1].get(&6).map(String::as_str), Some("Français"));
+    }
+}
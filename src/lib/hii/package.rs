@@ -16,7 +16,9 @@ use std::fmt;
 use std::fs;
 use std::io::Seek;
 use std::rc::Rc;
+use std::str::FromStr;
 
+use anyhow::anyhow;
 use anyhow::Context;
 use anyhow::Result;
 use binrw::io::Cursor;
@@ -150,6 +152,42 @@ pub struct Guid {
     pub data4: [u8; 8],
 }
 
+impl Guid {
+    /// to_bytes encodes the guid the way it's stored in HiiDB
+    pub fn to_bytes(self) -> [u8; 16] {
+        let mut res = [0u8; 16];
+        res[0..4].copy_from_slice(&self.data1.to_le_bytes());
+        res[4..6].copy_from_slice(&self.data2.to_le_bytes());
+        res[6..8].copy_from_slice(&self.data3.to_le_bytes());
+        res[8..16].copy_from_slice(&self.data4);
+        res
+    }
+}
+
+impl FromStr for Guid {
+    type Err = anyhow::Error;
+
+    /// parses guids the way they're displayed, ex: 1B838190-4625-4EAD-ABC9-CD5E6AF18FE0
+    fn from_str(s: &str) -> Result<Self> {
+        let hex: String = s.chars().filter(|c| *c != '-').collect();
+        if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(anyhow!("{} isn't a GUID", s));
+        }
+        let byte = |i: usize| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16);
+
+        let mut data4 = [0u8; 8];
+        for (i, b) in data4.iter_mut().enumerate() {
+            *b = byte(8 + i)?;
+        }
+        Ok(Guid {
+            data1: u32::from_str_radix(&hex[0..8], 16)?,
+            data2: u16::from_str_radix(&hex[8..12], 16)?,
+            data3: u16::from_str_radix(&hex[12..16], 16)?,
+            data4,
+        })
+    }
+}
+
 // lifted from https://github.com/LongSoft/IFRExtractor-RS/blob/ae9b550a6fe530f3a4911373ce22646043322bbc/src/parser.rs#L34
 impl fmt::Display for Guid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -245,6 +283,51 @@ pub fn read_db(source: &[u8]) -> Result<ParsedHiiDB> {
     Ok(res)
 }
 
+/// read_string_headers returns the headers of the string packages of every package list
+/// (key = package list guid string), in the same order as ParsedHiiDB::strings
+pub fn read_string_headers(source: &[u8]) -> Result<HashMap<String, Vec<strings::StringHeader>>> {
+    let mut res: HashMap<String, Vec<strings::StringHeader>> = HashMap::new();
+    for package_list in get_package_lists(source)? {
+        for package in get_packages(&package_list)? {
+            if package.package_type == PackageType::Strings {
+                res.entry(package_list.guid.to_string())
+                    .or_default()
+                    .push(strings::read_string_header(&package.data)?);
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// write_package_list encodes a package list which read_db can read.
+/// string_packages and form_packages are the contents of each package without the package header,
+/// see strings::write_string_package and forms::IFROperation::to_bytes.
+pub fn write_package_list(
+    guid: &Guid,
+    string_packages: &[Vec<u8>],
+    form_packages: &[Vec<u8>],
+) -> Vec<u8> {
+    let mut packages = Vec::new();
+    let mut write_package = |package_type: u8, data: &[u8]| {
+        // 24 bits of length (including this header) and 8 bits of type
+        let header = (data.len() as u32 + 4) | (package_type as u32) << 24;
+        packages.extend(header.to_le_bytes());
+        packages.extend(data);
+    };
+    for data in string_packages {
+        write_package(0x04, data);
+    }
+    for data in form_packages {
+        write_package(0x02, data);
+    }
+    write_package(0xDF, &[]);
+
+    let mut res = guid.to_bytes().to_vec();
+    res.extend((packages.len() as u32 + 16 + 4).to_le_bytes());
+    res.extend(packages);
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::File;
@@ -0,0 +1,183 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Search through question prompts, help strings and option labels.
+
+use anyhow::Result;
+use regex::Regex;
+use regex::RegexBuilder;
+
+use crate::hii::forms::QuestionDescriptor;
+
+/// Matcher decides whether a piece of text matches the user's search pattern.
+/// Matching is always case-insensitive.
+pub enum Matcher {
+    Substring(String),
+    Regex(Regex),
+}
+
+/// How well a piece of text matched, higher is better.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchQuality {
+    Partial = 1,
+    Prefix = 2,
+    Exact = 3,
+}
+
+/// Where the match was found, higher is more relevant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchedField {
+    Help = 1,
+    Option = 2,
+    Prompt = 3,
+}
+
+impl MatchedField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchedField::Help => "help",
+            MatchedField::Option => "option",
+            MatchedField::Prompt => "prompt",
+        }
+    }
+}
+
+pub struct SearchHit {
+    pub question: QuestionDescriptor,
+    pub score: u32,
+    /// fields which matched, most relevant first
+    pub matched_fields: Vec<MatchedField>,
+}
+
+impl Matcher {
+    pub fn new(pattern: &str, is_regex: bool) -> Result<Self> {
+        if is_regex {
+            Ok(Matcher::Regex(
+                RegexBuilder::new(pattern).case_insensitive(true).build()?,
+            ))
+        } else {
+            Ok(Matcher::Substring(pattern.trim().to_lowercase()))
+        }
+    }
+
+    fn quality(&self, text: &str) -> Option<MatchQuality> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        let (start, end, len) = match self {
+            Matcher::Substring(pattern) => {
+                let text = text.to_lowercase();
+                let start = text.find(pattern.as_str())?;
+                (start, start + pattern.len(), text.len())
+            }
+            Matcher::Regex(re) => {
+                let m = re.find(text)?;
+                (m.start(), m.end(), text.len())
+            }
+        };
+
+        if start == 0 && end == len {
+            Some(MatchQuality::Exact)
+        } else if start == 0 {
+            Some(MatchQuality::Prefix)
+        } else {
+            Some(MatchQuality::Partial)
+        }
+    }
+}
+
+/// search returns the questions which match in their prompt, help string or option labels
+/// sorted by relevance. A match in the prompt ranks above a match in an option label which
+/// ranks above a match in the help text. Within the same field an exact match ranks
+/// above a prefix match which ranks above a match somewhere in the middle.
+pub fn search(questions: Vec<QuestionDescriptor>, matcher: &Matcher) -> Vec<SearchHit> {
+    let mut hits = Vec::new();
+
+    for question in questions {
+        let mut matches: Vec<(MatchedField, MatchQuality)> = Vec::new();
+
+        if let Some(quality) = matcher.quality(&question.question) {
+            matches.push((MatchedField::Prompt, quality));
+        }
+        if let Some(quality) = question
+            .possible_options
+            .iter()
+            .filter_map(|opt| matcher.quality(&opt.value))
+            .max()
+        {
+            matches.push((MatchedField::Option, quality));
+        }
+        if let Some(quality) = matcher.quality(&question.help) {
+            matches.push((MatchedField::Help, quality));
+        }
+
+        if matches.is_empty() {
+            continue;
+        }
+
+        // The best field decides the rank, other matching fields only break ties.
+        let score = matches
+            .iter()
+            .map(|(field, quality)| (*field as u32) * 10 + (*quality as u32))
+            .max()
+            .unwrap_or_default()
+            * 10
+            + matches.len() as u32;
+
+        hits.push(SearchHit {
+            question,
+            score,
+            matched_fields: matches.into_iter().map(|(field, _)| field).collect(),
+        });
+    }
+
+    // sort_by_key is stable so questions with the same score stay in the order they appear in the forms
+    hits.sort_by_key(|hit| std::cmp::Reverse(hit.score));
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substring_quality() {
+        let matcher = Matcher::new("sr-iov", false).unwrap();
+        assert_eq!(matcher.quality("SR-IOV"), Some(MatchQuality::Exact));
+        assert_eq!(
+            matcher.quality("SR-IOV Support"),
+            Some(MatchQuality::Prefix)
+        );
+        assert_eq!(matcher.quality("PCIe SR-IOV"), Some(MatchQuality::Partial));
+        assert_eq!(matcher.quality("VT-d"), None);
+        assert_eq!(matcher.quality(""), None);
+    }
+
+    #[test]
+    fn test_regex_quality() {
+        let matcher = Matcher::new("c[- ]?states?", true).unwrap();
+        assert_eq!(matcher.quality("C-States"), Some(MatchQuality::Exact));
+        assert_eq!(
+            matcher.quality("C State Control"),
+            Some(MatchQuality::Prefix)
+        );
+        assert_eq!(
+            matcher.quality("Package C State"),
+            Some(MatchQuality::Partial)
+        );
+        assert_eq!(matcher.quality("P-States"), None);
+
+        assert!(Matcher::new("(unclosed", true).is_err());
+    }
+}
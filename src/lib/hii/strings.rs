@@ -167,3 +167,63 @@ pub fn handle_string_package(
 
     Ok(string_map)
 }
+
+/// StringHeader is the header of a string package (language and all) as it was read,
+/// so that the package can be written again with other strings
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringHeader {
+    /// everything in front of the string blocks, starting with hdr_size
+    bytes: Vec<u8>,
+    /// string id of the name of the language (ex: "English"), 0 if there is none
+    pub language_name: u16,
+}
+
+/// read_string_header returns the header of a string package (without the package header)
+pub fn read_string_header(package_data: &[u8]) -> Result<StringHeader> {
+    let header: StringPackageHeader = Cursor::new(package_data)
+        .read_ne()
+        .context("failed to parse string package header")?;
+    // string_info_offset counts from the start of the package header (4 bytes)
+    let bytes = (header.string_info_offset as usize)
+        .checked_sub(4)
+        .and_then(|blocks_start| package_data.get(..blocks_start))
+        .ok_or_else(|| {
+            anyhow!(
+                "string info offset {} is outside of the string package",
+                header.string_info_offset
+            )
+        })?;
+    Ok(StringHeader {
+        bytes: bytes.to_vec(),
+        language_name: header.language_name,
+    })
+}
+
+/// write_string_package encodes strings (string id -> string) as the contents of a string package
+/// with header which handle_string_package can read. Gaps between string ids are skipped so the ids don't change.
+pub fn write_string_package(header: &StringHeader, strings: &HashMap<i32, String>) -> Vec<u8> {
+    let mut res = header.bytes.clone();
+
+    let mut ids: Vec<&i32> = strings.keys().filter(|id| **id > 0).collect();
+    ids.sort();
+
+    let mut string_id_current: i32 = 1;
+    for id in ids {
+        let mut skip_count = id - string_id_current;
+        while skip_count > 0 {
+            let skipped = skip_count.min(u16::MAX as i32);
+            res.push(0x21); // Skip2
+            res.extend((skipped as u16).to_le_bytes());
+            skip_count -= skipped;
+        }
+
+        res.push(0x14); // StringUcs2
+        for c in strings[id].encode_utf16().chain([0]) {
+            res.extend(c.to_le_bytes());
+        }
+        string_id_current = id + 1;
+    }
+    res.push(0x00); // End
+
+    res
+}
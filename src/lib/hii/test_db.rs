@@ -0,0 +1,153 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Builds small HiiDBs for tests out of IFR operations and strings, so that tests don't need a
+// (proprietary) dump of a real firmware.
+
+use std::collections::HashMap;
+
+use crate::hii::package;
+use crate::hii::strings;
+
+/// IFR End operation which closes a scope
+pub(crate) const END: [u8; 2] = [0x29, 0x02];
+
+/// ifr encodes an IFR operation with its data
+pub(crate) fn ifr(op_code: u8, open_scope: bool, data: &[u8]) -> Vec<u8> {
+    let length = (data.len() + 2) as u8 | if open_scope { 0x80 } else { 0 };
+    [&[op_code, length], data].concat()
+}
+
+/// form_set is a FormSet with the given guid around children (like var_store and form)
+pub(crate) fn form_set(guid: [u8; 16], title: u16, help: u16, children: &[Vec<u8>]) -> Vec<u8> {
+    let data = [
+        &guid[..],
+        &title.to_le_bytes(),
+        &help.to_le_bytes(),
+        &[0],
+        &guid[..],
+    ]
+    .concat();
+    [ifr(0x0E, true, &data), children.concat(), END.to_vec()].concat()
+}
+
+/// var_store is a buffer varstore called name, stored in the efivar name-guid
+pub(crate) fn var_store(guid: [u8; 16], id: u16, size: u16, name: &str) -> Vec<u8> {
+    let data = [
+        &guid[..],
+        &id.to_le_bytes(),
+        &size.to_le_bytes(),
+        name.as_bytes(),
+        &[0],
+    ]
+    .concat();
+    ifr(0x24, false, &data)
+}
+
+/// form is a Form around questions (like one_of)
+pub(crate) fn form(id: u16, title: u16, questions: &[Vec<u8>]) -> Vec<u8> {
+    let data = [id.to_le_bytes(), title.to_le_bytes()].concat();
+    [ifr(0x01, true, &data), questions.concat(), END.to_vec()].concat()
+}
+
+/// one_of is a OneOf question stored in one byte at offset of a varstore. The value of an option
+/// is its position in options, which are string ids.
+pub(crate) fn one_of(
+    prompt: u16,
+    help: u16,
+    question_id: u16,
+    var_store_id: u16,
+    offset: u16,
+    options: &[u16],
+) -> Vec<u8> {
+    let data: Vec<u8> = [prompt, help, question_id, var_store_id, offset]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        // question flags, flags and a Range8 of 0..len(options) - 1 with a step of 1
+        .chain([0, 0, 0, options.len().saturating_sub(1) as u8, 1])
+        .collect();
+    let options = options.iter().enumerate().map(|(value, option)| {
+        // string id, flags, type (u8) and value
+        let data = [&option.to_le_bytes()[..], &[0, 0, value as u8]].concat();
+        ifr(0x09, false, &data)
+    });
+    [ifr(0x05, true, &data)]
+        .into_iter()
+        .chain(options)
+        .chain([END.to_vec()])
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+/// numeric is a Numeric question stored in one byte at offset of a varstore, from 0 to max
+pub(crate) fn numeric(
+    prompt: u16,
+    help: u16,
+    question_id: u16,
+    var_store_id: u16,
+    offset: u16,
+    max: u8,
+) -> Vec<u8> {
+    let data: Vec<u8> = [prompt, help, question_id, var_store_id, offset]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        // question flags, flags (u8) and a Range8 of 0..max with a step of 1
+        .chain([0, 0, 0, max, 1])
+        .collect();
+    [ifr(0x07, true, &data), END.to_vec()].concat()
+}
+
+/// default is an IFRDefault with a value of one byte for the default store default_id
+pub(crate) fn default(default_id: u16, value: u8) -> Vec<u8> {
+    let data = [&default_id.to_le_bytes()[..], &[0, value]].concat();
+    ifr(0x5B, false, &data)
+}
+
+/// with_children adds children at the end of the scope of an operation (like a question)
+pub(crate) fn with_children(scope: Vec<u8>, children: &[Vec<u8>]) -> Vec<u8> {
+    let (operation, end) = scope.split_at(scope.len() - END.len());
+    [operation, &children.concat(), end].concat()
+}
+
+/// string_header is the header of a string package in language whose name is the string language_name
+pub(crate) fn string_header(language: &str, language_name: u16) -> strings::StringHeader {
+    let language = [language.as_bytes(), &[0]].concat();
+    // package header (4 bytes) + hdr_size + string_info_offset + language_window + language_name + language
+    let hdr_size = (4 + 4 + 4 + 32 + 2 + language.len()) as u32;
+    let data = [
+        &hdr_size.to_le_bytes()[..],
+        // the string blocks start right after the header
+        &hdr_size.to_le_bytes(),
+        &[0; 32],
+        &language_name.to_le_bytes(),
+        &language,
+    ]
+    .concat();
+    strings::read_string_header(&data).unwrap()
+}
+
+/// package_list is a package list with one en-US string package (strings get the ids 1, 2, ...)
+/// and a form package for each of form_sets, like firmware does
+pub(crate) fn package_list(guid: &str, strings: &[&str], form_sets: &[Vec<u8>]) -> Vec<u8> {
+    let strings: HashMap<i32, String> = (1..)
+        .zip(strings)
+        .map(|(id, s)| (id, s.to_string()))
+        .collect();
+    package::write_package_list(
+        &guid.parse().unwrap(),
+        &[strings::write_string_package(
+            &string_header("en-US", 0),
+            &strings,
+        )],
+        form_sets,
+    )
+}
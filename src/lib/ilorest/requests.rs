@@ -10,7 +10,9 @@
 //
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -20,6 +22,9 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::value::Value;
 
+use crate::bundle;
+use crate::dry_run;
+use crate::dry_run::PlannedWrite;
 use crate::ilorest::chif::find_lib_location;
 use crate::ilorest::rest::RestClient;
 
@@ -39,6 +44,10 @@ const ILO4_IGNORED_KEYS: [&str; 8] = [
 // The returned message should contain this if updating bios settings worked
 const SUCCESS_MSG: &str = "SystemResetRequired";
 
+// Bodies of the GET responses sent while capturing a bundle, by endpoint
+static RECORDED_RESPONSES: Mutex<Option<BTreeMap<String, String>>> = Mutex::new(None);
+static CACHED_RESPONSES: Mutex<Option<BTreeMap<String, Vec<u8>>>> = Mutex::new(None);
+
 // IloDevice isn't exactly an enum for ilo version or an HPE server version or even redfish version
 // its a combination of those
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -52,9 +61,7 @@ pub enum IloDevice {
 /// However, this is just the best guess. Even if we can't correctly differentiate between
 /// ilo5 with Gen10 vs ilo5 with Gen10+ or some other variant, we can still get/set bios settings.
 pub fn identify_hpe_machine_type() -> Result<IloDevice> {
-    let client = RestClient::new(&find_lib_location()?);
-
-    let (status, body) = client.get("/redfish/v1/")?;
+    let (status, body) = rest_get("/redfish/v1/")?;
     if status != HTTPStatusCode::Ok as u16 {
         return Err(anyhow!(
             "Unexpected HTTP Status Code while fetching pending bios settings"
@@ -210,8 +217,6 @@ pub struct Ilo4Dev;
 
 impl IloDev for Ilo4Dev {
     fn update_bios_setting(&self, attribute: &str, new_value: &str) -> Result<()> {
-        let client = RestClient::new(&find_lib_location()?);
-
         let update_struct =
             HashMap::from([(attribute.to_string(), Value::String(new_value.to_string()))]);
 
@@ -219,6 +224,14 @@ impl IloDev for Ilo4Dev {
             .context("failed while serializing Hashmap to json")?;
         debug!("Serialized Hashmap to patch ilo4 is {} ", serialized);
 
+        if dry_run::plan(PlannedWrite::Patch {
+            endpoint: "/redfish/v1/systems/1/bios/settings/".to_owned(),
+            body: serialized.clone(),
+        }) {
+            return Ok(());
+        }
+        let client = rest_client()?;
+
         // NOTE: trailing slashes are necessary in ilo4 because otherwise it returns HTTP 308 Moved Permanently
         let (status, body) = client.patch("/redfish/v1/systems/1/bios/settings/", &serialized)?;
         if status != HTTPStatusCode::Ok as u16 {
@@ -245,9 +258,7 @@ impl IloDev for Ilo4Dev {
     }
 
     fn get_pending_bios_settings(&self) -> Result<RedfishAttributes> {
-        let client = RestClient::new(&find_lib_location()?);
-
-        let (status, body) = client.get("/redfish/v1/systems/1/bios/settings/")?;
+        let (status, body) = rest_get("/redfish/v1/systems/1/bios/settings/")?;
         if status != HTTPStatusCode::Ok as u16 {
             return Err(anyhow!(
                 "Unexpected HTTP Status Code while fetching pending bios settings"
@@ -266,9 +277,7 @@ impl IloDev for Ilo4Dev {
     }
 
     fn get_current_bios_settings(&self) -> Result<RedfishAttributes> {
-        let client = RestClient::new(&find_lib_location()?);
-
-        let (status, body) = client.get("/redfish/v1/systems/1/bios/")?;
+        let (status, body) = rest_get("/redfish/v1/systems/1/bios/")?;
         if status != HTTPStatusCode::Ok as u16 {
             return Err(anyhow!(
                 "Unexpected HTTP Status Code while fetching current bios settings"
@@ -298,9 +307,7 @@ mod redfish {
     use super::*;
 
     pub fn get_attributes(endpoint: &str) -> Result<RedfishAttributes> {
-        let client = RestClient::new(&find_lib_location()?);
-
-        let (status, body) = client.get(endpoint)?;
+        let (status, body) = rest_get(endpoint)?;
         if status != HTTPStatusCode::Ok as u16 {
             return Err(anyhow!(
                 "Unexpected HTTP Status Code while fetching {endpoint}"
@@ -315,8 +322,6 @@ mod redfish {
     }
 
     pub fn update_attribute(endpoint: &str, attribute: &str, new_value: &str) -> Result<()> {
-        let client = RestClient::new(&find_lib_location()?);
-
         let update_struct = RedfishUpdateAttribute {
             attributes: HashMap::from([(
                 attribute.to_string(),
@@ -327,6 +332,14 @@ mod redfish {
             .context("failed while serializing RedfishUpdateAttribute to json")?;
         debug!("Serialized RedfishUpdateAttribute is {} ", serialized);
 
+        if dry_run::plan(PlannedWrite::Patch {
+            endpoint: endpoint.to_owned(),
+            body: serialized.clone(),
+        }) {
+            return Ok(());
+        }
+        let client = rest_client()?;
+
         let (status, body) = client.patch(endpoint, &serialized)?;
         if status != HTTPStatusCode::Ok as u16 {
             return Err(anyhow!(
@@ -352,6 +365,103 @@ mod redfish {
     }
 }
 
+/// record_responses runs f and returns the body of every successful GET it sent to the iLO by endpoint
+pub fn record_responses(f: impl FnOnce() -> Result<()>) -> Result<BTreeMap<String, String>> {
+    *RECORDED_RESPONSES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(BTreeMap::new());
+    let res = f();
+    let recorded = RECORDED_RESPONSES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .take()
+        .unwrap_or_default();
+    res.map(|_| recorded)
+}
+
+/// ResponseCache makes every GET to an endpoint return the first successful response to it until
+/// it's dropped. Responses are forgotten whenever a setting is changed.
+pub struct ResponseCache;
+
+impl ResponseCache {
+    pub fn start() -> Self {
+        *CACHED_RESPONSES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(BTreeMap::new());
+        ResponseCache
+    }
+}
+
+impl Drop for ResponseCache {
+    fn drop(&mut self) {
+        *CACHED_RESPONSES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+    }
+}
+
+/// rest_get sends a GET to the iLO unless the response is cached by a ResponseCache.
+/// While a bundle is replayed the response captured in the bundle is returned instead.
+fn rest_get(endpoint: &str) -> Result<(u16, Vec<u8>)> {
+    if let Some(bundle) = bundle::replayed_bundle() {
+        return match bundle.ilo_response(endpoint) {
+            Some(body) => Ok((HTTPStatusCode::Ok as u16, body.as_bytes().to_vec())),
+            None => Err(anyhow!(
+                "{} has no response for {}",
+                bundle.source().display(),
+                endpoint
+            )),
+        };
+    }
+
+    if let Some(body) = CACHED_RESPONSES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .and_then(|cached| cached.get(endpoint))
+    {
+        return Ok((HTTPStatusCode::Ok as u16, body.clone()));
+    }
+
+    let (status, body) = RestClient::new(&find_lib_location()?).get(endpoint)?;
+    if status == HTTPStatusCode::Ok as u16 {
+        if let Some(cached) = CACHED_RESPONSES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_mut()
+        {
+            cached.insert(endpoint.to_owned(), body.clone());
+        }
+        if let Some(recorded) = RECORDED_RESPONSES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_mut()
+        {
+            recorded.insert(endpoint.to_owned(), remove_null_bytes(&body));
+        }
+    }
+    Ok((status, body))
+}
+
+/// rest_client returns a client for changing settings, which isn't allowed while a bundle is replayed
+fn rest_client() -> Result<RestClient> {
+    if let Some(bundle) = bundle::replayed_bundle() {
+        return Err(anyhow!(
+            "refusing to change iLO settings replayed from {}",
+            bundle.source().display()
+        ));
+    }
+    // the client is only used to change settings, so cached responses might not be current anymore
+    if let Some(cached) = CACHED_RESPONSES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_mut()
+    {
+        cached.clear();
+    }
+    Ok(RestClient::new(&find_lib_location()?))
+}
+
 fn remove_null_bytes(body: &[u8]) -> String {
     // serde_json::from_str and serde_json::from_slice both fail if they see null-terminators/null-bytes.
     // CStr::from_bytes_with_nul fails if there are interior null bytes before the final one.
@@ -0,0 +1,212 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// The journal records every question changed by set, apply or restore (and every change reverted
+// by undo) so that changes can be undone later. It's a file of JSON lines, one JournalEntry per
+// line, which is only ever appended to.
+
+use std::collections::HashSet;
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use anyhow::Result;
+use log::error;
+use nix::fcntl::flock;
+use nix::fcntl::FlockArg;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::profile::ProfileBackend;
+use crate::system_root::system_root;
+
+pub const JOURNAL_PATH: &str = "/var/lib/uefisettings/journal";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// set when the entry is appended
+    pub id: u64,
+    /// seconds since the unix epoch, set when the entry is appended
+    pub timestamp: u64,
+    pub backend: ProfileBackend,
+    /// hii: package list GUID, ilo: bios, debug or service
+    pub selector: String,
+    /// hii: prompt of the question, ilo: name of the attribute
+    pub question: String,
+    /// hii: varstore and offset the question is stored at
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub identity: String,
+    /// hii: number stored in the varstore before the change, ilo: value of the attribute
+    pub previous_value: String,
+    /// value stored by the change (like previous_value)
+    pub new_value: String,
+    /// id of the entry which was reverted by this change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverts: Option<u64>,
+}
+
+impl JournalEntry {
+    /// new describes a change, its id and timestamp are set when it's appended
+    pub fn new(
+        backend: ProfileBackend,
+        selector: &str,
+        question: &str,
+        previous_value: &str,
+        new_value: &str,
+    ) -> Self {
+        JournalEntry {
+            id: 0,
+            timestamp: 0,
+            backend,
+            selector: selector.to_owned(),
+            question: question.to_owned(),
+            identity: String::new(),
+            previous_value: previous_value.to_owned(),
+            new_value: new_value.to_owned(),
+            reverts: None,
+        }
+    }
+}
+
+pub fn journal_path() -> PathBuf {
+    system_root().join(JOURNAL_PATH)
+}
+
+/// read_journal returns every entry of the journal, oldest first
+pub fn read_journal() -> Result<Vec<JournalEntry>> {
+    let path = journal_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(&path).context(format!("reading {}", path.display()))?;
+    parse_journal(&contents, &path)
+}
+
+fn parse_journal(contents: &str, path: &Path) -> Result<Vec<JournalEntry>> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line).context(format!(
+                "parsing entry {} of {}",
+                i + 1,
+                path.display()
+            ))
+        })
+        .collect()
+}
+
+/// append adds entries to the journal, their ids and timestamps are set here.
+/// The journal is locked from reading the last id until the entries are written so that
+/// concurrent runs don't hand out the same ids.
+pub fn append(entries: Vec<JournalEntry>) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let path = journal_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context(format!("creating {}", dir.display()))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(&path)
+        .context(format!("opening {}", path.display()))?;
+    // released when file is closed
+    flock(file.as_raw_fd(), FlockArg::LockExclusive)
+        .context(format!("locking {}", path.display()))?;
+
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .context(format!("reading {}", path.display()))?;
+    let next_id = parse_journal(&contents, &path)?
+        .last()
+        .map_or(1, |entry| entry.id + 1);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("system time is before the unix epoch")?
+        .as_secs();
+
+    let mut lines = String::new();
+    for (id, mut entry) in (next_id..).zip(entries) {
+        entry.id = id;
+        entry.timestamp = timestamp;
+        lines.push_str(&serde_json::to_string(&entry)?);
+        lines.push('\n');
+    }
+
+    file.write_all(lines.as_bytes())
+        .context(format!("writing {}", path.display()))
+}
+
+/// record journals a change which was made. The change was made already, so failing to journal it
+/// is only logged.
+pub(crate) fn record(entry: JournalEntry) {
+    append_or_log(vec![entry]);
+}
+
+pub(crate) fn append_or_log(entries: Vec<JournalEntry>) {
+    if let Err(why) = append(entries) {
+        error!("failed to record changes in the journal: {:#}", why);
+    }
+}
+
+/// undoable returns the entries which can be undone, newest first. Entries which were already
+/// reverted and entries which revert others can't be undone.
+pub fn undoable(entries: &[JournalEntry]) -> Vec<&JournalEntry> {
+    let reverted: HashSet<u64> = entries.iter().filter_map(|entry| entry.reverts).collect();
+    entries
+        .iter()
+        .rev()
+        .filter(|entry| entry.reverts.is_none() && !reverted.contains(&entry.id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, reverts: Option<u64>) -> JournalEntry {
+        JournalEntry {
+            id,
+            reverts,
+            ..JournalEntry::new(
+                ProfileBackend::Ilo,
+                "bios",
+                "WorkloadProfile",
+                "Virtualization-MaxPerformance",
+                "Custom",
+            )
+        }
+    }
+
+    #[test]
+    fn test_undoable() {
+        let entries = vec![
+            entry(1, None),
+            entry(2, None),
+            entry(3, Some(2)),
+            entry(4, None),
+        ];
+        let ids: Vec<u64> = undoable(&entries).iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![4, 1]);
+    }
+}
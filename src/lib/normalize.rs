@@ -0,0 +1,147 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Vendors spell the same question in slightly different ways, like "Intel(R) VT-d" and "Intel® VT-d"
+// or "Hyper-Threading" and "Hyper-Threading [ALL]". Normalizing both sides before comparing means
+// the spellings database doesn't have to list every one of those variants.
+
+use std::collections::BTreeSet;
+
+// Trademark markers which are dropped before comparing. Compared against lowercased text.
+const TRADEMARKS: [&str; 4] = ["®", "™", "(r)", "(tm)"];
+
+// How many suggestions closest() returns at most
+const MAX_SUGGESTIONS: usize = 5;
+
+/// normalize returns the form of a question used for comparisons.
+/// It lowercases, drops trademark markers and trailing "[ALL]"-style suffixes,
+/// and removes whitespace and punctuation.
+/// Ex: "Intel(R) VT for Directed I/O (VT-d) [ALL]" -> "intelvtfordirectediovtd"
+pub fn normalize(s: &str) -> String {
+    let mut s = s.to_lowercase();
+    for trademark in TRADEMARKS {
+        s = s.replace(trademark, "");
+    }
+
+    let mut s = s.trim();
+    if s.ends_with(']') {
+        if let Some(start) = s.rfind('[') {
+            // don't strip questions like "[ALL]" which only consist of the suffix
+            if !s[..start].trim().is_empty() {
+                s = &s[..start];
+            }
+        }
+    }
+
+    s.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// equivalent returns true if both strings are the same after normalization
+pub fn equivalent(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+/// edit_distance is the Levenshtein distance between two strings counted in chars
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// closest returns up to MAX_SUGGESTIONS candidates which are closest to the query by edit distance
+/// (after normalization), closest first. Candidates which are too far away to be a typo or a
+/// different spelling of the query aren't returned.
+pub fn closest<I, T>(query: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<str>,
+{
+    let query = normalize(query);
+    let max_distance = (query.chars().count() / 2).max(2);
+
+    let mut scored = BTreeSet::new();
+    for candidate in candidates {
+        let candidate = candidate.as_ref().trim();
+        if candidate.is_empty() {
+            continue;
+        }
+        let distance = edit_distance(&query, &normalize(candidate));
+        if distance <= max_distance {
+            scored.insert((distance, candidate.to_owned()));
+        }
+    }
+
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("Intel® VT for Directed I/O (VT-d)"),
+            "intelvtfordirectediovtd"
+        );
+        assert_eq!(
+            normalize("Intel(R) VT for Directed I/O (VT-d)"),
+            "intelvtfordirectediovtd"
+        );
+        assert_eq!(normalize("Enable Intel(TM) TXT"), "enableinteltxt");
+        assert_eq!(normalize("  Hyper-Threading [ALL] "), "hyperthreading");
+        assert_eq!(normalize("Hyper Threading"), "hyperthreading");
+        assert_eq!(normalize("[ALL]"), "all");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("hyperthreading", "hyperthreding"), 1);
+    }
+
+    #[test]
+    fn test_closest() {
+        let candidates = [
+            "Hyper-Threading",
+            "Hyper-Threading [ALL]",
+            "TPM State",
+            "TXT Support",
+            "",
+        ];
+        assert_eq!(
+            closest("HyperThreding", candidates),
+            vec!["Hyper-Threading", "Hyper-Threading [ALL]"]
+        );
+        assert_eq!(closest("TPM Stat", candidates), vec!["TPM State"]);
+        assert!(closest("Boot Order", candidates).is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// Platform identity (DMI) and matching it against the platform constraints in the spellings database.
+
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use log::debug;
+use regex::RegexBuilder;
+
+use uefisettings_backend_thrift::MachineInfo;
+use uefisettings_spellings_db_thrift::PlatformConstraint;
+
+use crate::system_root::system_root;
+
+const DMI_DIR: &str = "/sys/class/dmi/id";
+
+/// read_dmi_info returns the hardware/bios-information of the machine (the backend field is left empty)
+pub fn read_dmi_info() -> MachineInfo {
+    // the entries in /sys/class/dmi/id/ are plaintext and populated by the kernel
+    let dmi_dir = system_root().join(DMI_DIR);
+    MachineInfo {
+        bios_vendor: read_file_contents(&dmi_dir.join("bios_vendor")),
+        bios_version: read_file_contents(&dmi_dir.join("bios_version")),
+        bios_release: read_file_contents(&dmi_dir.join("bios_release")),
+        bios_date: read_file_contents(&dmi_dir.join("bios_date")),
+        product_name: read_file_contents(&dmi_dir.join("product_name")),
+        product_family: read_file_contents(&dmi_dir.join("product_family")),
+        product_version: read_file_contents(&dmi_dir.join("product_version")),
+        ..Default::default()
+    }
+}
+
+/// read_file_contents is a wrapper over std::fs::read_to_string but it
+/// returns an empty string if file can't be read / doesn't exist
+fn read_file_contents(file_path: &Path) -> String {
+    match fs::read_to_string(file_path) {
+        Ok(contents) => contents.trim().to_owned(),
+        Err(why) => {
+            debug!("Can't read {:?} because {}", file_path, why);
+            "".to_owned()
+        }
+    }
+}
+
+/// platform_matches returns true if every constraint which is set matches the machine.
+/// It only fails if the product name regex is invalid.
+pub fn platform_matches(constraint: &PlatformConstraint, machine: &MachineInfo) -> Result<bool> {
+    if let Some(bios_vendor) = &constraint.bios_vendor {
+        if !bios_vendor
+            .trim()
+            .eq_ignore_ascii_case(&machine.bios_vendor)
+        {
+            return Ok(false);
+        }
+    }
+
+    if let Some(product_name_regex) = &constraint.product_name_regex {
+        let re = RegexBuilder::new(&format!("^(?:{})$", product_name_regex))
+            .case_insensitive(true)
+            .build()
+            .context(format!(
+                "Invalid product_name_regex '{}'",
+                product_name_regex
+            ))?;
+        if !re.is_match(&machine.product_name) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(min_bios_version) = &constraint.min_bios_version {
+        if compare_versions(&machine.bios_version, min_bios_version) == Ordering::Less {
+            return Ok(false);
+        }
+    }
+
+    if let Some(max_bios_version) = &constraint.max_bios_version {
+        if compare_versions(&machine.bios_version, max_bios_version) == Ordering::Greater {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// compare_versions compares BIOS versions part by part. Parts are runs of digits or runs of letters,
+/// everything else is a separator. Numeric parts are compared as numbers and the rest case-insensitively,
+/// so "1.10" > "1.9" and "F20" > "F9". If one version is a prefix of the other then the shorter one is smaller.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a = version_parts(a);
+    let b = version_parts(b);
+
+    for (x, y) in a.iter().zip(b.iter()) {
+        let ordering = match (x.parse::<u128>(), y.parse::<u128>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.to_lowercase().cmp(&y.to_lowercase()),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    a.len().cmp(&b.len())
+}
+
+fn version_parts(version: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut previous: Option<bool> = None; // is the previous char a digit
+
+    for c in version.chars() {
+        if !c.is_alphanumeric() {
+            previous = None;
+            continue;
+        }
+        let is_digit = c.is_ascii_digit();
+        match (previous, parts.last_mut()) {
+            (Some(was_digit), Some(part)) if was_digit == is_digit => part.push(c),
+            _ => parts.push(c.to_string()),
+        }
+        previous = Some(is_digit);
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("F20", "F9"), Ordering::Greater);
+        assert_eq!(compare_versions("1.2", "1.2.1"), Ordering::Less);
+        assert_eq!(compare_versions("v1.02", "V1.2"), Ordering::Equal);
+        assert_eq!(
+            compare_versions("SE5C620.86B.02.01.0012", "SE5C620.86B.02.01.0008"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_platform_matches() {
+        let machine = MachineInfo {
+            bios_vendor: "American Megatrends Inc.".to_owned(),
+            bios_version: "F20".to_owned(),
+            product_name: "Tioga Pass".to_owned(),
+            ..Default::default()
+        };
+
+        let constraint = PlatformConstraint {
+            bios_vendor: Some("american megatrends inc.".to_owned()),
+            product_name_regex: Some("tioga.*".to_owned()),
+            min_bios_version: Some("F10".to_owned()),
+            max_bios_version: Some("F20".to_owned()),
+            ..Default::default()
+        };
+        assert!(platform_matches(&constraint, &machine).unwrap());
+
+        // the regex has to match the whole product name
+        let constraint = PlatformConstraint {
+            product_name_regex: Some("Tioga".to_owned()),
+            ..Default::default()
+        };
+        assert!(!platform_matches(&constraint, &machine).unwrap());
+
+        let constraint = PlatformConstraint {
+            min_bios_version: Some("F21".to_owned()),
+            ..Default::default()
+        };
+        assert!(!platform_matches(&constraint, &machine).unwrap());
+
+        let constraint = PlatformConstraint {
+            product_name_regex: Some("(".to_owned()),
+            ..Default::default()
+        };
+        assert!(platform_matches(&constraint, &machine).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// A profile is the desired state of a machine's settings: a list of questions and the answers
+// they should have. Questions and answers can be canonical (from the spellings database) or
+// spelled like the backend spells them.
+//
+// settings:
+//   - question: Hyper Threading
+//     answer: Enabled
+//   - question: Boot Timeout
+//     answer: 5
+//     backend: hii
+//     selector: Boot
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub settings: Vec<ProfileSetting>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileSetting {
+    pub question: String,
+    #[serde(deserialize_with = "scalar_to_string")]
+    pub answer: String,
+    /// only use this backend, otherwise the backend set and get would pick is used
+    #[serde(default)]
+    pub backend: Option<ProfileBackend>,
+    /// same as --selector of set and get
+    #[serde(default)]
+    pub selector: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileBackend {
+    Hii,
+    Ilo,
+}
+
+/// load_profile reads a profile from a YAML (or JSON) file
+pub fn load_profile(path: &Path) -> Result<Profile> {
+    let contents = fs::read_to_string(path).context(format!("reading {}", path.display()))?;
+    serde_yaml::from_str(&contents).context(format!("parsing {}", path.display()))
+}
+
+// Answers like 5 or true are numbers and booleans in YAML but they're sent to backends as text
+fn scalar_to_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(s) => Ok(s),
+        serde_yaml::Value::Number(n) => Ok(n.to_string()),
+        serde_yaml::Value::Bool(b) => Ok(b.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "answer should be text, a number or a boolean, not {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profile() {
+        let profile: Profile = serde_yaml::from_str(
+            "settings:\n\
+             - question: Hyper Threading\n  answer: Enabled\n\
+             - question: Boot Timeout\n  answer: 5\n  backend: hii\n  selector: Boot\n",
+        )
+        .unwrap();
+        assert_eq!(
+            profile.settings,
+            vec![
+                ProfileSetting {
+                    question: "Hyper Threading".to_owned(),
+                    answer: "Enabled".to_owned(),
+                    ..Default::default()
+                },
+                ProfileSetting {
+                    question: "Boot Timeout".to_owned(),
+                    answer: "5".to_owned(),
+                    backend: Some(ProfileBackend::Hii),
+                    selector: Some("Boot".to_owned()),
+                },
+            ]
+        );
+
+        assert!(serde_yaml::from_str::<Profile>(
+            "settings:\n- question: Hyper Threading\n  answr: Enabled\n"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2023 Meta Platforms, Inc. and affiliates.
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+// The root directory which every system path (efivars, DMI, lock files, /dev/mem) is resolved against.
+// It is / unless it's changed, which allows working on a mounted image of another system
+// or on a temporary directory in tests.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// Environment variable which sets the root if it isn't set with set_system_root
+pub const SYSTEM_ROOT_ENV: &str = "UEFISETTINGS_ROOT";
+
+static SYSTEM_ROOT: RwLock<Option<SystemRoot>> = RwLock::new(None);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemRoot {
+    root: PathBuf,
+}
+
+impl Default for SystemRoot {
+    fn default() -> Self {
+        SystemRoot::new("/")
+    }
+}
+
+impl SystemRoot {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        SystemRoot { root: root.into() }
+    }
+
+    /// from_env uses the directory in UEFISETTINGS_ROOT or / if it isn't set
+    pub fn from_env() -> Self {
+        match std::env::var_os(SYSTEM_ROOT_ENV) {
+            Some(root) if !root.is_empty() => SystemRoot::new(root),
+            _ => SystemRoot::default(),
+        }
+    }
+
+    /// is_host returns true if the root is / (the running system), also when it's spelled
+    /// differently like /. or // or is a symlink to /
+    pub fn is_host(&self) -> bool {
+        match std::fs::canonicalize(&self.root) {
+            Ok(root) => root == Path::new("/"),
+            Err(_) => self.root == Path::new("/"),
+        }
+    }
+
+    /// join resolves an absolute system path like /sys/firmware/efi/efivars against the root
+    pub fn join(&self, system_path: impl AsRef<Path>) -> PathBuf {
+        let system_path = system_path.as_ref();
+        self.root
+            .join(system_path.strip_prefix("/").unwrap_or(system_path))
+    }
+}
+
+/// set_system_root changes the root for every system path used afterwards
+pub fn set_system_root(root: SystemRoot) {
+    *SYSTEM_ROOT
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(root);
+}
+
+/// system_root returns the root set by set_system_root, otherwise the one from the environment
+pub fn system_root() -> SystemRoot {
+    SYSTEM_ROOT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+        .unwrap_or_else(SystemRoot::from_env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join() {
+        let root = SystemRoot::default();
+        assert!(root.is_host());
+        assert_eq!(
+            root.join("/sys/class/dmi/id"),
+            PathBuf::from("/sys/class/dmi/id")
+        );
+
+        assert!(SystemRoot::new("/.").is_host());
+        assert!(SystemRoot::new("//").is_host());
+        assert!(SystemRoot::new("/sys/..").is_host());
+
+        let root = SystemRoot::new("/mnt/image");
+        assert!(!root.is_host());
+        assert_eq!(
+            root.join("/sys/firmware/efi/efivars"),
+            PathBuf::from("/mnt/image/sys/firmware/efi/efivars")
+        );
+    }
+}
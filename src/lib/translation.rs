@@ -10,13 +10,612 @@
 //
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
 
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
 use log::debug;
+use log::error;
 use log::info;
+use log::warn;
+use serde::Deserialize;
 
 use uefisettings_backend_thrift::Backend;
+use uefisettings_backend_thrift::MachineInfo;
 use uefisettings_spellings_db_thrift::consts::translation_db;
+use uefisettings_spellings_db_thrift::CompositeSetting;
+use uefisettings_spellings_db_thrift::CompositeStep;
+use uefisettings_spellings_db_thrift::HiiQuestion;
+use uefisettings_spellings_db_thrift::IloQuestion;
+use uefisettings_spellings_db_thrift::PlatformConstraint;
+use uefisettings_spellings_db_thrift::PlatformMapping;
+use uefisettings_spellings_db_thrift::QuestionMapping;
+
+use crate::answers::answers_equivalent;
+use crate::answers::normalize_answer;
+use crate::bundle;
+use crate::normalize::equivalent;
+use crate::platform;
+
+/// Extra spellings are read from every .json and .toml file in this directory (in file name order)
+pub const SPELLINGS_DIR: &str = "/etc/uefisettings/spellings.d";
+
+pub type SpellingsDB = BTreeMap<String, QuestionMapping>;
+
+static SPELLINGS_DB: OnceLock<SpellingsDB> = OnceLock::new();
+
+// The on-disk format of spellings files. It mirrors the thrift structs in uefisettings_spellings_db.thrift
+// so that the files look just like the output of show-translations --json.
+// Ex (JSON):
+// {"SR-IOV": {"hii_question": {"question_variations": ["SR-IOV Support"]}}}
+// Ex (TOML):
+// ["SR-IOV".hii_question]
+// question_variations = ["SR-IOV Support"]
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct QuestionMappingFile {
+    hii_question: Option<HiiQuestionFile>,
+    ilo_question: Option<IloQuestionFile>,
+    platforms: Option<Vec<PlatformMappingFile>>,
+    composite: Option<CompositeSettingFile>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct HiiQuestionFile {
+    question_variations: Vec<String>,
+    answer_replacements: Option<BTreeMap<String, Vec<String>>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct IloQuestionFile {
+    question: String,
+    answer_replacements: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct PlatformMappingFile {
+    platform: PlatformConstraintFile,
+    hii_question: Option<HiiQuestionFile>,
+    ilo_question: Option<IloQuestionFile>,
+    composite: Option<CompositeSettingFile>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct CompositeSettingFile {
+    hii_steps: Option<BTreeMap<String, Vec<CompositeStepFile>>>,
+    ilo_steps: Option<BTreeMap<String, Vec<CompositeStepFile>>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct CompositeStepFile {
+    question: String,
+    answer: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct PlatformConstraintFile {
+    bios_vendor: Option<String>,
+    product_name_regex: Option<String>,
+    min_bios_version: Option<String>,
+    max_bios_version: Option<String>,
+}
+
+impl From<HiiQuestionFile> for HiiQuestion {
+    fn from(f: HiiQuestionFile) -> Self {
+        HiiQuestion {
+            question_variations: f.question_variations,
+            answer_replacements: f.answer_replacements,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<IloQuestionFile> for IloQuestion {
+    fn from(f: IloQuestionFile) -> Self {
+        IloQuestion {
+            question: f.question,
+            answer_replacements: f.answer_replacements,
+            ..Default::default()
+        }
+    }
+}
+
+fn composite_steps_from(
+    steps: Option<BTreeMap<String, Vec<CompositeStepFile>>>,
+) -> Option<BTreeMap<String, Vec<CompositeStep>>> {
+    steps.map(|steps| {
+        steps
+            .into_iter()
+            .map(|(answer, steps)| {
+                let steps = steps
+                    .into_iter()
+                    .map(|step| CompositeStep {
+                        question: step.question,
+                        answer: step.answer,
+                        ..Default::default()
+                    })
+                    .collect();
+                (answer, steps)
+            })
+            .collect()
+    })
+}
+
+impl From<CompositeSettingFile> for CompositeSetting {
+    fn from(f: CompositeSettingFile) -> Self {
+        CompositeSetting {
+            hii_steps: composite_steps_from(f.hii_steps),
+            ilo_steps: composite_steps_from(f.ilo_steps),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PlatformMappingFile> for PlatformMapping {
+    fn from(f: PlatformMappingFile) -> Self {
+        PlatformMapping {
+            platform: PlatformConstraint {
+                bios_vendor: f.platform.bios_vendor,
+                product_name_regex: f.platform.product_name_regex,
+                min_bios_version: f.platform.min_bios_version,
+                max_bios_version: f.platform.max_bios_version,
+                ..Default::default()
+            },
+            hii_question: f.hii_question.map(Into::into),
+            ilo_question: f.ilo_question.map(Into::into),
+            composite: f.composite.map(Into::into),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<QuestionMappingFile> for QuestionMapping {
+    fn from(f: QuestionMappingFile) -> Self {
+        QuestionMapping {
+            hii_question: f.hii_question.map(Into::into),
+            ilo_question: f.ilo_question.map(Into::into),
+            platforms: f
+                .platforms
+                .map(|platforms| platforms.into_iter().map(Into::into).collect()),
+            composite: f.composite.map(Into::into),
+            ..Default::default()
+        }
+    }
+}
+
+/// load_spellings_db builds the spellings database which is used for all translations.
+/// It starts with the built-in translation_db, then merges files from SPELLINGS_DIR
+/// and finally the file or directory at extra_path over it.
+/// This can only be done once and has to happen before the first translation.
+pub fn load_spellings_db(extra_path: Option<&Path>) -> Result<&'static SpellingsDB> {
+    let db = build_spellings_db(extra_path)?;
+    SPELLINGS_DB
+        .set(db)
+        .map_err(|_| anyhow!("spellings database has already been loaded"))?;
+    Ok(spellings_db())
+}
+
+/// spellings_db returns the active spellings database.
+/// If load_spellings_db wasn't called then it's the built-in database merged with SPELLINGS_DIR.
+pub fn spellings_db() -> &'static SpellingsDB {
+    SPELLINGS_DB.get_or_init(|| match build_spellings_db(None) {
+        Ok(db) => db,
+        Err(why) => {
+            warn!("ignoring {}: {:#}", SPELLINGS_DIR, why);
+            translation_db.clone()
+        }
+    })
+}
+
+fn build_spellings_db(extra_path: Option<&Path>) -> Result<SpellingsDB> {
+    let mut db = translation_db.clone();
+
+    let spellings_dir = Path::new(SPELLINGS_DIR);
+    if spellings_dir.is_dir() {
+        merge_spellings_path(&mut db, spellings_dir)?;
+    }
+    if let Some(path) = extra_path {
+        merge_spellings_path(&mut db, path)?;
+    }
+
+    Ok(db)
+}
+
+/// merge_spellings_path merges a spellings file or every spellings file in a directory into db
+fn merge_spellings_path(db: &mut SpellingsDB, path: &Path) -> Result<()> {
+    if !path.is_dir() {
+        return merge_spellings_file(db, path);
+    }
+
+    let mut files = Vec::new();
+    for entry in
+        fs::read_dir(path).context(format!("Failed to read spellings directory {:?}", path))?
+    {
+        let file = entry?.path();
+        match file.extension().and_then(|ext| ext.to_str()) {
+            Some("json") | Some("toml") => files.push(file),
+            _ => debug!("skipping {:?} because it isn't a .json or .toml file", file),
+        }
+    }
+    files.sort();
+
+    for file in files {
+        merge_spellings_file(db, &file)?;
+    }
+    Ok(())
+}
+
+fn merge_spellings_file(db: &mut SpellingsDB, path: &Path) -> Result<()> {
+    info!("loading spellings from {:?}", path);
+    let contents =
+        fs::read_to_string(path).context(format!("Failed to read spellings file {:?}", path))?;
+
+    let parsed: BTreeMap<String, QuestionMappingFile> =
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&contents)
+                .context(format!("Failed to parse spellings file {:?}", path))?,
+            _ => serde_json::from_str(&contents)
+                .context(format!("Failed to parse spellings file {:?}", path))?,
+        };
+
+    // nothing is merged unless every entry of the file is valid
+    let mappings: Vec<(String, QuestionMapping)> = parsed
+        .into_iter()
+        .map(|(question, mapping)| (question, mapping.into()))
+        .collect();
+    for (question, mapping) in &mappings {
+        validate_mapping(question, mapping)
+            .context(format!("Invalid spellings file {:?}", path))?;
+    }
+    for (question, mapping) in mappings {
+        merge_mapping(db, question, mapping);
+    }
+    Ok(())
+}
+
+fn validate_mapping(question: &str, mapping: &QuestionMapping) -> Result<()> {
+    if question.trim().is_empty() {
+        return Err(anyhow!("canonical question can't be empty"));
+    }
+    if mapping.hii_question.is_none()
+        && mapping.ilo_question.is_none()
+        && mapping.platforms.is_none()
+        && mapping.composite.is_none()
+    {
+        return Err(anyhow!(
+            "'{question}' needs at least one of hii_question, ilo_question, platforms or composite"
+        ));
+    }
+
+    validate_backend_mappings(question, &mapping.hii_question, &mapping.ilo_question)?;
+    if let Some(composite) = &mapping.composite {
+        validate_composite(question, composite)?;
+    }
+
+    for platform_mapping in mapping.platforms.iter().flatten() {
+        validate_platform(question, &platform_mapping.platform)?;
+        if platform_mapping.hii_question.is_none()
+            && platform_mapping.ilo_question.is_none()
+            && platform_mapping.composite.is_none()
+        {
+            return Err(anyhow!(
+                "'{question}' has a platform entry without hii_question, ilo_question or composite"
+            ));
+        }
+        validate_backend_mappings(
+            question,
+            &platform_mapping.hii_question,
+            &platform_mapping.ilo_question,
+        )?;
+        if let Some(composite) = &platform_mapping.composite {
+            validate_composite(question, composite)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_backend_mappings(
+    question: &str,
+    hii_question: &Option<HiiQuestion>,
+    ilo_question: &Option<IloQuestion>,
+) -> Result<()> {
+    if let Some(hii_question) = hii_question {
+        if hii_question.question_variations.is_empty() {
+            return Err(anyhow!("'{question}' has no hii question_variations"));
+        }
+        if hii_question
+            .question_variations
+            .iter()
+            .any(|v| v.trim().is_empty())
+        {
+            return Err(anyhow!("'{question}' has an empty hii question variation"));
+        }
+        for (answer, replacements) in hii_question.answer_replacements.iter().flatten() {
+            if answer.trim().is_empty() || replacements.iter().any(|r| r.trim().is_empty()) {
+                return Err(anyhow!("'{question}' has an empty hii answer replacement"));
+            }
+            if replacements.is_empty() {
+                return Err(anyhow!(
+                    "'{question}' has no hii answer replacements for '{answer}'"
+                ));
+            }
+        }
+    }
+
+    if let Some(ilo_question) = ilo_question {
+        if ilo_question.question.trim().is_empty() {
+            return Err(anyhow!("'{question}' has an empty ilo question"));
+        }
+        for (answer, replacement) in ilo_question.answer_replacements.iter().flatten() {
+            if answer.trim().is_empty() || replacement.trim().is_empty() {
+                return Err(anyhow!("'{question}' has an empty ilo answer replacement"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_composite(question: &str, composite: &CompositeSetting) -> Result<()> {
+    if composite.hii_steps.is_none() && composite.ilo_steps.is_none() {
+        return Err(anyhow!(
+            "'{question}' has a composite setting without hii_steps or ilo_steps"
+        ));
+    }
+
+    for (answer, steps) in composite
+        .hii_steps
+        .iter()
+        .flatten()
+        .chain(composite.ilo_steps.iter().flatten())
+    {
+        if answer.trim().is_empty() {
+            return Err(anyhow!(
+                "'{question}' has a composite answer which is empty"
+            ));
+        }
+        if steps.is_empty() {
+            return Err(anyhow!(
+                "'{question}' has no composite steps for '{answer}'"
+            ));
+        }
+        for step in steps {
+            if step.question.trim().is_empty() || step.answer.trim().is_empty() {
+                return Err(anyhow!(
+                    "'{question}' has an empty composite step for '{answer}'"
+                ));
+            }
+            if equivalent(&step.question, question) {
+                return Err(anyhow!(
+                    "'{question}' has a composite step which changes itself"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_platform(question: &str, platform: &PlatformConstraint) -> Result<()> {
+    if platform.bios_vendor.is_none()
+        && platform.product_name_regex.is_none()
+        && platform.min_bios_version.is_none()
+        && platform.max_bios_version.is_none()
+    {
+        return Err(anyhow!(
+            "'{question}' has a platform entry without constraints"
+        ));
+    }
+    // matching against an empty machine is enough to find invalid regexes
+    platform::platform_matches(platform, &MachineInfo::default())
+        .context(format!("'{question}' has an invalid platform entry"))?;
+    Ok(())
+}
+
+/// merge_mapping puts the mapping into db. Every part (hii_question, ilo_question, platforms)
+/// which is set replaces the existing part, the others are kept.
+fn merge_mapping(db: &mut SpellingsDB, question: String, mapping: QuestionMapping) {
+    let entry = db.entry(question).or_default();
+
+    if mapping.hii_question.is_some() {
+        entry.hii_question = mapping.hii_question;
+    }
+    if mapping.ilo_question.is_some() {
+        entry.ilo_question = mapping.ilo_question;
+    }
+    if mapping.platforms.is_some() {
+        entry.platforms = mapping.platforms;
+    }
+    if mapping.composite.is_some() {
+        entry.composite = mapping.composite;
+    }
+}
+
+thread_local! {
+    /// Machine cached by the MachineCache which is active on this thread, see host_machine
+    static CACHED_MACHINE: RefCell<Option<Option<MachineInfo>>> = const { RefCell::new(None) };
+}
+
+/// MachineCache makes every translation until it's dropped use the machine which was read for the
+/// first platform specific mapping, instead of reading DMI again for every lookup.
+/// A MachineCache started while another one is active leaves the outer one in charge.
+pub struct MachineCache {
+    owner: bool,
+}
+
+impl MachineCache {
+    pub fn start() -> Self {
+        let owner = CACHED_MACHINE.with(|cached| {
+            let mut cached = cached.borrow_mut();
+            if cached.is_some() {
+                return false;
+            }
+            *cached = Some(None);
+            true
+        });
+        MachineCache { owner }
+    }
+}
+
+impl Drop for MachineCache {
+    fn drop(&mut self) {
+        if self.owner {
+            CACHED_MACHINE.with(|cached| *cached.borrow_mut() = None);
+        }
+    }
+}
+
+/// host_machine returns the DMI information of the machine being inspected:
+/// the captured machine of a replayed bundle, or else the one under the current system root
+fn host_machine() -> MachineInfo {
+    if let Some(Some(machine)) = CACHED_MACHINE.with(|cached| cached.borrow().clone()) {
+        return machine;
+    }
+
+    let machine = match bundle::replayed_bundle() {
+        Some(replayed) => replayed.machine().clone(),
+        None => platform::read_dmi_info(),
+    };
+    CACHED_MACHINE.with(|cached| {
+        if let Some(cached) = cached.borrow_mut().as_mut() {
+            *cached = Some(machine.clone());
+        }
+    });
+    machine
+}
+
+/// resolve_for_machine returns the mapping with the first matching platform entry applied
+/// over the generic hii_question/ilo_question. The result has no platform entries.
+fn resolve_for_machine(mapping: &QuestionMapping, machine: &MachineInfo) -> QuestionMapping {
+    let mut resolved = QuestionMapping {
+        hii_question: mapping.hii_question.clone(),
+        ilo_question: mapping.ilo_question.clone(),
+        composite: mapping.composite.clone(),
+        ..Default::default()
+    };
+
+    for platform_mapping in mapping.platforms.iter().flatten() {
+        match platform::platform_matches(&platform_mapping.platform, machine) {
+            Ok(true) => {
+                debug!("using platform specific mapping {:?}", platform_mapping);
+                if platform_mapping.hii_question.is_some() {
+                    resolved.hii_question = platform_mapping.hii_question.clone();
+                }
+                if platform_mapping.ilo_question.is_some() {
+                    resolved.ilo_question = platform_mapping.ilo_question.clone();
+                }
+                if platform_mapping.composite.is_some() {
+                    resolved.composite = platform_mapping.composite.clone();
+                }
+                break;
+            }
+            Ok(false) => {}
+            Err(why) => error!("ignoring platform specific mapping: {:#}", why),
+        }
+    }
+
+    resolved
+}
+
+/// find_question_mapping looks up a canonical question in the spellings database
+/// and resolves it for the current machine. The machine is only read if the mapping has platform entries.
+/// An exact match is preferred but "Hyper-Threading" will still find "Hyper Threading".
+fn find_question_mapping(question: &str) -> Option<QuestionMapping> {
+    let db = spellings_db();
+    db.get(question)
+        .or_else(|| {
+            db.iter()
+                .find(|(canonical, _)| equivalent(canonical, question))
+                .map(|(_, mapping)| mapping)
+        })
+        .map(|mapping| match mapping.platforms {
+            Some(_) => resolve_for_machine(mapping, &host_machine()),
+            None => mapping.clone(),
+        })
+}
+
+/// get_composite_steps returns the ordered steps which change the composite setting to answer on this backend.
+/// It returns None if the question isn't a composite setting for this backend
+/// and fails if it is one but it doesn't define steps for answer.
+pub fn get_composite_steps(
+    question: &str,
+    answer: &str,
+    backend: Backend,
+) -> Result<Option<Vec<CompositeStep>>> {
+    let steps = match composite_steps_for_backend(question, backend) {
+        Some(steps) => steps,
+        None => return Ok(None),
+    };
+
+    steps
+        .into_iter()
+        .find(|(canonical_answer, _)| canonical_answer.eq_ignore_ascii_case(answer))
+        .map(|(_, steps)| Some(steps))
+        .ok_or_else(|| {
+            anyhow!("composite setting '{question}' doesn't define steps for answer '{answer}'")
+        })
+}
+
+/// get_composite_questions returns every question changed by the composite setting on this backend
+/// (in order of first appearance) or None if the question isn't a composite setting for this backend
+pub fn get_composite_questions(question: &str, backend: Backend) -> Option<Vec<String>> {
+    let steps = composite_steps_for_backend(question, backend)?;
+
+    let mut questions: Vec<String> = Vec::new();
+    for step in steps.into_values().flatten() {
+        if !questions.iter().any(|q| equivalent(q, &step.question)) {
+            questions.push(step.question);
+        }
+    }
+    Some(questions)
+}
+
+/// get_composite_answer returns the canonical answer of a composite setting whose steps all match
+/// the current (question, answer) pairs or None if the current answers don't match any of its answers
+pub fn get_composite_answer(
+    question: &str,
+    backend: Backend,
+    current: &[(String, String)],
+) -> Option<String> {
+    let steps = composite_steps_for_backend(question, backend)?;
+
+    steps
+        .into_iter()
+        .find(|(_, steps)| {
+            steps.iter().all(|step| {
+                current.iter().any(|(q, a)| {
+                    equivalent(q, &step.question) && answers_equivalent(a, &step.answer)
+                })
+            })
+        })
+        .map(|(canonical_answer, _)| canonical_answer)
+}
+
+fn composite_steps_for_backend(
+    question: &str,
+    backend: Backend,
+) -> Option<BTreeMap<String, Vec<CompositeStep>>> {
+    let composite = find_question_mapping(question)?.composite?;
+    match backend {
+        Backend::Hii => composite.hii_steps,
+        Backend::Ilo => composite.ilo_steps,
+        _ => None,
+    }
+}
 
 pub enum HiiTranslation {
     Translated {
@@ -31,14 +630,14 @@ pub enum HiiTranslation {
 
 /// get_qa_variations_hii translates canonical questions and answers into possible hii variants
 /// If any part isn't in the translation database, it doesn't fail it just returns the original values in required form.
-/// Ex: the canonical question "Hyper Threading" -> ["Hyper-Threading", "Enable LP", "Hyper-Threading [ALL]"]
+/// Ex: the canonical question "Hyper Threading" -> ["Hyper-Threading", "Enable LP"]
 /// and for it the canonical answer "Enabled" -> ["Enabled", "Enable"].
 pub fn get_qa_variations_hii(question: &str, answer: &str) -> HiiTranslation {
     let mut question_variations = HashSet::from([question.to_owned()]);
     let mut answer_variations = HashSet::from([answer.to_owned()]);
 
     // if spellings_db has question variations, then use those instead
-    if let Some(question_mapping) = translation_db.get(question) {
+    if let Some(question_mapping) = find_question_mapping(question) {
         if let Some(hii_question_mapping) = &question_mapping.hii_question {
             // use question variations
             if !hii_question_mapping.question_variations.is_empty() {
@@ -90,7 +689,7 @@ pub enum IloTranslation {
 /// If any part isn't in the translation database, it doesn't fail it just returns the original values.
 /// Ex: the canonical question "TPM State" -> "TpmState" and for it the canonical answer "Enabled" -> "PresentEnabled".
 pub fn get_qa_variations_ilo(question: &str, answer: &str) -> IloTranslation {
-    if let Some(question_mapping) = translation_db.get(question) {
+    if let Some(question_mapping) = find_question_mapping(question) {
         if let Some(ilo_question_mapping) = &question_mapping.ilo_question {
             // use translated question name instead of canonical name
             let translated_question = ilo_question_mapping.question.to_owned();
@@ -121,9 +720,11 @@ pub fn get_qa_variations_ilo(question: &str, answer: &str) -> IloTranslation {
 /// translate_response: If the question is using canonical spelling then we should
 /// use the canonical spelling of the answer in the response to the user
 /// i.e. basically reverse replacement from real answer to canonical answer
-/// if something wasn't found in the db then return the real/original answer
+/// if the question wasn't found in the db then return the real/original answer.
+/// Answers of questions in the db without a replacement are normalized using the shared vocabulary
+/// (see answers::normalize_answer) so "Enable" and "PresentEnabled" are both shown as "Enabled"
 pub fn translate_response(question: &str, answer: &str, backend: Backend) -> String {
-    let question_mapping = translation_db.get(question);
+    let question_mapping = find_question_mapping(question);
     if question_mapping.is_none() {
         return answer.to_owned();
     }
@@ -158,5 +759,146 @@ pub fn translate_response(question: &str, answer: &str, backend: Backend) -> Str
         }
         _ => {}
     }
-    answer.to_owned()
+    normalize_answer(answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    fn spellings_file(suffix: &str, contents: &str) -> NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_merge_spellings_files() {
+        let mut db = translation_db.clone();
+
+        let json = spellings_file(
+            ".json",
+            r#"{"SR-IOV": {"hii_question": {"question_variations": ["SR-IOV Support"]}}}"#,
+        );
+        merge_spellings_path(&mut db, json.path()).unwrap();
+        assert_eq!(
+            db["SR-IOV"]
+                .hii_question
+                .as_ref()
+                .unwrap()
+                .question_variations,
+            vec!["SR-IOV Support"]
+        );
+
+        // only the hii mapping of an existing question is replaced, the ilo one is kept
+        let toml = spellings_file(
+            ".toml",
+            r#"
+            ["TPM State".hii_question]
+            question_variations = ["TPM Device State"]
+            answer_replacements = { Enabled = ["Enabled"] }
+            "#,
+        );
+        merge_spellings_path(&mut db, toml.path()).unwrap();
+        let tpm_state = &db["TPM State"];
+        assert_eq!(
+            tpm_state.hii_question.as_ref().unwrap().question_variations,
+            vec!["TPM Device State"]
+        );
+        assert_eq!(
+            tpm_state.ilo_question.as_ref().unwrap().question,
+            "TpmState"
+        );
+    }
+
+    #[test]
+    fn test_invalid_spellings_files() {
+        let mut db = translation_db.clone();
+
+        for (suffix, contents) in [
+            (".json", r#"{"SR-IOV": {}}"#),
+            (
+                ".json",
+                r#"{"SR-IOV": {"hii_question": {"question_variations": []}}}"#,
+            ),
+            (".json", r#"{"SR-IOV": {"ilo_question": {"question": ""}}}"#),
+            (
+                ".json",
+                r#"{"SR-IOV": {"hii_question": {"variations": ["SR-IOV"]}}}"#,
+            ),
+            (".toml", "not toml"),
+        ] {
+            let file = spellings_file(suffix, contents);
+            assert!(
+                merge_spellings_path(&mut db, file.path()).is_err(),
+                "{contents} should be rejected"
+            );
+        }
+        assert!(!db.contains_key("SR-IOV"));
+
+        let file = spellings_file(
+            ".toml",
+            r#"
+            [[SR-IOV.platforms]]
+            platform = { product_name_regex = "(" }
+            hii_question = { question_variations = ["SR-IOV Support"] }
+            "#,
+        );
+        assert!(merge_spellings_path(&mut db, file.path()).is_err());
+
+        // a file with an invalid entry isn't merged partly
+        let file = spellings_file(
+            ".json",
+            r#"{
+                "Above 4G Decoding": {"hii_question": {"question_variations": ["Above 4G Decoding"]}},
+                "SR-IOV": {}
+            }"#,
+        );
+        assert!(merge_spellings_path(&mut db, file.path()).is_err());
+        assert!(!db.contains_key("Above 4G Decoding"));
+    }
+
+    #[test]
+    fn test_platform_specific_mapping() {
+        let mut db = translation_db.clone();
+
+        let toml = spellings_file(
+            ".toml",
+            r#"
+            ["Hyper Threading".hii_question]
+            question_variations = ["Hyper-Threading"]
+
+            [["Hyper Threading".platforms]]
+            platform = { bios_vendor = "Vendor A", min_bios_version = "2.0" }
+            hii_question = { question_variations = ["Enable LP"] }
+            "#,
+        );
+        merge_spellings_path(&mut db, toml.path()).unwrap();
+        let mapping = &db["Hyper Threading"];
+
+        let variations = |machine: &MachineInfo| {
+            resolve_for_machine(mapping, machine)
+                .hii_question
+                .unwrap()
+                .question_variations
+        };
+
+        let mut machine = MachineInfo {
+            bios_vendor: "Vendor A".to_owned(),
+            bios_version: "2.1".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(variations(&machine), vec!["Enable LP"]);
+
+        machine.bios_version = "1.9".to_owned();
+        assert_eq!(variations(&machine), vec!["Hyper-Threading"]);
+
+        machine.bios_vendor = "Vendor B".to_owned();
+        machine.bios_version = "2.1".to_owned();
+        assert_eq!(variations(&machine), vec!["Hyper-Threading"]);
+    }
 }
@@ -11,6 +11,7 @@
 // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::fmt::Debug;
+use std::fs;
 use std::fs::File;
 use std::io::Read;
 use std::io::Write;
@@ -26,16 +27,36 @@ use clap::Subcommand;
 use fbthrift::simplejson_protocol;
 use fbthrift::simplejson_protocol::Serializable;
 use log::info;
+use log::warn;
+use uefisettings::exports::apply_profile;
+use uefisettings::exports::cache_machine;
+use uefisettings::exports::capabilities;
+use uefisettings::exports::capture_bundle;
+use uefisettings::exports::check_profile;
 use uefisettings::exports::identify_machine;
+use uefisettings::exports::load_profile;
+use uefisettings::exports::load_spellings_db;
+use uefisettings::exports::replay_bundle;
+use uefisettings::exports::set_captured_efivars_dir;
+use uefisettings::exports::set_system_root;
+use uefisettings::exports::spellings_db;
+use uefisettings::exports::undo;
+use uefisettings::exports::GetOptions;
 use uefisettings::exports::HiiBackend;
 use uefisettings::exports::IloBackend;
+use uefisettings::exports::SetOptions;
 use uefisettings::exports::SettingsBackend;
 use uefisettings_backend_thrift::Backend;
+use uefisettings_backend_thrift::HiiTranslationReport;
 use uefisettings_backend_thrift::MachineInfo;
-use uefisettings_spellings_db_thrift::consts::translation_db;
+use uefisettings_backend_thrift::SetStatus;
 
 const MAX_ALLOWED_FILESIZE: u64 = 16 * 1024 * 1024;
 
+/// check exits with this if a question doesn't have its answer from the profile,
+/// every command exits with 1 if it fails
+const EXIT_DRIFT: i32 = 2;
+
 #[derive(Debug, Parser)]
 #[clap(
     name = "uefisettings",
@@ -45,6 +66,16 @@ const MAX_ALLOWED_FILESIZE: u64 = 16 * 1024 * 1024;
 struct UefiSettingsToolArgs {
     #[clap(subcommand)]
     command: Commands,
+    /// Spellings file (.json or .toml) or directory of spellings files to merge over the built-in translations
+    #[clap(parse(from_os_str), long, global = true)]
+    spellings: Option<PathBuf>,
+    /// Directory to use instead of / for efivars, DMI and lock files (ex: a mounted image of another system).
+    /// Defaults to UEFISETTINGS_ROOT
+    #[clap(parse(from_os_str), long, global = true)]
+    root: Option<PathBuf>,
+    /// Read from a bundle made by capture instead of this machine (read-only commands)
+    #[clap(parse(from_os_str), long, global = true)]
+    bundle: Option<PathBuf>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -64,6 +95,12 @@ enum Commands {
     Get {
         #[clap(value_parser)]
         question: String,
+        /// Only look at questions in this selector (Hii: package list GUID, formset GUID or form title; Ilo: bios, debug or service)
+        #[clap(short, long)]
+        selector: Option<String>,
+        /// If nothing matches, suggest the closest questions (hii only)
+        #[clap(long, action)]
+        fuzzy: bool,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
@@ -73,6 +110,85 @@ enum Commands {
         question: String,
         #[clap(value_parser)]
         value: String,
+        /// Only change questions in this selector (Hii: package list GUID, formset GUID or form title; Ilo: bios, debug or service)
+        #[clap(short, long)]
+        selector: Option<String>,
+        /// Change every matching question even if they are stored in different places
+        #[clap(long, action)]
+        all: bool,
+        /// If nothing matches, suggest the closest questions (hii only)
+        #[clap(long, action)]
+        fuzzy: bool,
+        /// Show what would be written without changing anything
+        #[clap(long, action)]
+        dry_run: bool,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Auto-identify backends and change every question of a profile which doesn't have its answer yet
+    Apply {
+        /// YAML (or JSON) file with a list of settings, each with a question, an answer and optionally a backend and a selector
+        #[clap(parse(from_os_str))]
+        profile: PathBuf,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Auto-identify backends and compare the questions of a profile with their current answers without changing anything.
+    /// Exits with 2 if any question doesn't have its answer from the profile.
+    Check {
+        /// YAML (or JSON) file with a list of settings like the one used by apply
+        #[clap(parse(from_os_str))]
+        profile: PathBuf,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Revert changes made by set (recorded in /var/lib/uefisettings/journal), newest first.
+    /// A change is only reverted if its question still has the value it was changed to.
+    Undo {
+        /// Revert the last N changes which weren't undone yet
+        #[clap(long, default_value_t = 1, conflicts_with = "id")]
+        last: usize,
+        /// Revert the change with this id in the journal
+        #[clap(long)]
+        id: Option<u64>,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Auto-identify backends and report which canonical questions from the spellings database they support
+    Capabilities {
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Capture HiiDB, efivars, DMI information and iLO attributes into a bundle for bug reports and offline replay
+    Capture {
+        /// Where to write the bundle (a tar archive)
+        #[clap(parse(from_os_str), short, long)]
+        output: PathBuf,
+    },
+    /// Check the hii spellings in the translation database against HiiDB dumps
+    CheckTranslations {
+        /// HiiDB dumps to check against (see hii extract-db)
+        #[clap(
+            parse(from_os_str),
+            long = "db",
+            required = true,
+            multiple_values = true
+        )]
+        db: Vec<PathBuf>,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Suggest new translations by clustering similar questions across HiiDB dumps
+    ///
+    /// Prints a draft spellings file which can be reviewed and used with --spellings
+    SuggestTranslations {
+        /// Directory of HiiDB dumps, ideally from different vendors (see hii extract-db).
+        /// Files which aren't HiiDB dumps are skipped with a warning
+        #[clap(parse(from_os_str))]
+        dumps: PathBuf,
+        /// Only suggest questions which are found in at least this many dumps
+        #[clap(long, default_value_t = 2)]
+        min_dumps: usize,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
@@ -95,6 +211,19 @@ enum HiiSubcommands {
     Get {
         #[clap(value_parser)]
         question: String,
+        /// Only look at questions in this selector (package list GUID, formset GUID or form title)
+        #[clap(short, long)]
+        selector: Option<String>,
+        /// If nothing matches, suggest the closest questions
+        #[clap(long, action)]
+        fuzzy: bool,
+        /// Read questions from this HiiDB dump instead of extracting HiiDB from the machine
+        #[clap(parse(from_os_str), long)]
+        db: Option<PathBuf>,
+        /// Read answers from this directory of captured efivars instead of /sys/firmware/efi/efivars.
+        /// Needs the HiiDB dump which was captured with them
+        #[clap(parse(from_os_str), long, requires = "db")]
+        efivars_dir: Option<PathBuf>,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
@@ -104,24 +233,96 @@ enum HiiSubcommands {
         question: String,
         #[clap(value_parser)]
         value: String,
+        /// Only change questions in this selector (package list GUID, formset GUID or form title)
+        #[clap(short, long)]
+        selector: Option<String>,
+        /// Change every matching question even if they are stored in different places
+        #[clap(long, action)]
+        all: bool,
+        /// If nothing matches, suggest the closest questions
+        #[clap(long, action)]
+        fuzzy: bool,
+        /// Show what would be written without changing anything
+        #[clap(long, action)]
+        dry_run: bool,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
     /// Show a human readable representation of the Hii Forms
     ShowIFR {
         /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
-        #[clap(parse(from_os_str), short, long)]
+        #[clap(parse(from_os_str), short, long, alias = "db")]
         filename: Option<PathBuf>,
+        /// Read answers from this directory of captured efivars instead of /sys/firmware/efi/efivars.
+        /// Needs the HiiDB dump which was captured with them
+        #[clap(parse(from_os_str), long, requires = "filename")]
+        efivars_dir: Option<PathBuf>,
     },
     /// Dump HiiDB into a file
     ExtractDB {
         #[clap(parse(from_os_str))]
         filename: PathBuf,
     },
+    /// Write a smaller HiiDB with only some questions, to share as a fixture
+    Minimize {
+        /// Questions to keep, can be given multiple times
+        #[clap(short, long = "question", required = true)]
+        questions: Vec<String>,
+        /// Where to write the smaller HiiDB
+        #[clap(parse(from_os_str), short, long)]
+        output: PathBuf,
+        /// Replace titles and help texts with placeholders (question prompts and options are kept)
+        #[clap(long, action)]
+        anonymize: bool,
+        /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
+        #[clap(parse(from_os_str), short, long, alias = "db")]
+        filename: Option<PathBuf>,
+    },
+    /// Compare the questions of two HiiDB dumps, for example from before and after a BIOS update
+    Diff {
+        /// HiiDB dump to compare against
+        #[clap(parse(from_os_str))]
+        old: PathBuf,
+        #[clap(parse(from_os_str))]
+        new: PathBuf,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Record the value of every question into a file which can be restored later
+    Snapshot {
+        /// Where to write the snapshot
+        #[clap(parse(from_os_str), short, long)]
+        output: PathBuf,
+        /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
+        #[clap(parse(from_os_str), short, long, alias = "db")]
+        filename: Option<PathBuf>,
+        /// Read answers from this directory of captured efivars instead of /sys/firmware/efi/efivars.
+        /// Needs the HiiDB dump which was captured with them
+        #[clap(parse(from_os_str), long, requires = "filename")]
+        efivars_dir: Option<PathBuf>,
+    },
+    /// Change every question whose value differs from a snapshot back to the value in the snapshot
+    Restore {
+        /// Snapshot written by hii snapshot
+        #[clap(parse(from_os_str))]
+        snapshot: PathBuf,
+        /// The snapshot was taken on another BIOS version: match questions by prompt, breadcrumb,
+        /// options and varstore name and only report how they were matched
+        #[clap(long, action)]
+        migrate: bool,
+        /// Write the settings which were matched confidently by --migrate
+        #[clap(long, action, requires = "migrate")]
+        apply: bool,
+        /// Also write the settings which --migrate isn't sure about
+        #[clap(long, action, requires = "apply")]
+        include_uncertain: bool,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
     /// List all strings-id, string pairs in HiiDB
     ListStrings {
         /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
-        #[clap(parse(from_os_str), short, long)]
+        #[clap(parse(from_os_str), short, long, alias = "db")]
         filename: Option<PathBuf>,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
@@ -129,7 +330,32 @@ enum HiiSubcommands {
     /// List questions HiiDB. Note that these are not all possible questions, because we don't parse certain non-useful question types.
     ListQuestions {
         /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
-        #[clap(parse(from_os_str), short, long)]
+        #[clap(parse(from_os_str), short, long, alias = "db")]
+        filename: Option<PathBuf>,
+        /// Read answers from this directory of captured efivars instead of /sys/firmware/efi/efivars.
+        /// Needs the HiiDB dump which was captured with them
+        #[clap(parse(from_os_str), long, requires = "filename")]
+        efivars_dir: Option<PathBuf>,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// List formsets and their forms in HiiDB
+    ListForms {
+        /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
+        #[clap(parse(from_os_str), short, long, alias = "db")]
+        filename: Option<PathBuf>,
+        #[clap(short = 'j', long = "json", action, value_parser)]
+        json: bool,
+    },
+    /// Search question prompts, help strings and option labels
+    Search {
+        /// Case-insensitive text to look for
+        pattern: String,
+        /// Treat the pattern as a regular expression instead of plain text
+        #[clap(short, long, action)]
+        regex: bool,
+        /// If filename of HiiDB isn't specified then this tool will try to automatically extract it
+        #[clap(parse(from_os_str), short, long, alias = "db")]
         filename: Option<PathBuf>,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
@@ -148,6 +374,9 @@ enum IloSubcommands {
     Get {
         #[clap(value_parser)]
         question: String,
+        /// Only look at attributes in this selector (bios, debug or service)
+        #[clap(short, long)]
+        selector: Option<String>,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
@@ -157,6 +386,12 @@ enum IloSubcommands {
         question: String,
         #[clap(value_parser)]
         value: String,
+        /// Only change attributes in this selector (bios, debug or service)
+        #[clap(short, long)]
+        selector: Option<String>,
+        /// Show what would be written without changing anything
+        #[clap(long, action)]
+        dry_run: bool,
         #[clap(short = 'j', long = "json", action, value_parser)]
         json: bool,
     },
@@ -176,37 +411,91 @@ fn main() -> Result<()> {
     env_logger::init();
     let args = UefiSettingsToolArgs::parse();
 
-    if let Err(why) = handle_cmds(args) {
-        print_with_style(
-            uefisettings_backend_thrift::Error {
-                error_message: format!("{:#}", why),
-                ..Default::default()
-            },
-            true,
-        );
-        process::exit(1);
+    // the exit code is only used once handle_cmds returned, so that everything it started
+    // (like the replay of a bundle) is cleaned up before exiting
+    let exit_code = match handle_cmds(args) {
+        Ok(exit_code) => exit_code,
+        Err(why) => {
+            print_with_style(
+                uefisettings_backend_thrift::Error {
+                    error_message: format!("{:#}", why),
+                    ..Default::default()
+                },
+                true,
+            );
+            1
+        }
+    };
+    if exit_code != 0 {
+        process::exit(exit_code);
     }
 
     info!("Exiting UEFI Settings Manipulation Tool");
     Ok(())
 }
 
-fn handle_cmds(args: UefiSettingsToolArgs) -> Result<()> {
+/// handle_cmds runs the command and returns the code to exit with
+fn handle_cmds(args: UefiSettingsToolArgs) -> Result<i32> {
+    // without --spellings the database is loaded on the first translation, and a bad file in
+    // /etc/uefisettings/spellings.d is only warned about so that commands which don't translate still work
+    if let Some(spellings) = &args.spellings {
+        load_spellings_db(Some(spellings))?;
+    }
+    if let Some(root) = &args.root {
+        set_system_root(root)?;
+    }
+    // reads come from the bundle until _replay is dropped at the end of the command
+    let _replay = match &args.bundle {
+        Some(bundle) => Some(replay_bundle(bundle)?),
+        None => None,
+    };
+    // platform specific spellings are resolved against one read of the machine for the whole command
+    let _machine = cache_machine();
+
     match &args.command {
         Commands::Hii(hii_command) => match &hii_command.command {
-            HiiSubcommands::Get { question, json } => {
-                let res = HiiBackend::get(question, None)?;
+            HiiSubcommands::Get {
+                question,
+                selector,
+                fuzzy,
+                db,
+                efivars_dir,
+                json,
+            } => {
+                set_captured_efivars_dir(efivars_dir.as_deref())?;
+                let options = GetOptions {
+                    fuzzy: *fuzzy,
+                    db: match db {
+                        Some(db) => Some(get_db_dump_bytes(Some(db))?),
+                        None => None,
+                    },
+                };
+                let res = HiiBackend::get_with_options(question, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             }
             HiiSubcommands::Set {
                 question,
                 value,
+                selector,
+                all,
+                fuzzy,
+                dry_run,
                 json,
             } => {
-                let res = HiiBackend::set(question, value, None)?;
+                let options = SetOptions {
+                    all: *all,
+                    fuzzy: *fuzzy,
+                    dry_run: *dry_run,
+                };
+                let res =
+                    HiiBackend::set_with_options(question, value, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             }
-            HiiSubcommands::ShowIFR { filename } => {
+            HiiSubcommands::ShowIFR {
+                filename,
+                efivars_dir,
+            } => {
+                set_captured_efivars_dir(efivars_dir.as_deref())?;
                 let res = HiiBackend::show_ifr(&get_db_dump_bytes(filename.as_deref())?)?;
                 println!("{}", res.readable_representation);
             }
@@ -217,26 +506,112 @@ fn handle_cmds(args: UefiSettingsToolArgs) -> Result<()> {
 
                 println!("{{\"info\": \"HiiDB written to {:?}\"}}", &filename);
             }
+            HiiSubcommands::Minimize {
+                questions,
+                output,
+                anonymize,
+                filename,
+            } => {
+                let res = HiiBackend::minimize(
+                    &get_db_dump_bytes(filename.as_deref())?,
+                    questions,
+                    *anonymize,
+                )?;
+                File::create(output)?.write_all(&res.db)?;
+
+                println!("{{\"info\": \"HiiDB written to {:?}\"}}", &output);
+            }
+            HiiSubcommands::Diff { old, new, json } => {
+                let res = HiiBackend::diff(
+                    &get_db_dump_bytes(Some(old))?,
+                    &get_db_dump_bytes(Some(new))?,
+                )?;
+                print_with_style(res, *json);
+            }
+            HiiSubcommands::Snapshot {
+                output,
+                filename,
+                efivars_dir,
+            } => {
+                set_captured_efivars_dir(efivars_dir.as_deref())?;
+                let res = HiiBackend::snapshot(&get_db_dump_bytes(filename.as_deref())?)?;
+                File::create(output)?.write_all(&simplejson_protocol::serialize(&res))?;
+
+                println!(
+                    "{{\"info\": \"{} settings written to {:?}\"}}",
+                    res.settings.len(),
+                    &output
+                );
+            }
+            HiiSubcommands::Restore {
+                snapshot,
+                migrate,
+                apply,
+                include_uncertain,
+                json,
+            } => {
+                let snapshot = simplejson_protocol::deserialize(
+                    &fs::read(snapshot).context(format!("reading {}", snapshot.display()))?,
+                )
+                .context(format!("parsing {}", snapshot.display()))?;
+                if *migrate {
+                    let res = HiiBackend::migrate(&snapshot, *apply, *include_uncertain)?;
+                    print_with_style(res, *json);
+                } else {
+                    let res = HiiBackend::restore(&snapshot)?;
+                    print_with_style(res, *json);
+                }
+            }
             HiiSubcommands::ListStrings { filename, json } => {
                 let res = HiiBackend::list_strings(&get_db_dump_bytes(filename.as_deref())?)?;
                 print_with_style(res, *json);
             }
-            HiiSubcommands::ListQuestions { filename, json } => {
+            HiiSubcommands::ListQuestions {
+                filename,
+                efivars_dir,
+                json,
+            } => {
+                set_captured_efivars_dir(efivars_dir.as_deref())?;
                 let res = HiiBackend::list_questions(&get_db_dump_bytes(filename.as_deref())?)?;
                 print_with_style(res, *json);
             }
+            HiiSubcommands::ListForms { filename, json } => {
+                let res = HiiBackend::list_forms(&get_db_dump_bytes(filename.as_deref())?)?;
+                print_with_style(res, *json);
+            }
+            HiiSubcommands::Search {
+                pattern,
+                regex,
+                filename,
+                json,
+            } => {
+                let res =
+                    HiiBackend::search(&get_db_dump_bytes(filename.as_deref())?, pattern, *regex)?;
+                print_with_style(res, *json);
+            }
         },
         Commands::Ilo(ilo_command) => match &ilo_command.command {
-            IloSubcommands::Get { question, json } => {
-                let res = IloBackend::get(question, None)?;
+            IloSubcommands::Get {
+                question,
+                selector,
+                json,
+            } => {
+                let res = IloBackend::get(question, selector.as_deref())?;
                 print_with_style(res, *json);
             }
             IloSubcommands::Set {
                 question,
                 value,
+                selector,
+                dry_run,
                 json,
             } => {
-                let res = IloBackend::set(question, value, None)?;
+                let options = SetOptions {
+                    dry_run: *dry_run,
+                    ..Default::default()
+                };
+                let res =
+                    IloBackend::set_with_options(question, value, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             }
             IloSubcommands::ShowAttributes { json } => {
@@ -252,41 +627,144 @@ fn handle_cmds(args: UefiSettingsToolArgs) -> Result<()> {
             let machine = identify_machine();
             print_with_style(machine, *json);
         }
-        Commands::Get { question, json } => {
+        Commands::Get {
+            question,
+            selector,
+            fuzzy,
+            json,
+        } => {
             let machine = identify_machine();
             if machine.backend.contains(&Backend::Unknown) {
                 return Err(anyhow!("unknown backend"));
             }
             if prioritize_backend(&machine, *json) == Backend::Ilo {
-                let res = IloBackend::get(question, None)?;
+                let res = IloBackend::get(question, selector.as_deref())?;
                 print_with_style(res, *json);
             } else {
-                let res = HiiBackend::get(question, None)?;
+                let options = GetOptions {
+                    fuzzy: *fuzzy,
+                    ..Default::default()
+                };
+                let res = HiiBackend::get_with_options(question, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             }
         }
         Commands::Set {
             question,
             value,
+            selector,
+            all,
+            fuzzy,
+            dry_run,
             json,
         } => {
             let machine = identify_machine();
             if machine.backend.contains(&Backend::Unknown) {
                 return Err(anyhow!("unknown backend"));
             }
+            let options = SetOptions {
+                all: *all,
+                fuzzy: *fuzzy,
+                dry_run: *dry_run,
+            };
             if prioritize_backend(&machine, *json) == Backend::Ilo {
-                let res = IloBackend::set(question, value, None)?;
+                let res =
+                    IloBackend::set_with_options(question, value, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             } else {
-                let res = HiiBackend::set(question, value, None)?;
+                let res =
+                    HiiBackend::set_with_options(question, value, selector.as_deref(), &options)?;
                 print_with_style(res, *json);
             }
         }
+        Commands::Apply { profile, json } => {
+            let profile = load_profile(profile)?;
+            let machine = identify_machine();
+            if machine.backend.contains(&Backend::Unknown) {
+                return Err(anyhow!("unknown backend"));
+            }
+            let res = apply_profile(&profile, &machine, prioritize_backend(&machine, *json));
+            let failed = res
+                .responses
+                .iter()
+                .any(|resp| resp.status == Some(SetStatus::Failed));
+            print_with_style(res, *json);
+            if failed {
+                return Ok(1);
+            }
+        }
+        Commands::Check { profile, json } => {
+            let profile = load_profile(profile)?;
+            let machine = identify_machine();
+            if machine.backend.contains(&Backend::Unknown) {
+                return Err(anyhow!("unknown backend"));
+            }
+            let res = check_profile(&profile, &machine, prioritize_backend(&machine, *json));
+            let drifted = !res.mismatches.is_empty();
+            print_with_style(res, *json);
+            if drifted {
+                return Ok(EXIT_DRIFT);
+            }
+        }
+        Commands::Undo { last, id, json } => {
+            let res = undo(*last, *id)?;
+            let failed = res.results.iter().any(|result| !result.error.is_empty());
+            print_with_style(res, *json);
+            if failed {
+                return Ok(1);
+            }
+        }
+        Commands::Capabilities { json } => {
+            let machine = identify_machine();
+            if machine.backend.contains(&Backend::Unknown) {
+                return Err(anyhow!("unknown backend"));
+            }
+            print_with_style(capabilities(&machine), *json);
+        }
+        Commands::Capture { output } => {
+            capture_bundle(output)?;
+            println!("{{\"info\": \"Bundle written to {:?}\"}}", &output);
+        }
+        Commands::CheckTranslations { db, json } => {
+            let mut res = Vec::new();
+            for dump in db {
+                res.push(HiiTranslationReport {
+                    dump: dump.display().to_string(),
+                    checks: HiiBackend::check_translations(&get_db_dump_bytes(Some(dump))?)?,
+                    ..Default::default()
+                });
+            }
+            print_with_style(res, *json);
+        }
+        Commands::SuggestTranslations {
+            dumps,
+            min_dumps,
+            json,
+        } => {
+            let mut paths = Vec::new();
+            for entry in fs::read_dir(dumps).context(format!("reading {}", dumps.display()))? {
+                let path = entry?.path();
+                if path.is_file() {
+                    paths.push(path);
+                }
+            }
+            paths.sort();
+
+            let mut db_dumps = Vec::new();
+            for path in &paths {
+                match get_db_dump_bytes(Some(path)) {
+                    Ok(db_bytes) => db_dumps.push((path.display().to_string(), db_bytes)),
+                    Err(why) => warn!("skipping {}: {:#}", path.display(), why),
+                }
+            }
+            let res = HiiBackend::suggest_translations(&db_dumps, *min_dumps)?;
+            print_with_style(res, *json);
+        }
         Commands::ShowTranslations { json } => {
-            print_with_style(&*translation_db, *json);
+            print_with_style(spellings_db(), *json);
         }
     }
-    Ok(())
+    Ok(0)
 }
 
 fn prioritize_backend(machine: &MachineInfo, json: bool) -> Backend {
@@ -51,6 +51,10 @@ pub mod types {
         pub question: crate::types::Question,
         pub modified: ::std::primitive::bool,
         pub is_translated: ::std::primitive::bool,
+        pub status: ::std::option::Option<crate::types::SetStatus>,
+        pub error: ::std::option::Option<::std::string::String>,
+        pub planned_writes: ::std::option::Option<::std::vec::Vec<crate::types::PlannedWrite>>,
+        pub verified_answer: ::std::option::Option<::std::string::String>,
         // This field forces `..Default::default()` when instantiating this
         // struct, to make code future-proof against new fields added later to
         // the definition in Thrift. If you don't want this, add the annotation
@@ -152,6 +156,289 @@ pub mod types {
         pub _dot_dot_Default_default: self::dot_dot::OtherFields,
     }
 
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HiiForm {
+        pub form_id: ::std::primitive::i32,
+        pub title: ::std::string::String,
+        pub question_count: ::std::primitive::i32,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiFormSet {
+        pub package_list: ::std::string::String,
+        pub guid: ::std::string::String,
+        pub class_guid: ::std::string::String,
+        pub title: ::std::string::String,
+        pub help: ::std::string::String,
+        pub forms: ::std::vec::Vec<crate::types::HiiForm>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiSearchResult {
+        pub package_list: ::std::string::String,
+        pub question: crate::types::Question,
+        pub breadcrumb: ::std::vec::Vec<::std::string::String>,
+        pub matched_fields: ::std::vec::Vec<::std::string::String>,
+        pub score: ::std::primitive::i32,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct BackendCapability {
+        pub backend: crate::types::Backend,
+        pub status: crate::types::CapabilityStatus,
+        pub value: ::std::option::Option<::std::string::String>,
+        pub responses: ::std::vec::Vec<crate::types::GetResponse>,
+        pub reason: ::std::option::Option<::std::string::String>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct Capability {
+        pub question: ::std::string::String,
+        pub backends: ::std::vec::Vec<crate::types::BackendCapability>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct CapabilitiesReport {
+        pub machine: crate::types::MachineInfo,
+        pub capabilities: ::std::vec::Vec<crate::types::Capability>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HiiTranslationCheck {
+        pub question: ::std::string::String,
+        pub source: ::std::string::String,
+        pub matched_variations: ::std::vec::Vec<::std::string::String>,
+        pub unmatched_variations: ::std::vec::Vec<::std::string::String>,
+        pub missing_answers: ::std::vec::Vec<::std::string::String>,
+        pub ambiguous_matches: ::std::vec::Vec<::std::string::String>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiTranslationReport {
+        pub dump: ::std::string::String,
+        pub checks: ::std::vec::Vec<crate::types::HiiTranslationCheck>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HiiFieldChange {
+        pub field: ::std::string::String,
+        pub old_value: ::std::string::String,
+        pub new_value: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiQuestionDiff {
+        pub formset_guid: ::std::string::String,
+        pub question: ::std::string::String,
+        pub storage: ::std::string::String,
+        pub kind: crate::types::HiiChangeKind,
+        pub changes: ::std::vec::Vec<crate::types::HiiFieldChange>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HiiSettingValue {
+        pub identity: ::std::string::String,
+        pub question: ::std::string::String,
+        pub raw_value: ::std::string::String,
+        pub display_value: ::std::string::String,
+        pub breadcrumb: ::std::vec::Vec<::std::string::String>,
+        pub options: ::std::vec::Vec<::std::string::String>,
+        pub varstore: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiSnapshot {
+        pub machine: crate::types::MachineInfo,
+        pub settings: ::std::vec::Vec<crate::types::HiiSettingValue>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct HiiRestoreResult {
+        pub identity: ::std::string::String,
+        pub question: ::std::string::String,
+        pub old_value: ::std::string::String,
+        pub new_value: ::std::string::String,
+        pub error: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiMigrationEntry {
+        pub saved: crate::types::HiiSettingValue,
+        pub status: crate::types::HiiMigrationStatus,
+        pub identity: ::std::string::String,
+        pub breadcrumb: ::std::vec::Vec<::std::string::String>,
+        pub new_value: ::std::string::String,
+        pub differences: ::std::vec::Vec<::std::string::String>,
+        pub reason: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct HiiMigrationReport {
+        pub entries: ::std::vec::Vec<crate::types::HiiMigrationEntry>,
+        pub results: ::std::vec::Vec<crate::types::HiiRestoreResult>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct ProfileMismatch {
+        pub question: ::std::string::String,
+        pub expected: ::std::string::String,
+        pub actual: ::std::string::String,
+        pub backend: crate::types::Backend,
+        pub selector: ::std::string::String,
+        pub error: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct ProfileCheckReport {
+        pub checked: ::std::primitive::i32,
+        pub mismatches: ::std::vec::Vec<crate::types::ProfileMismatch>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct PlannedWrite {
+        pub target: ::std::string::String,
+        pub offset: ::std::primitive::i32,
+        pub old_bytes: ::std::string::String,
+        pub new_bytes: ::std::string::String,
+        pub body: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct UndoResult {
+        pub id: ::std::primitive::i64,
+        pub timestamp: ::std::primitive::i64,
+        pub backend: crate::types::Backend,
+        pub selector: ::std::string::String,
+        pub question: ::std::string::String,
+        pub restored_value: ::std::string::String,
+        pub error: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct UndoResultList {
+        pub results: ::std::vec::Vec<crate::types::UndoResult>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
     pub struct Backend(pub ::std::primitive::i32);
 
@@ -200,976 +487,3395 @@ pub mod types {
         }
     }
 
-    impl ::std::convert::From<Backend> for ::std::primitive::i32 {
-        #[inline]
-        fn from(x: Backend) -> Self {
-            x.0
+    impl ::std::convert::From<Backend> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: Backend) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<::std::primitive::i32> for Backend {
+        #[inline]
+        fn from(x: ::std::primitive::i32) -> Self {
+            Self(x)
+        }
+    }
+
+    impl ::std::fmt::Display for Backend {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Unknown", 0),
+                ("Hii", 1),
+                ("Ilo", 2),
+            ];
+            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+        }
+    }
+
+    impl ::std::fmt::Debug for Backend {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(fmt, "Backend::{}", self)
+        }
+    }
+
+    impl ::std::str::FromStr for Backend {
+        type Err = ::anyhow::Error;
+
+        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Hii", 1),
+                ("Ilo", 2),
+                ("Unknown", 0),
+            ];
+            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "Backend").map(Backend)
+        }
+    }
+
+    impl ::fbthrift::GetTType for Backend {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for Backend
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        #[inline]
+        fn write(&self, p: &mut P) {
+            p.write_i32(self.into())
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for Backend
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        #[inline]
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            ::std::result::Result::Ok(Backend::from(p.read_i32()?))
+        }
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub struct CapabilityStatus(pub ::std::primitive::i32);
+
+    impl CapabilityStatus {
+        pub const Unsupported: Self = CapabilityStatus(0i32);
+        pub const Supported: Self = CapabilityStatus(1i32);
+        pub const Ambiguous: Self = CapabilityStatus(2i32);
+    }
+
+    impl ::fbthrift::ThriftEnum for CapabilityStatus {
+        fn enumerate() -> &'static [(CapabilityStatus, &'static str)] {
+            &[
+                (CapabilityStatus::Unsupported, "Unsupported"),
+                (CapabilityStatus::Supported, "Supported"),
+                (CapabilityStatus::Ambiguous, "Ambiguous"),
+            ]
+        }
+
+        fn variants() -> &'static [&'static str] {
+            &[
+                "Unsupported",
+                "Supported",
+                "Ambiguous",
+            ]
+        }
+
+        fn variant_values() -> &'static [CapabilityStatus] {
+            &[
+                CapabilityStatus::Unsupported,
+                CapabilityStatus::Supported,
+                CapabilityStatus::Ambiguous,
+            ]
+        }
+    }
+
+    impl ::std::default::Default for CapabilityStatus {
+        fn default() -> Self {
+            CapabilityStatus(::fbthrift::__UNKNOWN_ID)
+        }
+    }
+
+    impl<'a> ::std::convert::From<&'a CapabilityStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: &'a CapabilityStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<CapabilityStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: CapabilityStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<::std::primitive::i32> for CapabilityStatus {
+        #[inline]
+        fn from(x: ::std::primitive::i32) -> Self {
+            Self(x)
+        }
+    }
+
+    impl ::std::fmt::Display for CapabilityStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Unsupported", 0),
+                ("Supported", 1),
+                ("Ambiguous", 2),
+            ];
+            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+        }
+    }
+
+    impl ::std::fmt::Debug for CapabilityStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(fmt, "CapabilityStatus::{}", self)
+        }
+    }
+
+    impl ::std::str::FromStr for CapabilityStatus {
+        type Err = ::anyhow::Error;
+
+        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Ambiguous", 2),
+                ("Supported", 1),
+                ("Unsupported", 0),
+            ];
+            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "CapabilityStatus").map(CapabilityStatus)
+        }
+    }
+
+    impl ::fbthrift::GetTType for CapabilityStatus {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for CapabilityStatus
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        #[inline]
+        fn write(&self, p: &mut P) {
+            p.write_i32(self.into())
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for CapabilityStatus
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        #[inline]
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            ::std::result::Result::Ok(CapabilityStatus::from(p.read_i32()?))
+        }
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub struct HiiChangeKind(pub ::std::primitive::i32);
+
+    impl HiiChangeKind {
+        pub const Added: Self = HiiChangeKind(0i32);
+        pub const Removed: Self = HiiChangeKind(1i32);
+        pub const Changed: Self = HiiChangeKind(2i32);
+    }
+
+    impl ::fbthrift::ThriftEnum for HiiChangeKind {
+        fn enumerate() -> &'static [(HiiChangeKind, &'static str)] {
+            &[
+                (HiiChangeKind::Added, "Added"),
+                (HiiChangeKind::Removed, "Removed"),
+                (HiiChangeKind::Changed, "Changed"),
+            ]
+        }
+
+        fn variants() -> &'static [&'static str] {
+            &[
+                "Added",
+                "Removed",
+                "Changed",
+            ]
+        }
+
+        fn variant_values() -> &'static [HiiChangeKind] {
+            &[
+                HiiChangeKind::Added,
+                HiiChangeKind::Removed,
+                HiiChangeKind::Changed,
+            ]
+        }
+    }
+
+    impl ::std::default::Default for HiiChangeKind {
+        fn default() -> Self {
+            HiiChangeKind(::fbthrift::__UNKNOWN_ID)
+        }
+    }
+
+    impl<'a> ::std::convert::From<&'a HiiChangeKind> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: &'a HiiChangeKind) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<HiiChangeKind> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: HiiChangeKind) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<::std::primitive::i32> for HiiChangeKind {
+        #[inline]
+        fn from(x: ::std::primitive::i32) -> Self {
+            Self(x)
+        }
+    }
+
+    impl ::std::fmt::Display for HiiChangeKind {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Added", 0),
+                ("Removed", 1),
+                ("Changed", 2),
+            ];
+            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+        }
+    }
+
+    impl ::std::fmt::Debug for HiiChangeKind {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(fmt, "HiiChangeKind::{}", self)
+        }
+    }
+
+    impl ::std::str::FromStr for HiiChangeKind {
+        type Err = ::anyhow::Error;
+
+        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Added", 0),
+                ("Changed", 2),
+                ("Removed", 1),
+            ];
+            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "HiiChangeKind").map(HiiChangeKind)
+        }
+    }
+
+    impl ::fbthrift::GetTType for HiiChangeKind {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for HiiChangeKind
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        #[inline]
+        fn write(&self, p: &mut P) {
+            p.write_i32(self.into())
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for HiiChangeKind
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        #[inline]
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            ::std::result::Result::Ok(HiiChangeKind::from(p.read_i32()?))
+        }
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub struct HiiMigrationStatus(pub ::std::primitive::i32);
+
+    impl HiiMigrationStatus {
+        pub const Confident: Self = HiiMigrationStatus(0i32);
+        pub const Uncertain: Self = HiiMigrationStatus(1i32);
+        pub const Dropped: Self = HiiMigrationStatus(2i32);
+    }
+
+    impl ::fbthrift::ThriftEnum for HiiMigrationStatus {
+        fn enumerate() -> &'static [(HiiMigrationStatus, &'static str)] {
+            &[
+                (HiiMigrationStatus::Confident, "Confident"),
+                (HiiMigrationStatus::Uncertain, "Uncertain"),
+                (HiiMigrationStatus::Dropped, "Dropped"),
+            ]
+        }
+
+        fn variants() -> &'static [&'static str] {
+            &[
+                "Confident",
+                "Uncertain",
+                "Dropped",
+            ]
+        }
+
+        fn variant_values() -> &'static [HiiMigrationStatus] {
+            &[
+                HiiMigrationStatus::Confident,
+                HiiMigrationStatus::Uncertain,
+                HiiMigrationStatus::Dropped,
+            ]
+        }
+    }
+
+    impl ::std::default::Default for HiiMigrationStatus {
+        fn default() -> Self {
+            HiiMigrationStatus(::fbthrift::__UNKNOWN_ID)
+        }
+    }
+
+    impl<'a> ::std::convert::From<&'a HiiMigrationStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: &'a HiiMigrationStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<HiiMigrationStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: HiiMigrationStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<::std::primitive::i32> for HiiMigrationStatus {
+        #[inline]
+        fn from(x: ::std::primitive::i32) -> Self {
+            Self(x)
+        }
+    }
+
+    impl ::std::fmt::Display for HiiMigrationStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Confident", 0),
+                ("Uncertain", 1),
+                ("Dropped", 2),
+            ];
+            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+        }
+    }
+
+    impl ::std::fmt::Debug for HiiMigrationStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(fmt, "HiiMigrationStatus::{}", self)
+        }
+    }
+
+    impl ::std::str::FromStr for HiiMigrationStatus {
+        type Err = ::anyhow::Error;
+
+        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Confident", 0),
+                ("Dropped", 2),
+                ("Uncertain", 1),
+            ];
+            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "HiiMigrationStatus").map(HiiMigrationStatus)
+        }
+    }
+
+    impl ::fbthrift::GetTType for HiiMigrationStatus {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for HiiMigrationStatus
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        #[inline]
+        fn write(&self, p: &mut P) {
+            p.write_i32(self.into())
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for HiiMigrationStatus
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        #[inline]
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            ::std::result::Result::Ok(HiiMigrationStatus::from(p.read_i32()?))
+        }
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub struct SetStatus(pub ::std::primitive::i32);
+
+    impl SetStatus {
+        pub const Changed: Self = SetStatus(0i32);
+        pub const Unchanged: Self = SetStatus(1i32);
+        pub const Failed: Self = SetStatus(2i32);
+    }
+
+    impl ::fbthrift::ThriftEnum for SetStatus {
+        fn enumerate() -> &'static [(SetStatus, &'static str)] {
+            &[
+                (SetStatus::Changed, "Changed"),
+                (SetStatus::Unchanged, "Unchanged"),
+                (SetStatus::Failed, "Failed"),
+            ]
+        }
+
+        fn variants() -> &'static [&'static str] {
+            &[
+                "Changed",
+                "Unchanged",
+                "Failed",
+            ]
+        }
+
+        fn variant_values() -> &'static [SetStatus] {
+            &[
+                SetStatus::Changed,
+                SetStatus::Unchanged,
+                SetStatus::Failed,
+            ]
+        }
+    }
+
+    impl ::std::default::Default for SetStatus {
+        fn default() -> Self {
+            SetStatus(::fbthrift::__UNKNOWN_ID)
+        }
+    }
+
+    impl<'a> ::std::convert::From<&'a SetStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: &'a SetStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<SetStatus> for ::std::primitive::i32 {
+        #[inline]
+        fn from(x: SetStatus) -> Self {
+            x.0
+        }
+    }
+
+    impl ::std::convert::From<::std::primitive::i32> for SetStatus {
+        #[inline]
+        fn from(x: ::std::primitive::i32) -> Self {
+            Self(x)
+        }
+    }
+
+    impl ::std::fmt::Display for SetStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Changed", 0),
+                ("Unchanged", 1),
+                ("Failed", 2),
+            ];
+            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+        }
+    }
+
+    impl ::std::fmt::Debug for SetStatus {
+        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(fmt, "SetStatus::{}", self)
+        }
+    }
+
+    impl ::std::str::FromStr for SetStatus {
+        type Err = ::anyhow::Error;
+
+        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
+            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
+                ("Changed", 0),
+                ("Failed", 2),
+                ("Unchanged", 1),
+            ];
+            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "SetStatus").map(SetStatus)
+        }
+    }
+
+    impl ::fbthrift::GetTType for SetStatus {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for SetStatus
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        #[inline]
+        fn write(&self, p: &mut P) {
+            p.write_i32(self.into())
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for SetStatus
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        #[inline]
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            ::std::result::Result::Ok(SetStatus::from(p.read_i32()?))
+        }
+    }
+
+    impl ::std::default::Default for self::MachineInfo {
+        fn default() -> Self {
+            Self {
+                backend: ::std::default::Default::default(),
+                bios_vendor: ::std::default::Default::default(),
+                bios_version: ::std::default::Default::default(),
+                bios_release: ::std::default::Default::default(),
+                bios_date: ::std::default::Default::default(),
+                product_name: ::std::default::Default::default(),
+                product_family: ::std::default::Default::default(),
+                product_version: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::MachineInfo {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("MachineInfo")
+                .field("backend", &self.backend)
+                .field("bios_vendor", &self.bios_vendor)
+                .field("bios_version", &self.bios_version)
+                .field("bios_release", &self.bios_release)
+                .field("bios_date", &self.bios_date)
+                .field("product_name", &self.product_name)
+                .field("product_family", &self.product_family)
+                .field("product_version", &self.product_version)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::MachineInfo {}
+    unsafe impl ::std::marker::Sync for self::MachineInfo {}
+
+    impl ::fbthrift::GetTType for self::MachineInfo {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::MachineInfo
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("MachineInfo");
+            p.write_field_begin("backend", ::fbthrift::TType::Set, 1);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("bios_vendor", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.bios_vendor, p);
+            p.write_field_end();
+            p.write_field_begin("bios_version", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.bios_version, p);
+            p.write_field_end();
+            p.write_field_begin("bios_release", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.bios_release, p);
+            p.write_field_end();
+            p.write_field_begin("bios_date", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.bios_date, p);
+            p.write_field_end();
+            p.write_field_begin("product_name", ::fbthrift::TType::String, 6);
+            ::fbthrift::Serialize::write(&self.product_name, p);
+            p.write_field_end();
+            p.write_field_begin("product_family", ::fbthrift::TType::String, 7);
+            ::fbthrift::Serialize::write(&self.product_family, p);
+            p.write_field_end();
+            p.write_field_begin("product_version", ::fbthrift::TType::String, 8);
+            ::fbthrift::Serialize::write(&self.product_version, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::MachineInfo
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::Set, 1),
+                ::fbthrift::Field::new("bios_date", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("bios_release", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("bios_vendor", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("bios_version", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("product_family", ::fbthrift::TType::String, 7),
+                ::fbthrift::Field::new("product_name", ::fbthrift::TType::String, 6),
+                ::fbthrift::Field::new("product_version", ::fbthrift::TType::String, 8),
+            ];
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_bios_vendor = ::std::option::Option::None;
+            let mut field_bios_version = ::std::option::Option::None;
+            let mut field_bios_release = ::std::option::Option::None;
+            let mut field_bios_date = ::std::option::Option::None;
+            let mut field_product_name = ::std::option::Option::None;
+            let mut field_product_family = ::std::option::Option::None;
+            let mut field_product_version = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::Set, 1) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_bios_vendor = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_bios_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_bios_release = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_bios_date = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 6) => field_product_name = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 7) => field_product_family = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 8) => field_product_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                backend: field_backend.unwrap_or_default(),
+                bios_vendor: field_bios_vendor.unwrap_or_default(),
+                bios_version: field_bios_version.unwrap_or_default(),
+                bios_release: field_bios_release.unwrap_or_default(),
+                bios_date: field_bios_date.unwrap_or_default(),
+                product_name: field_product_name.unwrap_or_default(),
+                product_family: field_product_family.unwrap_or_default(),
+                product_version: field_product_version.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::Question {
+        fn default() -> Self {
+            Self {
+                name: ::std::default::Default::default(),
+                answer: ::std::default::Default::default(),
+                options: ::std::default::Default::default(),
+                help: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::Question {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("Question")
+                .field("name", &self.name)
+                .field("answer", &self.answer)
+                .field("options", &self.options)
+                .field("help", &self.help)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::Question {}
+    unsafe impl ::std::marker::Sync for self::Question {}
+
+    impl ::fbthrift::GetTType for self::Question {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::Question
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("Question");
+            p.write_field_begin("name", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.name, p);
+            p.write_field_end();
+            p.write_field_begin("answer", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.answer, p);
+            p.write_field_end();
+            p.write_field_begin("options", ::fbthrift::TType::List, 3);
+            ::fbthrift::Serialize::write(&self.options, p);
+            p.write_field_end();
+            p.write_field_begin("help", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.help, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::Question
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("answer", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("help", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("name", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("options", ::fbthrift::TType::List, 3),
+            ];
+            let mut field_name = ::std::option::Option::None;
+            let mut field_answer = ::std::option::Option::None;
+            let mut field_options = ::std::option::Option::None;
+            let mut field_help = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_name = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_answer = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 3) => field_options = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_help = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                name: field_name.unwrap_or_default(),
+                answer: field_answer.unwrap_or_default(),
+                options: field_options.unwrap_or_default(),
+                help: field_help.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::SetResponse {
+        fn default() -> Self {
+            Self {
+                selector: ::std::default::Default::default(),
+                backend: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                modified: ::std::default::Default::default(),
+                is_translated: ::std::default::Default::default(),
+                status: ::std::option::Option::None,
+                error: ::std::option::Option::None,
+                planned_writes: ::std::option::Option::None,
+                verified_answer: ::std::option::Option::None,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::SetResponse {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("SetResponse")
+                .field("selector", &self.selector)
+                .field("backend", &self.backend)
+                .field("question", &self.question)
+                .field("modified", &self.modified)
+                .field("is_translated", &self.is_translated)
+                .field("status", &self.status)
+                .field("error", &self.error)
+                .field("planned_writes", &self.planned_writes)
+                .field("verified_answer", &self.verified_answer)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::SetResponse {}
+    unsafe impl ::std::marker::Sync for self::SetResponse {}
+
+    impl ::fbthrift::GetTType for self::SetResponse {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::SetResponse
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("SetResponse");
+            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_field_end();
+            p.write_field_begin("backend", ::fbthrift::TType::I32, 2);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("question", ::fbthrift::TType::Struct, 3);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("modified", ::fbthrift::TType::Bool, 4);
+            ::fbthrift::Serialize::write(&self.modified, p);
+            p.write_field_end();
+            p.write_field_begin("is_translated", ::fbthrift::TType::Bool, 5);
+            ::fbthrift::Serialize::write(&self.is_translated, p);
+            p.write_field_end();
+            if let ::std::option::Option::Some(some) = &self.status {
+                p.write_field_begin("status", ::fbthrift::TType::I32, 6);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.error {
+                p.write_field_begin("error", ::fbthrift::TType::String, 7);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.planned_writes {
+                p.write_field_begin("planned_writes", ::fbthrift::TType::List, 8);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.verified_answer {
+                p.write_field_begin("verified_answer", ::fbthrift::TType::String, 9);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::SetResponse
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 2),
+                ::fbthrift::Field::new("error", ::fbthrift::TType::String, 7),
+                ::fbthrift::Field::new("is_translated", ::fbthrift::TType::Bool, 5),
+                ::fbthrift::Field::new("modified", ::fbthrift::TType::Bool, 4),
+                ::fbthrift::Field::new("planned_writes", ::fbthrift::TType::List, 8),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::Struct, 3),
+                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("status", ::fbthrift::TType::I32, 6),
+                ::fbthrift::Field::new("verified_answer", ::fbthrift::TType::String, 9),
+            ];
+            let mut field_selector = ::std::option::Option::None;
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_modified = ::std::option::Option::None;
+            let mut field_is_translated = ::std::option::Option::None;
+            let mut field_status = ::std::option::Option::None;
+            let mut field_error = ::std::option::Option::None;
+            let mut field_planned_writes = ::std::option::Option::None;
+            let mut field_verified_answer = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 2) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 3) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Bool, 4) => field_modified = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Bool, 5) => field_is_translated = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 6) => field_status = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 7) => field_error = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 8) => field_planned_writes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 9) => field_verified_answer = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                selector: field_selector.unwrap_or_default(),
+                backend: field_backend.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                modified: field_modified.unwrap_or_default(),
+                is_translated: field_is_translated.unwrap_or_default(),
+                status: field_status,
+                error: field_error,
+                planned_writes: field_planned_writes,
+                verified_answer: field_verified_answer,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::SetResponseList {
+        fn default() -> Self {
+            Self {
+                responses: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::SetResponseList {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("SetResponseList")
+                .field("responses", &self.responses)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::SetResponseList {}
+    unsafe impl ::std::marker::Sync for self::SetResponseList {}
+
+    impl ::fbthrift::GetTType for self::SetResponseList {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::SetResponseList
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("SetResponseList");
+            p.write_field_begin("responses", ::fbthrift::TType::List, 1);
+            ::fbthrift::Serialize::write(&self.responses, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::SetResponseList
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("responses", ::fbthrift::TType::List, 1),
+            ];
+            let mut field_responses = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::List, 1) => field_responses = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                responses: field_responses.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::GetResponse {
+        fn default() -> Self {
+            Self {
+                selector: ::std::default::Default::default(),
+                backend: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                is_translated: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::GetResponse {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("GetResponse")
+                .field("selector", &self.selector)
+                .field("backend", &self.backend)
+                .field("question", &self.question)
+                .field("is_translated", &self.is_translated)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::GetResponse {}
+    unsafe impl ::std::marker::Sync for self::GetResponse {}
+
+    impl ::fbthrift::GetTType for self::GetResponse {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::GetResponse
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("GetResponse");
+            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_field_end();
+            p.write_field_begin("backend", ::fbthrift::TType::I32, 2);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("question", ::fbthrift::TType::Struct, 3);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("is_translated", ::fbthrift::TType::Bool, 4);
+            ::fbthrift::Serialize::write(&self.is_translated, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::GetResponse
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 2),
+                ::fbthrift::Field::new("is_translated", ::fbthrift::TType::Bool, 4),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::Struct, 3),
+                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_selector = ::std::option::Option::None;
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_is_translated = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 2) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 3) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Bool, 4) => field_is_translated = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                selector: field_selector.unwrap_or_default(),
+                backend: field_backend.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                is_translated: field_is_translated.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::GetResponseList {
+        fn default() -> Self {
+            Self {
+                responses: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::GetResponseList {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("GetResponseList")
+                .field("responses", &self.responses)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::GetResponseList {}
+    unsafe impl ::std::marker::Sync for self::GetResponseList {}
+
+    impl ::fbthrift::GetTType for self::GetResponseList {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::GetResponseList
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("GetResponseList");
+            p.write_field_begin("responses", ::fbthrift::TType::List, 1);
+            ::fbthrift::Serialize::write(&self.responses, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::GetResponseList
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("responses", ::fbthrift::TType::List, 1),
+            ];
+            let mut field_responses = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::List, 1) => field_responses = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                responses: field_responses.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::Error {
+        fn default() -> Self {
+            Self {
+                error_message: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::Error {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("Error")
+                .field("error_message", &self.error_message)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::Error {}
+    unsafe impl ::std::marker::Sync for self::Error {}
+
+    impl ::fbthrift::GetTType for self::Error {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::Error
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("Error");
+            p.write_field_begin("error_message", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.error_message, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::Error
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("error_message", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_error_message = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_error_message = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                error_message: field_error_message.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::IloAttributes {
+        fn default() -> Self {
+            Self {
+                selector: ::std::default::Default::default(),
+                attributes: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::IloAttributes {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("IloAttributes")
+                .field("selector", &self.selector)
+                .field("attributes", &self.attributes)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::IloAttributes {}
+    unsafe impl ::std::marker::Sync for self::IloAttributes {}
+
+    impl ::fbthrift::GetTType for self::IloAttributes {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::IloAttributes
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("IloAttributes");
+            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_field_end();
+            p.write_field_begin("attributes", ::fbthrift::TType::Map, 2);
+            ::fbthrift::Serialize::write(&self.attributes, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::IloAttributes
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("attributes", ::fbthrift::TType::Map, 2),
+                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_selector = ::std::option::Option::None;
+            let mut field_attributes = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Map, 2) => field_attributes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                selector: field_selector.unwrap_or_default(),
+                attributes: field_attributes.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiShowIfrResponse {
+        fn default() -> Self {
+            Self {
+                readable_representation: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiShowIfrResponse {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiShowIfrResponse")
+                .field("readable_representation", &self.readable_representation)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiShowIfrResponse {}
+    unsafe impl ::std::marker::Sync for self::HiiShowIfrResponse {}
+
+    impl ::fbthrift::GetTType for self::HiiShowIfrResponse {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiShowIfrResponse
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiShowIfrResponse");
+            p.write_field_begin("readable_representation", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.readable_representation, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiShowIfrResponse
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("readable_representation", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_readable_representation = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_readable_representation = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                readable_representation: field_readable_representation.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiDatabase {
+        fn default() -> Self {
+            Self {
+                db: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiDatabase {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiDatabase")
+                .field("db", &self.db)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiDatabase {}
+    unsafe impl ::std::marker::Sync for self::HiiDatabase {}
+
+    impl ::fbthrift::GetTType for self::HiiDatabase {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiDatabase
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiDatabase");
+            p.write_field_begin("db", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.db, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiDatabase
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("db", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_db = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_db = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                db: field_db.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiStringsPackage {
+        fn default() -> Self {
+            Self {
+                package_list: ::std::default::Default::default(),
+                string_package: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiStringsPackage {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiStringsPackage")
+                .field("package_list", &self.package_list)
+                .field("string_package", &self.string_package)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiStringsPackage {}
+    unsafe impl ::std::marker::Sync for self::HiiStringsPackage {}
+
+    impl ::fbthrift::GetTType for self::HiiStringsPackage {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiStringsPackage
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiStringsPackage");
+            p.write_field_begin("package_list", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.package_list, p);
+            p.write_field_end();
+            p.write_field_begin("string_package", ::fbthrift::TType::Map, 2);
+            ::fbthrift::Serialize::write(&self.string_package, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiStringsPackage
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("package_list", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("string_package", ::fbthrift::TType::Map, 2),
+            ];
+            let mut field_package_list = ::std::option::Option::None;
+            let mut field_string_package = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_package_list = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Map, 2) => field_string_package = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                package_list: field_package_list.unwrap_or_default(),
+                string_package: field_string_package.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiForm {
+        fn default() -> Self {
+            Self {
+                form_id: ::std::default::Default::default(),
+                title: ::std::default::Default::default(),
+                question_count: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiForm {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiForm")
+                .field("form_id", &self.form_id)
+                .field("title", &self.title)
+                .field("question_count", &self.question_count)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiForm {}
+    unsafe impl ::std::marker::Sync for self::HiiForm {}
+
+    impl ::fbthrift::GetTType for self::HiiForm {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiForm
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiForm");
+            p.write_field_begin("form_id", ::fbthrift::TType::I32, 1);
+            ::fbthrift::Serialize::write(&self.form_id, p);
+            p.write_field_end();
+            p.write_field_begin("title", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.title, p);
+            p.write_field_end();
+            p.write_field_begin("question_count", ::fbthrift::TType::I32, 3);
+            ::fbthrift::Serialize::write(&self.question_count, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiForm
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("form_id", ::fbthrift::TType::I32, 1),
+                ::fbthrift::Field::new("question_count", ::fbthrift::TType::I32, 3),
+                ::fbthrift::Field::new("title", ::fbthrift::TType::String, 2),
+            ];
+            let mut field_form_id = ::std::option::Option::None;
+            let mut field_title = ::std::option::Option::None;
+            let mut field_question_count = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::I32, 1) => field_form_id = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_title = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 3) => field_question_count = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                form_id: field_form_id.unwrap_or_default(),
+                title: field_title.unwrap_or_default(),
+                question_count: field_question_count.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiFormSet {
+        fn default() -> Self {
+            Self {
+                package_list: ::std::default::Default::default(),
+                guid: ::std::default::Default::default(),
+                class_guid: ::std::default::Default::default(),
+                title: ::std::default::Default::default(),
+                help: ::std::default::Default::default(),
+                forms: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiFormSet {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiFormSet")
+                .field("package_list", &self.package_list)
+                .field("guid", &self.guid)
+                .field("class_guid", &self.class_guid)
+                .field("title", &self.title)
+                .field("help", &self.help)
+                .field("forms", &self.forms)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiFormSet {}
+    unsafe impl ::std::marker::Sync for self::HiiFormSet {}
+
+    impl ::fbthrift::GetTType for self::HiiFormSet {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiFormSet
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiFormSet");
+            p.write_field_begin("package_list", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.package_list, p);
+            p.write_field_end();
+            p.write_field_begin("guid", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.guid, p);
+            p.write_field_end();
+            p.write_field_begin("class_guid", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.class_guid, p);
+            p.write_field_end();
+            p.write_field_begin("title", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.title, p);
+            p.write_field_end();
+            p.write_field_begin("help", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.help, p);
+            p.write_field_end();
+            p.write_field_begin("forms", ::fbthrift::TType::List, 6);
+            ::fbthrift::Serialize::write(&self.forms, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiFormSet
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("class_guid", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("forms", ::fbthrift::TType::List, 6),
+                ::fbthrift::Field::new("guid", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("help", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("package_list", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("title", ::fbthrift::TType::String, 4),
+            ];
+            let mut field_package_list = ::std::option::Option::None;
+            let mut field_guid = ::std::option::Option::None;
+            let mut field_class_guid = ::std::option::Option::None;
+            let mut field_title = ::std::option::Option::None;
+            let mut field_help = ::std::option::Option::None;
+            let mut field_forms = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_package_list = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_guid = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_class_guid = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_title = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_help = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 6) => field_forms = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                package_list: field_package_list.unwrap_or_default(),
+                guid: field_guid.unwrap_or_default(),
+                class_guid: field_class_guid.unwrap_or_default(),
+                title: field_title.unwrap_or_default(),
+                help: field_help.unwrap_or_default(),
+                forms: field_forms.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiSearchResult {
+        fn default() -> Self {
+            Self {
+                package_list: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                breadcrumb: ::std::default::Default::default(),
+                matched_fields: ::std::default::Default::default(),
+                score: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiSearchResult {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiSearchResult")
+                .field("package_list", &self.package_list)
+                .field("question", &self.question)
+                .field("breadcrumb", &self.breadcrumb)
+                .field("matched_fields", &self.matched_fields)
+                .field("score", &self.score)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiSearchResult {}
+    unsafe impl ::std::marker::Sync for self::HiiSearchResult {}
+
+    impl ::fbthrift::GetTType for self::HiiSearchResult {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiSearchResult
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiSearchResult");
+            p.write_field_begin("package_list", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.package_list, p);
+            p.write_field_end();
+            p.write_field_begin("question", ::fbthrift::TType::Struct, 2);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("breadcrumb", ::fbthrift::TType::List, 3);
+            ::fbthrift::Serialize::write(&self.breadcrumb, p);
+            p.write_field_end();
+            p.write_field_begin("matched_fields", ::fbthrift::TType::List, 4);
+            ::fbthrift::Serialize::write(&self.matched_fields, p);
+            p.write_field_end();
+            p.write_field_begin("score", ::fbthrift::TType::I32, 5);
+            ::fbthrift::Serialize::write(&self.score, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiSearchResult
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("breadcrumb", ::fbthrift::TType::List, 3),
+                ::fbthrift::Field::new("matched_fields", ::fbthrift::TType::List, 4),
+                ::fbthrift::Field::new("package_list", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::Struct, 2),
+                ::fbthrift::Field::new("score", ::fbthrift::TType::I32, 5),
+            ];
+            let mut field_package_list = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_breadcrumb = ::std::option::Option::None;
+            let mut field_matched_fields = ::std::option::Option::None;
+            let mut field_score = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_package_list = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 2) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 3) => field_breadcrumb = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 4) => field_matched_fields = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 5) => field_score = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                package_list: field_package_list.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                breadcrumb: field_breadcrumb.unwrap_or_default(),
+                matched_fields: field_matched_fields.unwrap_or_default(),
+                score: field_score.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::BackendCapability {
+        fn default() -> Self {
+            Self {
+                backend: ::std::default::Default::default(),
+                status: ::std::default::Default::default(),
+                value: ::std::option::Option::None,
+                responses: ::std::default::Default::default(),
+                reason: ::std::option::Option::None,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::BackendCapability {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("BackendCapability")
+                .field("backend", &self.backend)
+                .field("status", &self.status)
+                .field("value", &self.value)
+                .field("responses", &self.responses)
+                .field("reason", &self.reason)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::BackendCapability {}
+    unsafe impl ::std::marker::Sync for self::BackendCapability {}
+
+    impl ::fbthrift::GetTType for self::BackendCapability {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::BackendCapability
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("BackendCapability");
+            p.write_field_begin("backend", ::fbthrift::TType::I32, 1);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("status", ::fbthrift::TType::I32, 2);
+            ::fbthrift::Serialize::write(&self.status, p);
+            p.write_field_end();
+            if let ::std::option::Option::Some(some) = &self.value {
+                p.write_field_begin("value", ::fbthrift::TType::String, 3);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_begin("responses", ::fbthrift::TType::List, 4);
+            ::fbthrift::Serialize::write(&self.responses, p);
+            p.write_field_end();
+            if let ::std::option::Option::Some(some) = &self.reason {
+                p.write_field_begin("reason", ::fbthrift::TType::String, 5);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::BackendCapability
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 1),
+                ::fbthrift::Field::new("reason", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("responses", ::fbthrift::TType::List, 4),
+                ::fbthrift::Field::new("status", ::fbthrift::TType::I32, 2),
+                ::fbthrift::Field::new("value", ::fbthrift::TType::String, 3),
+            ];
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_status = ::std::option::Option::None;
+            let mut field_value = ::std::option::Option::None;
+            let mut field_responses = ::std::option::Option::None;
+            let mut field_reason = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::I32, 1) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 2) => field_status = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 4) => field_responses = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_reason = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                backend: field_backend.unwrap_or_default(),
+                status: field_status.unwrap_or_default(),
+                value: field_value,
+                responses: field_responses.unwrap_or_default(),
+                reason: field_reason,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::Capability {
+        fn default() -> Self {
+            Self {
+                question: ::std::default::Default::default(),
+                backends: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::Capability {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("Capability")
+                .field("question", &self.question)
+                .field("backends", &self.backends)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::Capability {}
+    unsafe impl ::std::marker::Sync for self::Capability {}
+
+    impl ::fbthrift::GetTType for self::Capability {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::Capability
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("Capability");
+            p.write_field_begin("question", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("backends", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.backends, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::Capability
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("backends", ::fbthrift::TType::List, 2),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_question = ::std::option::Option::None;
+            let mut field_backends = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_backends = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                question: field_question.unwrap_or_default(),
+                backends: field_backends.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::CapabilitiesReport {
+        fn default() -> Self {
+            Self {
+                machine: ::std::default::Default::default(),
+                capabilities: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::CapabilitiesReport {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("CapabilitiesReport")
+                .field("machine", &self.machine)
+                .field("capabilities", &self.capabilities)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::CapabilitiesReport {}
+    unsafe impl ::std::marker::Sync for self::CapabilitiesReport {}
+
+    impl ::fbthrift::GetTType for self::CapabilitiesReport {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::CapabilitiesReport
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("CapabilitiesReport");
+            p.write_field_begin("machine", ::fbthrift::TType::Struct, 1);
+            ::fbthrift::Serialize::write(&self.machine, p);
+            p.write_field_end();
+            p.write_field_begin("capabilities", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.capabilities, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::CapabilitiesReport
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("capabilities", ::fbthrift::TType::List, 2),
+                ::fbthrift::Field::new("machine", ::fbthrift::TType::Struct, 1),
+            ];
+            let mut field_machine = ::std::option::Option::None;
+            let mut field_capabilities = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::Struct, 1) => field_machine = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_capabilities = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                machine: field_machine.unwrap_or_default(),
+                capabilities: field_capabilities.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiTranslationCheck {
+        fn default() -> Self {
+            Self {
+                question: ::std::default::Default::default(),
+                source: ::std::default::Default::default(),
+                matched_variations: ::std::default::Default::default(),
+                unmatched_variations: ::std::default::Default::default(),
+                missing_answers: ::std::default::Default::default(),
+                ambiguous_matches: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiTranslationCheck {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiTranslationCheck")
+                .field("question", &self.question)
+                .field("source", &self.source)
+                .field("matched_variations", &self.matched_variations)
+                .field("unmatched_variations", &self.unmatched_variations)
+                .field("missing_answers", &self.missing_answers)
+                .field("ambiguous_matches", &self.ambiguous_matches)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::HiiTranslationCheck {}
+    unsafe impl ::std::marker::Sync for self::HiiTranslationCheck {}
+
+    impl ::fbthrift::GetTType for self::HiiTranslationCheck {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiTranslationCheck
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiTranslationCheck");
+            p.write_field_begin("question", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("source", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.source, p);
+            p.write_field_end();
+            p.write_field_begin("matched_variations", ::fbthrift::TType::List, 3);
+            ::fbthrift::Serialize::write(&self.matched_variations, p);
+            p.write_field_end();
+            p.write_field_begin("unmatched_variations", ::fbthrift::TType::List, 4);
+            ::fbthrift::Serialize::write(&self.unmatched_variations, p);
+            p.write_field_end();
+            p.write_field_begin("missing_answers", ::fbthrift::TType::List, 5);
+            ::fbthrift::Serialize::write(&self.missing_answers, p);
+            p.write_field_end();
+            p.write_field_begin("ambiguous_matches", ::fbthrift::TType::List, 6);
+            ::fbthrift::Serialize::write(&self.ambiguous_matches, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiTranslationCheck
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("ambiguous_matches", ::fbthrift::TType::List, 6),
+                ::fbthrift::Field::new("matched_variations", ::fbthrift::TType::List, 3),
+                ::fbthrift::Field::new("missing_answers", ::fbthrift::TType::List, 5),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("source", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("unmatched_variations", ::fbthrift::TType::List, 4),
+            ];
+            let mut field_question = ::std::option::Option::None;
+            let mut field_source = ::std::option::Option::None;
+            let mut field_matched_variations = ::std::option::Option::None;
+            let mut field_unmatched_variations = ::std::option::Option::None;
+            let mut field_missing_answers = ::std::option::Option::None;
+            let mut field_ambiguous_matches = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_source = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 3) => field_matched_variations = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 4) => field_unmatched_variations = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 5) => field_missing_answers = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 6) => field_ambiguous_matches = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                question: field_question.unwrap_or_default(),
+                source: field_source.unwrap_or_default(),
+                matched_variations: field_matched_variations.unwrap_or_default(),
+                unmatched_variations: field_unmatched_variations.unwrap_or_default(),
+                missing_answers: field_missing_answers.unwrap_or_default(),
+                ambiguous_matches: field_ambiguous_matches.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::HiiTranslationReport {
+        fn default() -> Self {
+            Self {
+                dump: ::std::default::Default::default(),
+                checks: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::HiiTranslationReport {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiTranslationReport")
+                .field("dump", &self.dump)
+                .field("checks", &self.checks)
+                .finish()
         }
     }
 
-    impl ::std::convert::From<::std::primitive::i32> for Backend {
-        #[inline]
-        fn from(x: ::std::primitive::i32) -> Self {
-            Self(x)
+    unsafe impl ::std::marker::Send for self::HiiTranslationReport {}
+    unsafe impl ::std::marker::Sync for self::HiiTranslationReport {}
+
+    impl ::fbthrift::GetTType for self::HiiTranslationReport {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::HiiTranslationReport
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("HiiTranslationReport");
+            p.write_field_begin("dump", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.dump, p);
+            p.write_field_end();
+            p.write_field_begin("checks", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.checks, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
         }
     }
 
-    impl ::std::fmt::Display for Backend {
-        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-            static VARIANTS_BY_NUMBER: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
-                ("Unknown", 0),
-                ("Hii", 1),
-                ("Ilo", 2),
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiTranslationReport
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("checks", ::fbthrift::TType::List, 2),
+                ::fbthrift::Field::new("dump", ::fbthrift::TType::String, 1),
             ];
-            ::fbthrift::help::enum_display(VARIANTS_BY_NUMBER, fmt, self.0)
+            let mut field_dump = ::std::option::Option::None;
+            let mut field_checks = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_dump = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_checks = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                dump: field_dump.unwrap_or_default(),
+                checks: field_checks.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
         }
     }
 
-    impl ::std::fmt::Debug for Backend {
-        fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-            write!(fmt, "Backend::{}", self)
+
+    impl ::std::default::Default for self::HiiFieldChange {
+        fn default() -> Self {
+            Self {
+                field: ::std::default::Default::default(),
+                old_value: ::std::default::Default::default(),
+                new_value: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
         }
     }
 
-    impl ::std::str::FromStr for Backend {
-        type Err = ::anyhow::Error;
-
-        fn from_str(string: &::std::primitive::str) -> ::std::result::Result<Self, Self::Err> {
-            static VARIANTS_BY_NAME: &[(&::std::primitive::str, ::std::primitive::i32)] = &[
-                ("Hii", 1),
-                ("Ilo", 2),
-                ("Unknown", 0),
-            ];
-            ::fbthrift::help::enum_from_str(VARIANTS_BY_NAME, string, "Backend").map(Backend)
+    impl ::std::fmt::Debug for self::HiiFieldChange {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("HiiFieldChange")
+                .field("field", &self.field)
+                .field("old_value", &self.old_value)
+                .field("new_value", &self.new_value)
+                .finish()
         }
     }
 
-    impl ::fbthrift::GetTType for Backend {
-        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::I32;
+    unsafe impl ::std::marker::Send for self::HiiFieldChange {}
+    unsafe impl ::std::marker::Sync for self::HiiFieldChange {}
+
+    impl ::fbthrift::GetTType for self::HiiFieldChange {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for Backend
+    impl<P> ::fbthrift::Serialize<P> for self::HiiFieldChange
     where
         P: ::fbthrift::ProtocolWriter,
     {
-        #[inline]
         fn write(&self, p: &mut P) {
-            p.write_i32(self.into())
+            p.write_struct_begin("HiiFieldChange");
+            p.write_field_begin("field", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.field, p);
+            p.write_field_end();
+            p.write_field_begin("old_value", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.old_value, p);
+            p.write_field_end();
+            p.write_field_begin("new_value", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.new_value, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for Backend
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiFieldChange
     where
         P: ::fbthrift::ProtocolReader,
     {
-        #[inline]
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
-            ::std::result::Result::Ok(Backend::from(p.read_i32()?))
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("field", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("new_value", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("old_value", ::fbthrift::TType::String, 2),
+            ];
+            let mut field_field = ::std::option::Option::None;
+            let mut field_old_value = ::std::option::Option::None;
+            let mut field_new_value = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_field = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_old_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_new_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                field: field_field.unwrap_or_default(),
+                old_value: field_old_value.unwrap_or_default(),
+                new_value: field_new_value.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
         }
     }
 
-    impl ::std::default::Default for self::MachineInfo {
+
+    impl ::std::default::Default for self::HiiQuestionDiff {
         fn default() -> Self {
             Self {
-                backend: ::std::default::Default::default(),
-                bios_vendor: ::std::default::Default::default(),
-                bios_version: ::std::default::Default::default(),
-                bios_release: ::std::default::Default::default(),
-                bios_date: ::std::default::Default::default(),
-                product_name: ::std::default::Default::default(),
-                product_family: ::std::default::Default::default(),
-                product_version: ::std::default::Default::default(),
+                formset_guid: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                storage: ::std::default::Default::default(),
+                kind: ::std::default::Default::default(),
+                changes: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::MachineInfo {
+    impl ::std::fmt::Debug for self::HiiQuestionDiff {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("MachineInfo")
-                .field("backend", &self.backend)
-                .field("bios_vendor", &self.bios_vendor)
-                .field("bios_version", &self.bios_version)
-                .field("bios_release", &self.bios_release)
-                .field("bios_date", &self.bios_date)
-                .field("product_name", &self.product_name)
-                .field("product_family", &self.product_family)
-                .field("product_version", &self.product_version)
+                .debug_struct("HiiQuestionDiff")
+                .field("formset_guid", &self.formset_guid)
+                .field("question", &self.question)
+                .field("storage", &self.storage)
+                .field("kind", &self.kind)
+                .field("changes", &self.changes)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::MachineInfo {}
-    unsafe impl ::std::marker::Sync for self::MachineInfo {}
+    unsafe impl ::std::marker::Send for self::HiiQuestionDiff {}
+    unsafe impl ::std::marker::Sync for self::HiiQuestionDiff {}
 
-    impl ::fbthrift::GetTType for self::MachineInfo {
+    impl ::fbthrift::GetTType for self::HiiQuestionDiff {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::MachineInfo
+    impl<P> ::fbthrift::Serialize<P> for self::HiiQuestionDiff
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("MachineInfo");
-            p.write_field_begin("backend", ::fbthrift::TType::Set, 1);
-            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_struct_begin("HiiQuestionDiff");
+            p.write_field_begin("formset_guid", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.formset_guid, p);
             p.write_field_end();
-            p.write_field_begin("bios_vendor", ::fbthrift::TType::String, 2);
-            ::fbthrift::Serialize::write(&self.bios_vendor, p);
-            p.write_field_end();
-            p.write_field_begin("bios_version", ::fbthrift::TType::String, 3);
-            ::fbthrift::Serialize::write(&self.bios_version, p);
-            p.write_field_end();
-            p.write_field_begin("bios_release", ::fbthrift::TType::String, 4);
-            ::fbthrift::Serialize::write(&self.bios_release, p);
-            p.write_field_end();
-            p.write_field_begin("bios_date", ::fbthrift::TType::String, 5);
-            ::fbthrift::Serialize::write(&self.bios_date, p);
+            p.write_field_begin("question", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.question, p);
             p.write_field_end();
-            p.write_field_begin("product_name", ::fbthrift::TType::String, 6);
-            ::fbthrift::Serialize::write(&self.product_name, p);
+            p.write_field_begin("storage", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.storage, p);
             p.write_field_end();
-            p.write_field_begin("product_family", ::fbthrift::TType::String, 7);
-            ::fbthrift::Serialize::write(&self.product_family, p);
+            p.write_field_begin("kind", ::fbthrift::TType::I32, 4);
+            ::fbthrift::Serialize::write(&self.kind, p);
             p.write_field_end();
-            p.write_field_begin("product_version", ::fbthrift::TType::String, 8);
-            ::fbthrift::Serialize::write(&self.product_version, p);
+            p.write_field_begin("changes", ::fbthrift::TType::List, 5);
+            ::fbthrift::Serialize::write(&self.changes, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::MachineInfo
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiQuestionDiff
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("backend", ::fbthrift::TType::Set, 1),
-                ::fbthrift::Field::new("bios_date", ::fbthrift::TType::String, 5),
-                ::fbthrift::Field::new("bios_release", ::fbthrift::TType::String, 4),
-                ::fbthrift::Field::new("bios_vendor", ::fbthrift::TType::String, 2),
-                ::fbthrift::Field::new("bios_version", ::fbthrift::TType::String, 3),
-                ::fbthrift::Field::new("product_family", ::fbthrift::TType::String, 7),
-                ::fbthrift::Field::new("product_name", ::fbthrift::TType::String, 6),
-                ::fbthrift::Field::new("product_version", ::fbthrift::TType::String, 8),
+                ::fbthrift::Field::new("changes", ::fbthrift::TType::List, 5),
+                ::fbthrift::Field::new("formset_guid", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("kind", ::fbthrift::TType::I32, 4),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("storage", ::fbthrift::TType::String, 3),
             ];
-            let mut field_backend = ::std::option::Option::None;
-            let mut field_bios_vendor = ::std::option::Option::None;
-            let mut field_bios_version = ::std::option::Option::None;
-            let mut field_bios_release = ::std::option::Option::None;
-            let mut field_bios_date = ::std::option::Option::None;
-            let mut field_product_name = ::std::option::Option::None;
-            let mut field_product_family = ::std::option::Option::None;
-            let mut field_product_version = ::std::option::Option::None;
+            let mut field_formset_guid = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_storage = ::std::option::Option::None;
+            let mut field_kind = ::std::option::Option::None;
+            let mut field_changes = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::Set, 1) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 2) => field_bios_vendor = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 3) => field_bios_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 4) => field_bios_release = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 5) => field_bios_date = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 6) => field_product_name = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 7) => field_product_family = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 8) => field_product_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 1) => field_formset_guid = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_storage = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 4) => field_kind = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 5) => field_changes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                backend: field_backend.unwrap_or_default(),
-                bios_vendor: field_bios_vendor.unwrap_or_default(),
-                bios_version: field_bios_version.unwrap_or_default(),
-                bios_release: field_bios_release.unwrap_or_default(),
-                bios_date: field_bios_date.unwrap_or_default(),
-                product_name: field_product_name.unwrap_or_default(),
-                product_family: field_product_family.unwrap_or_default(),
-                product_version: field_product_version.unwrap_or_default(),
+                formset_guid: field_formset_guid.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                storage: field_storage.unwrap_or_default(),
+                kind: field_kind.unwrap_or_default(),
+                changes: field_changes.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::Question {
+    impl ::std::default::Default for self::HiiSettingValue {
         fn default() -> Self {
             Self {
-                name: ::std::default::Default::default(),
-                answer: ::std::default::Default::default(),
+                identity: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                raw_value: ::std::default::Default::default(),
+                display_value: ::std::default::Default::default(),
+                breadcrumb: ::std::default::Default::default(),
                 options: ::std::default::Default::default(),
-                help: ::std::default::Default::default(),
+                varstore: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::Question {
+    impl ::std::fmt::Debug for self::HiiSettingValue {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("Question")
-                .field("name", &self.name)
-                .field("answer", &self.answer)
+                .debug_struct("HiiSettingValue")
+                .field("identity", &self.identity)
+                .field("question", &self.question)
+                .field("raw_value", &self.raw_value)
+                .field("display_value", &self.display_value)
+                .field("breadcrumb", &self.breadcrumb)
                 .field("options", &self.options)
-                .field("help", &self.help)
+                .field("varstore", &self.varstore)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::Question {}
-    unsafe impl ::std::marker::Sync for self::Question {}
+    unsafe impl ::std::marker::Send for self::HiiSettingValue {}
+    unsafe impl ::std::marker::Sync for self::HiiSettingValue {}
 
-    impl ::fbthrift::GetTType for self::Question {
+    impl ::fbthrift::GetTType for self::HiiSettingValue {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::Question
+    impl<P> ::fbthrift::Serialize<P> for self::HiiSettingValue
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("Question");
-            p.write_field_begin("name", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.name, p);
+            p.write_struct_begin("HiiSettingValue");
+            p.write_field_begin("identity", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.identity, p);
             p.write_field_end();
-            p.write_field_begin("answer", ::fbthrift::TType::String, 2);
-            ::fbthrift::Serialize::write(&self.answer, p);
+            p.write_field_begin("question", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.question, p);
             p.write_field_end();
-            p.write_field_begin("options", ::fbthrift::TType::List, 3);
+            p.write_field_begin("raw_value", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.raw_value, p);
+            p.write_field_end();
+            p.write_field_begin("display_value", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.display_value, p);
+            p.write_field_end();
+            p.write_field_begin("breadcrumb", ::fbthrift::TType::List, 5);
+            ::fbthrift::Serialize::write(&self.breadcrumb, p);
+            p.write_field_end();
+            p.write_field_begin("options", ::fbthrift::TType::List, 6);
             ::fbthrift::Serialize::write(&self.options, p);
             p.write_field_end();
-            p.write_field_begin("help", ::fbthrift::TType::String, 4);
-            ::fbthrift::Serialize::write(&self.help, p);
+            p.write_field_begin("varstore", ::fbthrift::TType::String, 7);
+            ::fbthrift::Serialize::write(&self.varstore, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::Question
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiSettingValue
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("answer", ::fbthrift::TType::String, 2),
-                ::fbthrift::Field::new("help", ::fbthrift::TType::String, 4),
-                ::fbthrift::Field::new("name", ::fbthrift::TType::String, 1),
-                ::fbthrift::Field::new("options", ::fbthrift::TType::List, 3),
+                ::fbthrift::Field::new("breadcrumb", ::fbthrift::TType::List, 5),
+                ::fbthrift::Field::new("display_value", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("identity", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("options", ::fbthrift::TType::List, 6),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("raw_value", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("varstore", ::fbthrift::TType::String, 7),
             ];
-            let mut field_name = ::std::option::Option::None;
-            let mut field_answer = ::std::option::Option::None;
+            let mut field_identity = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_raw_value = ::std::option::Option::None;
+            let mut field_display_value = ::std::option::Option::None;
+            let mut field_breadcrumb = ::std::option::Option::None;
             let mut field_options = ::std::option::Option::None;
-            let mut field_help = ::std::option::Option::None;
+            let mut field_varstore = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_name = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 2) => field_answer = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::List, 3) => field_options = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::String, 4) => field_help = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 1) => field_identity = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_raw_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_display_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 5) => field_breadcrumb = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 6) => field_options = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 7) => field_varstore = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                name: field_name.unwrap_or_default(),
-                answer: field_answer.unwrap_or_default(),
+                identity: field_identity.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                raw_value: field_raw_value.unwrap_or_default(),
+                display_value: field_display_value.unwrap_or_default(),
+                breadcrumb: field_breadcrumb.unwrap_or_default(),
                 options: field_options.unwrap_or_default(),
-                help: field_help.unwrap_or_default(),
+                varstore: field_varstore.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::SetResponse {
+    impl ::std::default::Default for self::HiiSnapshot {
         fn default() -> Self {
             Self {
-                selector: ::std::default::Default::default(),
-                backend: ::std::default::Default::default(),
-                question: ::std::default::Default::default(),
-                modified: ::std::default::Default::default(),
-                is_translated: ::std::default::Default::default(),
+                machine: ::std::default::Default::default(),
+                settings: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::SetResponse {
+    impl ::std::fmt::Debug for self::HiiSnapshot {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("SetResponse")
-                .field("selector", &self.selector)
-                .field("backend", &self.backend)
-                .field("question", &self.question)
-                .field("modified", &self.modified)
-                .field("is_translated", &self.is_translated)
+                .debug_struct("HiiSnapshot")
+                .field("machine", &self.machine)
+                .field("settings", &self.settings)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::SetResponse {}
-    unsafe impl ::std::marker::Sync for self::SetResponse {}
+    unsafe impl ::std::marker::Send for self::HiiSnapshot {}
+    unsafe impl ::std::marker::Sync for self::HiiSnapshot {}
 
-    impl ::fbthrift::GetTType for self::SetResponse {
+    impl ::fbthrift::GetTType for self::HiiSnapshot {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::SetResponse
+    impl<P> ::fbthrift::Serialize<P> for self::HiiSnapshot
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("SetResponse");
-            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.selector, p);
-            p.write_field_end();
-            p.write_field_begin("backend", ::fbthrift::TType::I32, 2);
-            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_struct_begin("HiiSnapshot");
+            p.write_field_begin("machine", ::fbthrift::TType::Struct, 1);
+            ::fbthrift::Serialize::write(&self.machine, p);
             p.write_field_end();
-            p.write_field_begin("question", ::fbthrift::TType::Struct, 3);
-            ::fbthrift::Serialize::write(&self.question, p);
-            p.write_field_end();
-            p.write_field_begin("modified", ::fbthrift::TType::Bool, 4);
-            ::fbthrift::Serialize::write(&self.modified, p);
-            p.write_field_end();
-            p.write_field_begin("is_translated", ::fbthrift::TType::Bool, 5);
-            ::fbthrift::Serialize::write(&self.is_translated, p);
+            p.write_field_begin("settings", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.settings, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::SetResponse
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiSnapshot
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 2),
-                ::fbthrift::Field::new("is_translated", ::fbthrift::TType::Bool, 5),
-                ::fbthrift::Field::new("modified", ::fbthrift::TType::Bool, 4),
-                ::fbthrift::Field::new("question", ::fbthrift::TType::Struct, 3),
-                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("machine", ::fbthrift::TType::Struct, 1),
+                ::fbthrift::Field::new("settings", ::fbthrift::TType::List, 2),
             ];
-            let mut field_selector = ::std::option::Option::None;
-            let mut field_backend = ::std::option::Option::None;
-            let mut field_question = ::std::option::Option::None;
-            let mut field_modified = ::std::option::Option::None;
-            let mut field_is_translated = ::std::option::Option::None;
+            let mut field_machine = ::std::option::Option::None;
+            let mut field_settings = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::I32, 2) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Struct, 3) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Bool, 4) => field_modified = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Bool, 5) => field_is_translated = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 1) => field_machine = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_settings = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                selector: field_selector.unwrap_or_default(),
-                backend: field_backend.unwrap_or_default(),
-                question: field_question.unwrap_or_default(),
-                modified: field_modified.unwrap_or_default(),
-                is_translated: field_is_translated.unwrap_or_default(),
+                machine: field_machine.unwrap_or_default(),
+                settings: field_settings.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::SetResponseList {
+    impl ::std::default::Default for self::HiiRestoreResult {
         fn default() -> Self {
             Self {
-                responses: ::std::default::Default::default(),
+                identity: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                old_value: ::std::default::Default::default(),
+                new_value: ::std::default::Default::default(),
+                error: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::SetResponseList {
+    impl ::std::fmt::Debug for self::HiiRestoreResult {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("SetResponseList")
-                .field("responses", &self.responses)
+                .debug_struct("HiiRestoreResult")
+                .field("identity", &self.identity)
+                .field("question", &self.question)
+                .field("old_value", &self.old_value)
+                .field("new_value", &self.new_value)
+                .field("error", &self.error)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::SetResponseList {}
-    unsafe impl ::std::marker::Sync for self::SetResponseList {}
+    unsafe impl ::std::marker::Send for self::HiiRestoreResult {}
+    unsafe impl ::std::marker::Sync for self::HiiRestoreResult {}
 
-    impl ::fbthrift::GetTType for self::SetResponseList {
+    impl ::fbthrift::GetTType for self::HiiRestoreResult {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::SetResponseList
+    impl<P> ::fbthrift::Serialize<P> for self::HiiRestoreResult
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("SetResponseList");
-            p.write_field_begin("responses", ::fbthrift::TType::List, 1);
-            ::fbthrift::Serialize::write(&self.responses, p);
+            p.write_struct_begin("HiiRestoreResult");
+            p.write_field_begin("identity", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.identity, p);
+            p.write_field_end();
+            p.write_field_begin("question", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("old_value", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.old_value, p);
+            p.write_field_end();
+            p.write_field_begin("new_value", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.new_value, p);
+            p.write_field_end();
+            p.write_field_begin("error", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.error, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::SetResponseList
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiRestoreResult
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("responses", ::fbthrift::TType::List, 1),
+                ::fbthrift::Field::new("error", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("identity", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("new_value", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("old_value", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 2),
             ];
-            let mut field_responses = ::std::option::Option::None;
+            let mut field_identity = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_old_value = ::std::option::Option::None;
+            let mut field_new_value = ::std::option::Option::None;
+            let mut field_error = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::List, 1) => field_responses = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 1) => field_identity = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_old_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_new_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_error = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                responses: field_responses.unwrap_or_default(),
+                identity: field_identity.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                old_value: field_old_value.unwrap_or_default(),
+                new_value: field_new_value.unwrap_or_default(),
+                error: field_error.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::GetResponse {
+    impl ::std::default::Default for self::HiiMigrationEntry {
         fn default() -> Self {
             Self {
-                selector: ::std::default::Default::default(),
-                backend: ::std::default::Default::default(),
-                question: ::std::default::Default::default(),
-                is_translated: ::std::default::Default::default(),
+                saved: ::std::default::Default::default(),
+                status: ::std::default::Default::default(),
+                identity: ::std::default::Default::default(),
+                breadcrumb: ::std::default::Default::default(),
+                new_value: ::std::default::Default::default(),
+                differences: ::std::default::Default::default(),
+                reason: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::GetResponse {
+    impl ::std::fmt::Debug for self::HiiMigrationEntry {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("GetResponse")
-                .field("selector", &self.selector)
-                .field("backend", &self.backend)
-                .field("question", &self.question)
-                .field("is_translated", &self.is_translated)
+                .debug_struct("HiiMigrationEntry")
+                .field("saved", &self.saved)
+                .field("status", &self.status)
+                .field("identity", &self.identity)
+                .field("breadcrumb", &self.breadcrumb)
+                .field("new_value", &self.new_value)
+                .field("differences", &self.differences)
+                .field("reason", &self.reason)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::GetResponse {}
-    unsafe impl ::std::marker::Sync for self::GetResponse {}
+    unsafe impl ::std::marker::Send for self::HiiMigrationEntry {}
+    unsafe impl ::std::marker::Sync for self::HiiMigrationEntry {}
 
-    impl ::fbthrift::GetTType for self::GetResponse {
+    impl ::fbthrift::GetTType for self::HiiMigrationEntry {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::GetResponse
+    impl<P> ::fbthrift::Serialize<P> for self::HiiMigrationEntry
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("GetResponse");
-            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_struct_begin("HiiMigrationEntry");
+            p.write_field_begin("saved", ::fbthrift::TType::Struct, 1);
+            ::fbthrift::Serialize::write(&self.saved, p);
             p.write_field_end();
-            p.write_field_begin("backend", ::fbthrift::TType::I32, 2);
-            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_begin("status", ::fbthrift::TType::I32, 2);
+            ::fbthrift::Serialize::write(&self.status, p);
             p.write_field_end();
-            p.write_field_begin("question", ::fbthrift::TType::Struct, 3);
-            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_begin("identity", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.identity, p);
             p.write_field_end();
-            p.write_field_begin("is_translated", ::fbthrift::TType::Bool, 4);
-            ::fbthrift::Serialize::write(&self.is_translated, p);
+            p.write_field_begin("breadcrumb", ::fbthrift::TType::List, 4);
+            ::fbthrift::Serialize::write(&self.breadcrumb, p);
+            p.write_field_end();
+            p.write_field_begin("new_value", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.new_value, p);
+            p.write_field_end();
+            p.write_field_begin("differences", ::fbthrift::TType::List, 6);
+            ::fbthrift::Serialize::write(&self.differences, p);
+            p.write_field_end();
+            p.write_field_begin("reason", ::fbthrift::TType::String, 7);
+            ::fbthrift::Serialize::write(&self.reason, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::GetResponse
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiMigrationEntry
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 2),
-                ::fbthrift::Field::new("is_translated", ::fbthrift::TType::Bool, 4),
-                ::fbthrift::Field::new("question", ::fbthrift::TType::Struct, 3),
-                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("breadcrumb", ::fbthrift::TType::List, 4),
+                ::fbthrift::Field::new("differences", ::fbthrift::TType::List, 6),
+                ::fbthrift::Field::new("identity", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("new_value", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("reason", ::fbthrift::TType::String, 7),
+                ::fbthrift::Field::new("saved", ::fbthrift::TType::Struct, 1),
+                ::fbthrift::Field::new("status", ::fbthrift::TType::I32, 2),
             ];
-            let mut field_selector = ::std::option::Option::None;
-            let mut field_backend = ::std::option::Option::None;
-            let mut field_question = ::std::option::Option::None;
-            let mut field_is_translated = ::std::option::Option::None;
+            let mut field_saved = ::std::option::Option::None;
+            let mut field_status = ::std::option::Option::None;
+            let mut field_identity = ::std::option::Option::None;
+            let mut field_breadcrumb = ::std::option::Option::None;
+            let mut field_new_value = ::std::option::Option::None;
+            let mut field_differences = ::std::option::Option::None;
+            let mut field_reason = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::I32, 2) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Struct, 3) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Bool, 4) => field_is_translated = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 1) => field_saved = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 2) => field_status = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_identity = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 4) => field_breadcrumb = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_new_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 6) => field_differences = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 7) => field_reason = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                selector: field_selector.unwrap_or_default(),
-                backend: field_backend.unwrap_or_default(),
-                question: field_question.unwrap_or_default(),
-                is_translated: field_is_translated.unwrap_or_default(),
+                saved: field_saved.unwrap_or_default(),
+                status: field_status.unwrap_or_default(),
+                identity: field_identity.unwrap_or_default(),
+                breadcrumb: field_breadcrumb.unwrap_or_default(),
+                new_value: field_new_value.unwrap_or_default(),
+                differences: field_differences.unwrap_or_default(),
+                reason: field_reason.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::GetResponseList {
+    impl ::std::default::Default for self::HiiMigrationReport {
         fn default() -> Self {
             Self {
-                responses: ::std::default::Default::default(),
+                entries: ::std::default::Default::default(),
+                results: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::GetResponseList {
+    impl ::std::fmt::Debug for self::HiiMigrationReport {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("GetResponseList")
-                .field("responses", &self.responses)
+                .debug_struct("HiiMigrationReport")
+                .field("entries", &self.entries)
+                .field("results", &self.results)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::GetResponseList {}
-    unsafe impl ::std::marker::Sync for self::GetResponseList {}
+    unsafe impl ::std::marker::Send for self::HiiMigrationReport {}
+    unsafe impl ::std::marker::Sync for self::HiiMigrationReport {}
 
-    impl ::fbthrift::GetTType for self::GetResponseList {
+    impl ::fbthrift::GetTType for self::HiiMigrationReport {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::GetResponseList
+    impl<P> ::fbthrift::Serialize<P> for self::HiiMigrationReport
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("GetResponseList");
-            p.write_field_begin("responses", ::fbthrift::TType::List, 1);
-            ::fbthrift::Serialize::write(&self.responses, p);
+            p.write_struct_begin("HiiMigrationReport");
+            p.write_field_begin("entries", ::fbthrift::TType::List, 1);
+            ::fbthrift::Serialize::write(&self.entries, p);
+            p.write_field_end();
+            p.write_field_begin("results", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.results, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::GetResponseList
+    impl<P> ::fbthrift::Deserialize<P> for self::HiiMigrationReport
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("responses", ::fbthrift::TType::List, 1),
+                ::fbthrift::Field::new("entries", ::fbthrift::TType::List, 1),
+                ::fbthrift::Field::new("results", ::fbthrift::TType::List, 2),
             ];
-            let mut field_responses = ::std::option::Option::None;
+            let mut field_entries = ::std::option::Option::None;
+            let mut field_results = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::List, 1) => field_responses = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 1) => field_entries = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_results = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                responses: field_responses.unwrap_or_default(),
+                entries: field_entries.unwrap_or_default(),
+                results: field_results.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::Error {
+    impl ::std::default::Default for self::ProfileMismatch {
         fn default() -> Self {
             Self {
-                error_message: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                expected: ::std::default::Default::default(),
+                actual: ::std::default::Default::default(),
+                backend: ::std::default::Default::default(),
+                selector: ::std::default::Default::default(),
+                error: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::Error {
+    impl ::std::fmt::Debug for self::ProfileMismatch {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("Error")
-                .field("error_message", &self.error_message)
+                .debug_struct("ProfileMismatch")
+                .field("question", &self.question)
+                .field("expected", &self.expected)
+                .field("actual", &self.actual)
+                .field("backend", &self.backend)
+                .field("selector", &self.selector)
+                .field("error", &self.error)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::Error {}
-    unsafe impl ::std::marker::Sync for self::Error {}
+    unsafe impl ::std::marker::Send for self::ProfileMismatch {}
+    unsafe impl ::std::marker::Sync for self::ProfileMismatch {}
 
-    impl ::fbthrift::GetTType for self::Error {
+    impl ::fbthrift::GetTType for self::ProfileMismatch {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::Error
+    impl<P> ::fbthrift::Serialize<P> for self::ProfileMismatch
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("Error");
-            p.write_field_begin("error_message", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.error_message, p);
+            p.write_struct_begin("ProfileMismatch");
+            p.write_field_begin("question", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("expected", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.expected, p);
+            p.write_field_end();
+            p.write_field_begin("actual", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.actual, p);
+            p.write_field_end();
+            p.write_field_begin("backend", ::fbthrift::TType::I32, 4);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("selector", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_field_end();
+            p.write_field_begin("error", ::fbthrift::TType::String, 6);
+            ::fbthrift::Serialize::write(&self.error, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::Error
+    impl<P> ::fbthrift::Deserialize<P> for self::ProfileMismatch
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("error_message", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("actual", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 4),
+                ::fbthrift::Field::new("error", ::fbthrift::TType::String, 6),
+                ::fbthrift::Field::new("expected", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 5),
             ];
-            let mut field_error_message = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_expected = ::std::option::Option::None;
+            let mut field_actual = ::std::option::Option::None;
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_selector = ::std::option::Option::None;
+            let mut field_error = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_error_message = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 1) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_expected = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_actual = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 4) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 6) => field_error = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                error_message: field_error_message.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                expected: field_expected.unwrap_or_default(),
+                actual: field_actual.unwrap_or_default(),
+                backend: field_backend.unwrap_or_default(),
+                selector: field_selector.unwrap_or_default(),
+                error: field_error.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::IloAttributes {
+    impl ::std::default::Default for self::ProfileCheckReport {
         fn default() -> Self {
             Self {
-                selector: ::std::default::Default::default(),
-                attributes: ::std::default::Default::default(),
+                checked: ::std::default::Default::default(),
+                mismatches: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::IloAttributes {
+    impl ::std::fmt::Debug for self::ProfileCheckReport {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("IloAttributes")
-                .field("selector", &self.selector)
-                .field("attributes", &self.attributes)
+                .debug_struct("ProfileCheckReport")
+                .field("checked", &self.checked)
+                .field("mismatches", &self.mismatches)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::IloAttributes {}
-    unsafe impl ::std::marker::Sync for self::IloAttributes {}
+    unsafe impl ::std::marker::Send for self::ProfileCheckReport {}
+    unsafe impl ::std::marker::Sync for self::ProfileCheckReport {}
 
-    impl ::fbthrift::GetTType for self::IloAttributes {
+    impl ::fbthrift::GetTType for self::ProfileCheckReport {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::IloAttributes
+    impl<P> ::fbthrift::Serialize<P> for self::ProfileCheckReport
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("IloAttributes");
-            p.write_field_begin("selector", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_struct_begin("ProfileCheckReport");
+            p.write_field_begin("checked", ::fbthrift::TType::I32, 1);
+            ::fbthrift::Serialize::write(&self.checked, p);
             p.write_field_end();
-            p.write_field_begin("attributes", ::fbthrift::TType::Map, 2);
-            ::fbthrift::Serialize::write(&self.attributes, p);
+            p.write_field_begin("mismatches", ::fbthrift::TType::List, 2);
+            ::fbthrift::Serialize::write(&self.mismatches, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::IloAttributes
+    impl<P> ::fbthrift::Deserialize<P> for self::ProfileCheckReport
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("attributes", ::fbthrift::TType::Map, 2),
-                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("checked", ::fbthrift::TType::I32, 1),
+                ::fbthrift::Field::new("mismatches", ::fbthrift::TType::List, 2),
             ];
-            let mut field_selector = ::std::option::Option::None;
-            let mut field_attributes = ::std::option::Option::None;
+            let mut field_checked = ::std::option::Option::None;
+            let mut field_mismatches = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Map, 2) => field_attributes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 1) => field_checked = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 2) => field_mismatches = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                selector: field_selector.unwrap_or_default(),
-                attributes: field_attributes.unwrap_or_default(),
+                checked: field_checked.unwrap_or_default(),
+                mismatches: field_mismatches.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::HiiShowIfrResponse {
+    impl ::std::default::Default for self::PlannedWrite {
         fn default() -> Self {
             Self {
-                readable_representation: ::std::default::Default::default(),
+                target: ::std::default::Default::default(),
+                offset: ::std::default::Default::default(),
+                old_bytes: ::std::default::Default::default(),
+                new_bytes: ::std::default::Default::default(),
+                body: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::HiiShowIfrResponse {
+    impl ::std::fmt::Debug for self::PlannedWrite {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("HiiShowIfrResponse")
-                .field("readable_representation", &self.readable_representation)
+                .debug_struct("PlannedWrite")
+                .field("target", &self.target)
+                .field("offset", &self.offset)
+                .field("old_bytes", &self.old_bytes)
+                .field("new_bytes", &self.new_bytes)
+                .field("body", &self.body)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::HiiShowIfrResponse {}
-    unsafe impl ::std::marker::Sync for self::HiiShowIfrResponse {}
+    unsafe impl ::std::marker::Send for self::PlannedWrite {}
+    unsafe impl ::std::marker::Sync for self::PlannedWrite {}
 
-    impl ::fbthrift::GetTType for self::HiiShowIfrResponse {
+    impl ::fbthrift::GetTType for self::PlannedWrite {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::HiiShowIfrResponse
+    impl<P> ::fbthrift::Serialize<P> for self::PlannedWrite
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("HiiShowIfrResponse");
-            p.write_field_begin("readable_representation", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.readable_representation, p);
+            p.write_struct_begin("PlannedWrite");
+            p.write_field_begin("target", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.target, p);
+            p.write_field_end();
+            p.write_field_begin("offset", ::fbthrift::TType::I32, 2);
+            ::fbthrift::Serialize::write(&self.offset, p);
+            p.write_field_end();
+            p.write_field_begin("old_bytes", ::fbthrift::TType::String, 3);
+            ::fbthrift::Serialize::write(&self.old_bytes, p);
+            p.write_field_end();
+            p.write_field_begin("new_bytes", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.new_bytes, p);
+            p.write_field_end();
+            p.write_field_begin("body", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.body, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::HiiShowIfrResponse
+    impl<P> ::fbthrift::Deserialize<P> for self::PlannedWrite
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("readable_representation", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("body", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("new_bytes", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("offset", ::fbthrift::TType::I32, 2),
+                ::fbthrift::Field::new("old_bytes", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("target", ::fbthrift::TType::String, 1),
             ];
-            let mut field_readable_representation = ::std::option::Option::None;
+            let mut field_target = ::std::option::Option::None;
+            let mut field_offset = ::std::option::Option::None;
+            let mut field_old_bytes = ::std::option::Option::None;
+            let mut field_new_bytes = ::std::option::Option::None;
+            let mut field_body = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_readable_representation = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 1) => field_target = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 2) => field_offset = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_old_bytes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_new_bytes = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_body = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                readable_representation: field_readable_representation.unwrap_or_default(),
+                target: field_target.unwrap_or_default(),
+                offset: field_offset.unwrap_or_default(),
+                old_bytes: field_old_bytes.unwrap_or_default(),
+                new_bytes: field_new_bytes.unwrap_or_default(),
+                body: field_body.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::HiiDatabase {
+    impl ::std::default::Default for self::UndoResult {
         fn default() -> Self {
             Self {
-                db: ::std::default::Default::default(),
+                id: ::std::default::Default::default(),
+                timestamp: ::std::default::Default::default(),
+                backend: ::std::default::Default::default(),
+                selector: ::std::default::Default::default(),
+                question: ::std::default::Default::default(),
+                restored_value: ::std::default::Default::default(),
+                error: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::HiiDatabase {
+    impl ::std::fmt::Debug for self::UndoResult {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("HiiDatabase")
-                .field("db", &self.db)
+                .debug_struct("UndoResult")
+                .field("id", &self.id)
+                .field("timestamp", &self.timestamp)
+                .field("backend", &self.backend)
+                .field("selector", &self.selector)
+                .field("question", &self.question)
+                .field("restored_value", &self.restored_value)
+                .field("error", &self.error)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::HiiDatabase {}
-    unsafe impl ::std::marker::Sync for self::HiiDatabase {}
+    unsafe impl ::std::marker::Send for self::UndoResult {}
+    unsafe impl ::std::marker::Sync for self::UndoResult {}
 
-    impl ::fbthrift::GetTType for self::HiiDatabase {
+    impl ::fbthrift::GetTType for self::UndoResult {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::HiiDatabase
+    impl<P> ::fbthrift::Serialize<P> for self::UndoResult
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("HiiDatabase");
-            p.write_field_begin("db", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.db, p);
+            p.write_struct_begin("UndoResult");
+            p.write_field_begin("id", ::fbthrift::TType::I64, 1);
+            ::fbthrift::Serialize::write(&self.id, p);
+            p.write_field_end();
+            p.write_field_begin("timestamp", ::fbthrift::TType::I64, 2);
+            ::fbthrift::Serialize::write(&self.timestamp, p);
+            p.write_field_end();
+            p.write_field_begin("backend", ::fbthrift::TType::I32, 3);
+            ::fbthrift::Serialize::write(&self.backend, p);
+            p.write_field_end();
+            p.write_field_begin("selector", ::fbthrift::TType::String, 4);
+            ::fbthrift::Serialize::write(&self.selector, p);
+            p.write_field_end();
+            p.write_field_begin("question", ::fbthrift::TType::String, 5);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("restored_value", ::fbthrift::TType::String, 6);
+            ::fbthrift::Serialize::write(&self.restored_value, p);
+            p.write_field_end();
+            p.write_field_begin("error", ::fbthrift::TType::String, 7);
+            ::fbthrift::Serialize::write(&self.error, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::HiiDatabase
+    impl<P> ::fbthrift::Deserialize<P> for self::UndoResult
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("db", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("backend", ::fbthrift::TType::I32, 3),
+                ::fbthrift::Field::new("error", ::fbthrift::TType::String, 7),
+                ::fbthrift::Field::new("id", ::fbthrift::TType::I64, 1),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 5),
+                ::fbthrift::Field::new("restored_value", ::fbthrift::TType::String, 6),
+                ::fbthrift::Field::new("selector", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("timestamp", ::fbthrift::TType::I64, 2),
             ];
-            let mut field_db = ::std::option::Option::None;
+            let mut field_id = ::std::option::Option::None;
+            let mut field_timestamp = ::std::option::Option::None;
+            let mut field_backend = ::std::option::Option::None;
+            let mut field_selector = ::std::option::Option::None;
+            let mut field_question = ::std::option::Option::None;
+            let mut field_restored_value = ::std::option::Option::None;
+            let mut field_error = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_db = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I64, 1) => field_id = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I64, 2) => field_timestamp = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::I32, 3) => field_backend = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_selector = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 5) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 6) => field_restored_value = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 7) => field_error = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                db: field_db.unwrap_or_default(),
+                id: field_id.unwrap_or_default(),
+                timestamp: field_timestamp.unwrap_or_default(),
+                backend: field_backend.unwrap_or_default(),
+                selector: field_selector.unwrap_or_default(),
+                question: field_question.unwrap_or_default(),
+                restored_value: field_restored_value.unwrap_or_default(),
+                error: field_error.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
     }
 
 
-    impl ::std::default::Default for self::HiiStringsPackage {
+    impl ::std::default::Default for self::UndoResultList {
         fn default() -> Self {
             Self {
-                package_list: ::std::default::Default::default(),
-                string_package: ::std::default::Default::default(),
+                results: ::std::default::Default::default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
     }
 
-    impl ::std::fmt::Debug for self::HiiStringsPackage {
+    impl ::std::fmt::Debug for self::UndoResultList {
         fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
             formatter
-                .debug_struct("HiiStringsPackage")
-                .field("package_list", &self.package_list)
-                .field("string_package", &self.string_package)
+                .debug_struct("UndoResultList")
+                .field("results", &self.results)
                 .finish()
         }
     }
 
-    unsafe impl ::std::marker::Send for self::HiiStringsPackage {}
-    unsafe impl ::std::marker::Sync for self::HiiStringsPackage {}
+    unsafe impl ::std::marker::Send for self::UndoResultList {}
+    unsafe impl ::std::marker::Sync for self::UndoResultList {}
 
-    impl ::fbthrift::GetTType for self::HiiStringsPackage {
+    impl ::fbthrift::GetTType for self::UndoResultList {
         const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
     }
 
-    impl<P> ::fbthrift::Serialize<P> for self::HiiStringsPackage
+    impl<P> ::fbthrift::Serialize<P> for self::UndoResultList
     where
         P: ::fbthrift::ProtocolWriter,
     {
         fn write(&self, p: &mut P) {
-            p.write_struct_begin("HiiStringsPackage");
-            p.write_field_begin("package_list", ::fbthrift::TType::String, 1);
-            ::fbthrift::Serialize::write(&self.package_list, p);
-            p.write_field_end();
-            p.write_field_begin("string_package", ::fbthrift::TType::Map, 2);
-            ::fbthrift::Serialize::write(&self.string_package, p);
+            p.write_struct_begin("UndoResultList");
+            p.write_field_begin("results", ::fbthrift::TType::List, 1);
+            ::fbthrift::Serialize::write(&self.results, p);
             p.write_field_end();
             p.write_field_stop();
             p.write_struct_end();
         }
     }
 
-    impl<P> ::fbthrift::Deserialize<P> for self::HiiStringsPackage
+    impl<P> ::fbthrift::Deserialize<P> for self::UndoResultList
     where
         P: ::fbthrift::ProtocolReader,
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
-                ::fbthrift::Field::new("package_list", ::fbthrift::TType::String, 1),
-                ::fbthrift::Field::new("string_package", ::fbthrift::TType::Map, 2),
+                ::fbthrift::Field::new("results", ::fbthrift::TType::List, 1),
             ];
-            let mut field_package_list = ::std::option::Option::None;
-            let mut field_string_package = ::std::option::Option::None;
+            let mut field_results = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
                 match (fty, fid as ::std::primitive::i32) {
                     (::fbthrift::TType::Stop, _) => break,
-                    (::fbthrift::TType::String, 1) => field_package_list = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
-                    (::fbthrift::TType::Map, 2) => field_string_package = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 1) => field_results = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
             }
             p.read_struct_end()?;
             ::std::result::Result::Ok(Self {
-                package_list: field_package_list.unwrap_or_default(),
-                string_package: field_string_package.unwrap_or_default(),
+                results: field_results.unwrap_or_default(),
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
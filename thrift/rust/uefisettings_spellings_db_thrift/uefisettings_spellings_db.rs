@@ -27,18 +27,6 @@ pub mod consts {
                     question_variations: vec![
                         "TPM State".to_owned(),
                     ],
-                    answer_replacements: ::std::option::Option::Some({
-                        let mut map = ::std::collections::BTreeMap::new();
-                        map.insert("Enabled".to_owned(), vec![
-                            "Enabled".to_owned(),
-                            "Enable".to_owned(),
-                        ]);
-                        map.insert("Disabled".to_owned(), vec![
-                            "Disabled".to_owned(),
-                            "Disable".to_owned(),
-                        ]);
-                        map
-                    }),
                     ..::std::default::Default::default()
                 }),
                 ilo_question: ::std::option::Option::Some(crate::types::IloQuestion {
@@ -57,18 +45,6 @@ pub mod consts {
                     question_variations: vec![
                         "Security Device Support".to_owned(),
                     ],
-                    answer_replacements: ::std::option::Option::Some({
-                        let mut map = ::std::collections::BTreeMap::new();
-                        map.insert("Enabled".to_owned(), vec![
-                            "Enabled".to_owned(),
-                            "Enable".to_owned(),
-                        ]);
-                        map.insert("Disabled".to_owned(), vec![
-                            "Disabled".to_owned(),
-                            "Disable".to_owned(),
-                        ]);
-                        map
-                    }),
                     ..::std::default::Default::default()
                 }),
                 ilo_question: ::std::default::Default::default(),
@@ -80,18 +56,6 @@ pub mod consts {
                         "TXT Support".to_owned(),
                         "Enable Intel(R) TXT".to_owned(),
                     ],
-                    answer_replacements: ::std::option::Option::Some({
-                        let mut map = ::std::collections::BTreeMap::new();
-                        map.insert("Enabled".to_owned(), vec![
-                            "Enabled".to_owned(),
-                            "Enable".to_owned(),
-                        ]);
-                        map.insert("Disabled".to_owned(), vec![
-                            "Disabled".to_owned(),
-                            "Disable".to_owned(),
-                        ]);
-                        map
-                    }),
                     ..::std::default::Default::default()
                 }),
                 ilo_question: ::std::option::Option::Some(crate::types::IloQuestion {
@@ -106,20 +70,7 @@ pub mod consts {
                     question_variations: vec![
                         "Hyper-Threading".to_owned(),
                         "Enable LP".to_owned(),
-                        "Hyper-Threading [ALL]".to_owned(),
                     ],
-                    answer_replacements: ::std::option::Option::Some({
-                        let mut map = ::std::collections::BTreeMap::new();
-                        map.insert("Enabled".to_owned(), vec![
-                            "Enabled".to_owned(),
-                            "Enable".to_owned(),
-                        ]);
-                        map.insert("Disabled".to_owned(), vec![
-                            "Disabled".to_owned(),
-                            "Disable".to_owned(),
-                        ]);
-                        map
-                    }),
                     ..::std::default::Default::default()
                 }),
                 ilo_question: ::std::default::Default::default(),
@@ -132,18 +83,6 @@ pub mod consts {
                         "VT for Directed I/O".to_owned(),
                         "Intel® VT for Directed I/O (VT-d)".to_owned(),
                     ],
-                    answer_replacements: ::std::option::Option::Some({
-                        let mut map = ::std::collections::BTreeMap::new();
-                        map.insert("Enabled".to_owned(), vec![
-                            "Enabled".to_owned(),
-                            "Enable".to_owned(),
-                        ]);
-                        map.insert("Disabled".to_owned(), vec![
-                            "Disabled".to_owned(),
-                            "Disable".to_owned(),
-                        ]);
-                        map
-                    }),
                     ..::std::default::Default::default()
                 }),
                 ilo_question: ::std::default::Default::default(),
@@ -162,6 +101,8 @@ pub mod types {
     pub struct QuestionMapping {
         pub hii_question: ::std::option::Option<crate::types::HiiQuestion>,
         pub ilo_question: ::std::option::Option<crate::types::IloQuestion>,
+        pub platforms: ::std::option::Option<::std::vec::Vec<crate::types::PlatformMapping>>,
+        pub composite: ::std::option::Option<crate::types::CompositeSetting>,
         // This field forces `..Default::default()` when instantiating this
         // struct, to make code future-proof against new fields added later to
         // the definition in Thrift. If you don't want this, add the annotation
@@ -194,11 +135,65 @@ pub mod types {
         pub _dot_dot_Default_default: self::dot_dot::OtherFields,
     }
 
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct PlatformConstraint {
+        pub bios_vendor: ::std::option::Option<::std::string::String>,
+        pub product_name_regex: ::std::option::Option<::std::string::String>,
+        pub min_bios_version: ::std::option::Option<::std::string::String>,
+        pub max_bios_version: ::std::option::Option<::std::string::String>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct PlatformMapping {
+        pub platform: crate::types::PlatformConstraint,
+        pub hii_question: ::std::option::Option<crate::types::HiiQuestion>,
+        pub ilo_question: ::std::option::Option<crate::types::IloQuestion>,
+        pub composite: ::std::option::Option<crate::types::CompositeSetting>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq)]
+    pub struct CompositeSetting {
+        pub hii_steps: ::std::option::Option<::std::collections::BTreeMap<::std::string::String, ::std::vec::Vec<crate::types::CompositeStep>>>,
+        pub ilo_steps: ::std::option::Option<::std::collections::BTreeMap<::std::string::String, ::std::vec::Vec<crate::types::CompositeStep>>>,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct CompositeStep {
+        pub question: ::std::string::String,
+        pub answer: ::std::string::String,
+        // This field forces `..Default::default()` when instantiating this
+        // struct, to make code future-proof against new fields added later to
+        // the definition in Thrift. If you don't want this, add the annotation
+        // `(rust.exhaustive)` to the Thrift struct to eliminate this field.
+        #[doc(hidden)]
+        pub _dot_dot_Default_default: self::dot_dot::OtherFields,
+    }
+
     impl ::std::default::Default for self::QuestionMapping {
         fn default() -> Self {
             Self {
                 hii_question: ::std::option::Option::None,
                 ilo_question: ::std::option::Option::None,
+                platforms: ::std::option::Option::None,
+                composite: ::std::option::Option::None,
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             }
         }
@@ -210,6 +205,8 @@ pub mod types {
                 .debug_struct("QuestionMapping")
                 .field("hii_question", &self.hii_question)
                 .field("ilo_question", &self.ilo_question)
+                .field("platforms", &self.platforms)
+                .field("composite", &self.composite)
                 .finish()
         }
     }
@@ -237,6 +234,16 @@ pub mod types {
                 ::fbthrift::Serialize::write(some, p);
                 p.write_field_end();
             }
+            if let ::std::option::Option::Some(some) = &self.platforms {
+                p.write_field_begin("platforms", ::fbthrift::TType::List, 3);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.composite {
+                p.write_field_begin("composite", ::fbthrift::TType::Struct, 4);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
             p.write_field_stop();
             p.write_struct_end();
         }
@@ -248,11 +255,15 @@ pub mod types {
     {
         fn read(p: &mut P) -> ::anyhow::Result<Self> {
             static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("composite", ::fbthrift::TType::Struct, 4),
                 ::fbthrift::Field::new("hii_question", ::fbthrift::TType::Struct, 1),
                 ::fbthrift::Field::new("ilo_question", ::fbthrift::TType::Struct, 2),
+                ::fbthrift::Field::new("platforms", ::fbthrift::TType::List, 3),
             ];
             let mut field_hii_question = ::std::option::Option::None;
             let mut field_ilo_question = ::std::option::Option::None;
+            let mut field_platforms = ::std::option::Option::None;
+            let mut field_composite = ::std::option::Option::None;
             let _ = p.read_struct_begin(|_| ())?;
             loop {
                 let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
@@ -260,6 +271,8 @@ pub mod types {
                     (::fbthrift::TType::Stop, _) => break,
                     (::fbthrift::TType::Struct, 1) => field_hii_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (::fbthrift::TType::Struct, 2) => field_ilo_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::List, 3) => field_platforms = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 4) => field_composite = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
                     (fty, _) => p.skip(fty)?,
                 }
                 p.read_field_end()?;
@@ -268,6 +281,8 @@ pub mod types {
             ::std::result::Result::Ok(Self {
                 hii_question: field_hii_question,
                 ilo_question: field_ilo_question,
+                platforms: field_platforms,
+                composite: field_composite,
                 _dot_dot_Default_default: self::dot_dot::OtherFields(()),
             })
         }
@@ -430,6 +445,364 @@ pub mod types {
     }
 
 
+    impl ::std::default::Default for self::PlatformConstraint {
+        fn default() -> Self {
+            Self {
+                bios_vendor: ::std::option::Option::None,
+                product_name_regex: ::std::option::Option::None,
+                min_bios_version: ::std::option::Option::None,
+                max_bios_version: ::std::option::Option::None,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::PlatformConstraint {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("PlatformConstraint")
+                .field("bios_vendor", &self.bios_vendor)
+                .field("product_name_regex", &self.product_name_regex)
+                .field("min_bios_version", &self.min_bios_version)
+                .field("max_bios_version", &self.max_bios_version)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::PlatformConstraint {}
+    unsafe impl ::std::marker::Sync for self::PlatformConstraint {}
+
+    impl ::fbthrift::GetTType for self::PlatformConstraint {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::PlatformConstraint
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("PlatformConstraint");
+            if let ::std::option::Option::Some(some) = &self.bios_vendor {
+                p.write_field_begin("bios_vendor", ::fbthrift::TType::String, 1);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.product_name_regex {
+                p.write_field_begin("product_name_regex", ::fbthrift::TType::String, 2);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.min_bios_version {
+                p.write_field_begin("min_bios_version", ::fbthrift::TType::String, 3);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.max_bios_version {
+                p.write_field_begin("max_bios_version", ::fbthrift::TType::String, 4);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::PlatformConstraint
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("bios_vendor", ::fbthrift::TType::String, 1),
+                ::fbthrift::Field::new("max_bios_version", ::fbthrift::TType::String, 4),
+                ::fbthrift::Field::new("min_bios_version", ::fbthrift::TType::String, 3),
+                ::fbthrift::Field::new("product_name_regex", ::fbthrift::TType::String, 2),
+            ];
+            let mut field_bios_vendor = ::std::option::Option::None;
+            let mut field_product_name_regex = ::std::option::Option::None;
+            let mut field_min_bios_version = ::std::option::Option::None;
+            let mut field_max_bios_version = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_bios_vendor = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_product_name_regex = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 3) => field_min_bios_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 4) => field_max_bios_version = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                bios_vendor: field_bios_vendor,
+                product_name_regex: field_product_name_regex,
+                min_bios_version: field_min_bios_version,
+                max_bios_version: field_max_bios_version,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::PlatformMapping {
+        fn default() -> Self {
+            Self {
+                platform: ::std::default::Default::default(),
+                hii_question: ::std::option::Option::None,
+                ilo_question: ::std::option::Option::None,
+                composite: ::std::option::Option::None,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::PlatformMapping {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("PlatformMapping")
+                .field("platform", &self.platform)
+                .field("hii_question", &self.hii_question)
+                .field("ilo_question", &self.ilo_question)
+                .field("composite", &self.composite)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::PlatformMapping {}
+    unsafe impl ::std::marker::Sync for self::PlatformMapping {}
+
+    impl ::fbthrift::GetTType for self::PlatformMapping {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::PlatformMapping
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("PlatformMapping");
+            p.write_field_begin("platform", ::fbthrift::TType::Struct, 1);
+            ::fbthrift::Serialize::write(&self.platform, p);
+            p.write_field_end();
+            if let ::std::option::Option::Some(some) = &self.hii_question {
+                p.write_field_begin("hii_question", ::fbthrift::TType::Struct, 2);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.ilo_question {
+                p.write_field_begin("ilo_question", ::fbthrift::TType::Struct, 3);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.composite {
+                p.write_field_begin("composite", ::fbthrift::TType::Struct, 4);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::PlatformMapping
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("composite", ::fbthrift::TType::Struct, 4),
+                ::fbthrift::Field::new("hii_question", ::fbthrift::TType::Struct, 2),
+                ::fbthrift::Field::new("ilo_question", ::fbthrift::TType::Struct, 3),
+                ::fbthrift::Field::new("platform", ::fbthrift::TType::Struct, 1),
+            ];
+            let mut field_platform = ::std::option::Option::None;
+            let mut field_hii_question = ::std::option::Option::None;
+            let mut field_ilo_question = ::std::option::Option::None;
+            let mut field_composite = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::Struct, 1) => field_platform = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 2) => field_hii_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 3) => field_ilo_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Struct, 4) => field_composite = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                platform: field_platform.unwrap_or_default(),
+                hii_question: field_hii_question,
+                ilo_question: field_ilo_question,
+                composite: field_composite,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::CompositeSetting {
+        fn default() -> Self {
+            Self {
+                hii_steps: ::std::option::Option::None,
+                ilo_steps: ::std::option::Option::None,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::CompositeSetting {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("CompositeSetting")
+                .field("hii_steps", &self.hii_steps)
+                .field("ilo_steps", &self.ilo_steps)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::CompositeSetting {}
+    unsafe impl ::std::marker::Sync for self::CompositeSetting {}
+
+    impl ::fbthrift::GetTType for self::CompositeSetting {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::CompositeSetting
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("CompositeSetting");
+            if let ::std::option::Option::Some(some) = &self.hii_steps {
+                p.write_field_begin("hii_steps", ::fbthrift::TType::Map, 1);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            if let ::std::option::Option::Some(some) = &self.ilo_steps {
+                p.write_field_begin("ilo_steps", ::fbthrift::TType::Map, 2);
+                ::fbthrift::Serialize::write(some, p);
+                p.write_field_end();
+            }
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::CompositeSetting
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("hii_steps", ::fbthrift::TType::Map, 1),
+                ::fbthrift::Field::new("ilo_steps", ::fbthrift::TType::Map, 2),
+            ];
+            let mut field_hii_steps = ::std::option::Option::None;
+            let mut field_ilo_steps = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::Map, 1) => field_hii_steps = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::Map, 2) => field_ilo_steps = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                hii_steps: field_hii_steps,
+                ilo_steps: field_ilo_steps,
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
+    impl ::std::default::Default for self::CompositeStep {
+        fn default() -> Self {
+            Self {
+                question: ::std::default::Default::default(),
+                answer: ::std::default::Default::default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            }
+        }
+    }
+
+    impl ::std::fmt::Debug for self::CompositeStep {
+        fn fmt(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            formatter
+                .debug_struct("CompositeStep")
+                .field("question", &self.question)
+                .field("answer", &self.answer)
+                .finish()
+        }
+    }
+
+    unsafe impl ::std::marker::Send for self::CompositeStep {}
+    unsafe impl ::std::marker::Sync for self::CompositeStep {}
+
+    impl ::fbthrift::GetTType for self::CompositeStep {
+        const TTYPE: ::fbthrift::TType = ::fbthrift::TType::Struct;
+    }
+
+    impl<P> ::fbthrift::Serialize<P> for self::CompositeStep
+    where
+        P: ::fbthrift::ProtocolWriter,
+    {
+        fn write(&self, p: &mut P) {
+            p.write_struct_begin("CompositeStep");
+            p.write_field_begin("question", ::fbthrift::TType::String, 1);
+            ::fbthrift::Serialize::write(&self.question, p);
+            p.write_field_end();
+            p.write_field_begin("answer", ::fbthrift::TType::String, 2);
+            ::fbthrift::Serialize::write(&self.answer, p);
+            p.write_field_end();
+            p.write_field_stop();
+            p.write_struct_end();
+        }
+    }
+
+    impl<P> ::fbthrift::Deserialize<P> for self::CompositeStep
+    where
+        P: ::fbthrift::ProtocolReader,
+    {
+        fn read(p: &mut P) -> ::anyhow::Result<Self> {
+            static FIELDS: &[::fbthrift::Field] = &[
+                ::fbthrift::Field::new("answer", ::fbthrift::TType::String, 2),
+                ::fbthrift::Field::new("question", ::fbthrift::TType::String, 1),
+            ];
+            let mut field_question = ::std::option::Option::None;
+            let mut field_answer = ::std::option::Option::None;
+            let _ = p.read_struct_begin(|_| ())?;
+            loop {
+                let (_, fty, fid) = p.read_field_begin(|_| (), FIELDS)?;
+                match (fty, fid as ::std::primitive::i32) {
+                    (::fbthrift::TType::Stop, _) => break,
+                    (::fbthrift::TType::String, 1) => field_question = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (::fbthrift::TType::String, 2) => field_answer = ::std::option::Option::Some(::fbthrift::Deserialize::read(p)?),
+                    (fty, _) => p.skip(fty)?,
+                }
+                p.read_field_end()?;
+            }
+            p.read_struct_end()?;
+            ::std::result::Result::Ok(Self {
+                question: field_question.unwrap_or_default(),
+                answer: field_answer.unwrap_or_default(),
+                _dot_dot_Default_default: self::dot_dot::OtherFields(()),
+            })
+        }
+    }
+
+
     mod dot_dot {
         #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct OtherFields(pub(crate) ());
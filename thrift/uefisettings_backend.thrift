@@ -37,6 +37,21 @@ struct Question {
   4: string help;
 }
 
+enum SetStatus {
+  Changed = 0,
+  Unchanged = 1, // the question already had the answer
+  Failed = 2,
+}
+
+// A write which set --dry-run would have done
+struct PlannedWrite {
+  1: string target; // hii: efivar file, ilo: endpoint of the PATCH request
+  2: i32 offset; // hii: offset of the answer in the varstore (after the 4 bytes of attributes)
+  3: string old_bytes; // hii: bytes at the offset now, in hex
+  4: string new_bytes; // hii: bytes which would be written at the offset, in hex
+  5: string body; // ilo: JSON body of the PATCH request
+}
+
 struct SetResponse {
   // selector values:
   // hii - the selector will be the packagelist (TODO: change to form@packagelist).
@@ -47,6 +62,10 @@ struct SetResponse {
   3: Question question; // this will be the newly modified question
   4: bool modified;
   5: bool is_translated; // is the question/answer in the spellings database
+  6: optional SetStatus status; // only set by apply
+  7: optional string error; // why the question couldn't be changed (only set by apply)
+  8: optional list<PlannedWrite> planned_writes; // only set by set --dry-run
+  9: optional string verified_answer; // hii: answer read back after it was written and verified
 }
 
 struct SetResponseList {
@@ -89,3 +108,157 @@ struct HiiStringsPackage {
   1: string package_list; // note that this isn't unique, multiple packages will be part of the same package list
   2: map<i32, string> string_package;
 }
+
+struct HiiForm {
+  1: i32 form_id;
+  2: string title;
+  3: i32 question_count; // only counts the question types we parse (OneOf, Numeric, CheckBox)
+}
+
+struct HiiFormSet {
+  1: string package_list;
+  2: string guid;
+  3: string class_guid;
+  4: string title;
+  5: string help;
+  6: list<HiiForm> forms;
+}
+
+struct HiiSearchResult {
+  1: string package_list;
+  2: Question question;
+  3: list<string> breadcrumb; // titles of the formset and form the question is in
+  4: list<string> matched_fields; // prompt, option or help
+  5: i32 score; // higher is more relevant
+}
+
+enum CapabilityStatus {
+  Unsupported = 0, // the question wasn't found (or couldn't be read)
+  Supported = 1,
+  Ambiguous = 2, // the question matches several different questions, set needs a selector
+}
+
+struct BackendCapability {
+  1: Backend backend;
+  2: CapabilityStatus status;
+  3: optional string value; // the current canonical answer, only set if supported
+  4: list<GetResponse> responses; // every question the canonical question resolved to
+  5: optional string reason; // why it isn't supported
+}
+
+struct Capability {
+  1: string question; // canonical question from the spellings database
+  2: list<BackendCapability> backends;
+}
+
+struct CapabilitiesReport {
+  1: MachineInfo machine;
+  2: list<Capability> capabilities;
+}
+
+struct HiiTranslationCheck {
+  1: string question; // canonical question from the spellings database
+  2: string source; // which mapping was checked: hii_question or platforms[N].hii_question
+  3: list<string> matched_variations;
+  4: list<string> unmatched_variations; // variations which don't match any question
+  5: list<string> missing_answers; // canonical answers whose replacements aren't options of a matched question
+  6: list<string> ambiguous_matches; // every matched question if the variations match more than one
+}
+
+struct HiiTranslationReport {
+  1: string dump; // path of the HiiDB dump
+  2: list<HiiTranslationCheck> checks;
+}
+
+enum HiiChangeKind {
+  Added = 0,
+  Removed = 1,
+  Changed = 2,
+}
+
+struct HiiFieldChange {
+  1: string field; // prompt, help, type, storage, form, default or option <value>
+  2: string old_value; // empty if the field was added
+  3: string new_value; // empty if the field was removed
+}
+
+struct HiiQuestionDiff {
+  1: string formset_guid;
+  2: string question; // prompt in the new dump, or in the old one if the question was removed
+  3: string storage; // varstore and offset like Setup-<guid>@0x10
+  4: HiiChangeKind kind;
+  5: list<HiiFieldChange> changes;
+}
+
+struct HiiSettingValue {
+  1: string identity; // varstore and offset like Setup-<guid>@0x10
+  2: string question;
+  3: string raw_value; // number stored in the varstore
+  4: string display_value; // answer as shown by get
+  5: list<string> breadcrumb; // titles of the formset and form the question is in
+  6: list<string> options; // labels of the options
+  7: string varstore; // name of the varstore like Setup
+}
+
+struct HiiSnapshot {
+  1: MachineInfo machine; // machine the snapshot was taken on
+  2: list<HiiSettingValue> settings;
+}
+
+struct HiiRestoreResult {
+  1: string identity;
+  2: string question;
+  3: string old_value; // display value before restoring, empty if the question wasn't found
+  4: string new_value; // display value from the snapshot
+  5: string error; // empty if the value was restored
+}
+
+enum HiiMigrationStatus {
+  Confident = 0, // prompt, breadcrumb, options and varstore name are the same
+  Uncertain = 1, // the prompt is the same but something else changed
+  Dropped = 2, // no question or option it could be mapped to
+}
+
+struct HiiMigrationEntry {
+  1: HiiSettingValue saved;
+  2: HiiMigrationStatus status;
+  3: string identity; // identity of the matching question in this HiiDB, empty if dropped
+  4: list<string> breadcrumb; // breadcrumb of the matching question
+  5: string new_value; // answer which will be written
+  6: list<string> differences; // what differs for uncertain matches: breadcrumb, options or varstore
+  7: string reason; // why it was dropped
+}
+
+struct HiiMigrationReport {
+  1: list<HiiMigrationEntry> entries;
+  2: list<HiiRestoreResult> results; // empty unless the migration was applied
+}
+
+struct ProfileMismatch {
+  1: string question;
+  2: string expected;
+  3: string actual; // empty if the question couldn't be read
+  4: Backend backend;
+  5: string selector;
+  6: string error; // why the question couldn't be read
+}
+
+struct ProfileCheckReport {
+  1: i32 checked; // number of settings in the profile
+  2: list<ProfileMismatch> mismatches;
+}
+
+// A journaled change which undo reverted, or refused to revert
+struct UndoResult {
+  1: i64 id; // id of the journal entry
+  2: i64 timestamp; // when the change was made, in seconds since the unix epoch
+  3: Backend backend;
+  4: string selector;
+  5: string question;
+  6: string restored_value; // hii: number stored in the varstore, ilo: value of the attribute
+  7: string error; // why the change wasn't reverted, changes older than it weren't tried
+}
+
+struct UndoResultList {
+  1: list<UndoResult> results;
+}
@@ -20,11 +20,15 @@ namespace cpp2 hardware.uefisettings.translations
 struct QuestionMapping {
   1: optional HiiQuestion hii_question;
   2: optional IloQuestion ilo_question;
+  // The first entry whose platform matches the current machine replaces hii_question and/or ilo_question above.
+  // If a question should only be translated on some platforms then leave hii_question/ilo_question unset.
+  3: optional list<PlatformMapping> platforms;
+  4: optional CompositeSetting composite; // if set, this question is changed by changing several other questions
 }
 
 struct HiiQuestion {
   1: list<string> question_variations; // multiple variations to try to get question
-  2: optional map<string, list<string>> answer_replacements; // ex map Enabled -> [Enabled, Enable] before trying to set
+  2: optional map<string, list<string>> answer_replacements; // ex map Fast -> [Fast, Quick] before trying to set. Booleans like Enabled/Enable/On are understood without it (see answers.rs)
 }
 
 struct IloQuestion {
@@ -32,6 +36,34 @@ struct IloQuestion {
   2: optional map<string, string> answer_replacements; // ex rename Enabled -> PresentEnabled before trying to set
 }
 
+// All constraints which are set have to match the machine's DMI information (/sys/class/dmi/id).
+struct PlatformConstraint {
+  1: optional string bios_vendor; // case-insensitive
+  2: optional string product_name_regex; // has to match the whole product name, case-insensitive
+  3: optional string min_bios_version; // inclusive, compared part by part so "1.10" > "1.9"
+  4: optional string max_bios_version; // inclusive
+}
+
+struct PlatformMapping {
+  1: PlatformConstraint platform;
+  2: optional HiiQuestion hii_question;
+  3: optional IloQuestion ilo_question;
+  4: optional CompositeSetting composite;
+}
+
+// Some settings need several questions to be changed together. For example enabling the TPM on some boards
+// means setting both "Security Device Support" and "TPM State".
+// Steps are applied in order as one set. If any step fails the steps already applied are rolled back.
+struct CompositeSetting {
+  1: optional map<string, list<CompositeStep>> hii_steps; // canonical answer -> steps
+  2: optional map<string, list<CompositeStep>> ilo_steps; // canonical answer -> steps
+}
+
+struct CompositeStep {
+  1: string question; // translated like any other question, but it can't be a composite setting itself
+  2: string answer;
+}
+
 // Canonical Question Names
 // These are defined as constants so its eazy to change them once here instead of changing them everywhere in tooling
 const string CQ_TPM_STATE = "TPM State";
@@ -44,10 +76,6 @@ const map<string, QuestionMapping> translation_db = {
   CQ_TPM_STATE: QuestionMapping{
     hii_question = HiiQuestion{
       question_variations = ["TPM State"],
-      answer_replacements = {
-        "Enabled": ["Enabled", "Enable"],
-        "Disabled": ["Disabled", "Disable"],
-      },
     },
     ilo_question = IloQuestion{
       question = "TpmState",
@@ -57,19 +85,11 @@ const map<string, QuestionMapping> translation_db = {
   CQ_SECURITY_DEVICE_SUPPORT: QuestionMapping{
     hii_question = HiiQuestion{
       question_variations = ["Security Device Support"],
-      answer_replacements = {
-        "Enabled": ["Enabled", "Enable"],
-        "Disabled": ["Disabled", "Disable"],
-      },
     },
   },
   CQ_TXT_SUPPORT: QuestionMapping{
     hii_question = HiiQuestion{
       question_variations = ["TXT Support", "Enable Intel(R) TXT"],
-      answer_replacements = {
-        "Enabled": ["Enabled", "Enable"],
-        "Disabled": ["Disabled", "Disable"],
-      },
     },
     ilo_question = IloQuestion{question = "IntelTxt"},
   },
@@ -78,12 +98,7 @@ const map<string, QuestionMapping> translation_db = {
       question_variations = [
         "Hyper-Threading",
         "Enable LP",
-        "Hyper-Threading [ALL]",
       ],
-      answer_replacements = {
-        "Enabled": ["Enabled", "Enable"],
-        "Disabled": ["Disabled", "Disable"],
-      },
     },
   },
   CQ_VT_D: QuestionMapping{
@@ -93,10 +108,6 @@ const map<string, QuestionMapping> translation_db = {
         "VT for Directed I/O",
         "Intel® VT for Directed I/O (VT-d)",
       ],
-      answer_replacements = {
-        "Enabled": ["Enabled", "Enable"],
-        "Disabled": ["Disabled", "Disable"],
-      },
     },
   },
 };